@@ -0,0 +1,25 @@
+//! Meshing many glyphs into the same buffers
+
+use fontmesh::{glyph_to_mesh_2d_reuse, glyph_to_mesh_3d_reuse, Face, Mesh2D, Mesh3D, Quality};
+
+fn main() {
+    let font_data = include_bytes!("../assets/test_font.ttf");
+    let face = Face::parse(font_data, 0).unwrap();
+
+    // One mesh per dimension, refilled for every glyph
+    let mut mesh_2d = Mesh2D::new();
+    let mut mesh_3d = Mesh3D::new();
+    let depth = 0.1;
+
+    for c in "Hello, World".chars().filter(|c| !c.is_whitespace()) {
+        glyph_to_mesh_2d_reuse(&face, c, Quality::Normal, &mut mesh_2d).unwrap();
+        glyph_to_mesh_3d_reuse(&face, c, Quality::High, depth, &mut mesh_3d).unwrap();
+        println!(
+            "'{}': 2D {} vertices, 3D {} vertices (vertex capacity {})",
+            c,
+            mesh_2d.vertex_count(),
+            mesh_3d.vertex_count(),
+            mesh_3d.vertices.capacity()
+        );
+    }
+}
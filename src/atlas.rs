@@ -0,0 +1,188 @@
+//! Packing glyph meshes into a single atlas mesh
+//!
+//! Every glyph is meshed once and placed in its own rectangle of a unit
+//! square, so a whole character set can be drawn, or baked into an atlas
+//! texture, with one mesh and one draw call.
+
+use crate::error::{FontMeshError, Result};
+use crate::types::Mesh2D;
+use glam::Vec2;
+use rustc_hash::{FxHashMap, FxHashSet};
+use ttf_parser::Face;
+
+/// Empty space left around every glyph in the atlas, in em
+///
+/// Keeps texture filtering from bleeding one glyph into its neighbor.
+const ATLAS_PADDING: f32 = 1.0 / 32.0;
+
+/// An axis-aligned rectangle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Rect {
+    /// Size of the rectangle along x and y
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    /// Whether two rectangles share any interior area
+    #[inline]
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.min.x < other.max.x
+            && other.min.x < self.max.x
+            && self.min.y < other.max.y
+            && other.min.y < self.max.y
+    }
+}
+
+/// Mesh a set of characters into one 2D mesh laid out as a packed atlas
+///
+/// Each glyph is meshed as by [`crate::char_to_mesh_2d`] and its bounding
+/// box is packed into rows (shelf packing, tallest glyphs first) with a
+/// little padding around every glyph. The whole atlas is then scaled
+/// uniformly to fit the unit square, so vertex positions double as UVs into
+/// an atlas texture: `(0, 0)` is the bottom-left corner (flip v for
+/// textures with a top-left origin). Glyphs keep their proportions.
+///
+/// The returned map gives each glyph's region in atlas (UV) space, which
+/// covers the glyph's mesh bounding box. Characters that draw nothing, like
+/// spaces, get no region; repeated characters are packed once.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `chars` - The characters to pack
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidQuality`] if `subdivisions` is 0, and the
+/// first meshing error otherwise, e.g. [`FontMeshError::GlyphNotFound`] for
+/// a character missing from the font.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, atlas_mesh_2d};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let chars: Vec<char> = ('A'..='Z').collect();
+/// let (mesh, regions) = atlas_mesh_2d(&face, &chars, 20)?;
+/// assert_eq!(regions.len(), 26);
+/// let (min, max) = mesh.aabb();
+/// assert!(min.x >= 0.0 && min.y >= 0.0 && max.x <= 1.0 && max.y <= 1.0);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn atlas_mesh_2d(
+    face: &Face,
+    chars: &[char],
+    subdivisions: u8,
+) -> Result<(Mesh2D, FxHashMap<char, Rect>)> {
+    if subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(subdivisions));
+    }
+
+    let mut seen = FxHashSet::default();
+    let mut glyphs: Vec<(char, Mesh2D, Rect)> = Vec::new();
+    for &c in chars {
+        if !seen.insert(c) {
+            continue;
+        }
+        let mesh = match crate::glyph::char_to_mesh_2d(face, c, subdivisions) {
+            Ok(mesh) => mesh,
+            Err(FontMeshError::EmptyOutline) => continue,
+            Err(e) => return Err(e),
+        };
+        let (min, max) = mesh.aabb();
+        glyphs.push((c, mesh, Rect { min, max }));
+    }
+
+    let sizes: Vec<Vec2> = glyphs.iter().map(|(_, _, bounds)| bounds.size()).collect();
+    let (positions, extent) = pack_shelves(&sizes);
+    let scale = 1.0 / extent.max_element().max(f32::MIN_POSITIVE);
+
+    let mut atlas = Mesh2D::new();
+    let mut regions = FxHashMap::with_capacity_and_hasher(glyphs.len(), Default::default());
+    for ((c, mut mesh, bounds), position) in glyphs.into_iter().zip(positions) {
+        let offset = position - bounds.min;
+        mesh.vertices
+            .iter_mut()
+            .for_each(|v| *v = (*v + offset) * scale);
+        atlas.append(&mesh);
+        regions.insert(
+            c,
+            Rect {
+                min: position * scale,
+                max: (position + bounds.size()) * scale,
+            },
+        );
+    }
+
+    Ok((atlas, regions))
+}
+
+/// Place rectangles of the given sizes on shelves
+///
+/// Returns the bottom-left corner of every rectangle and the size of the
+/// area they fill, padding included. Shelves are about as wide as the
+/// square root of the total area, so the result is roughly square.
+fn pack_shelves(sizes: &[Vec2]) -> (Vec<Vec2>, Vec2) {
+    let padded = |size: Vec2| size + Vec2::splat(ATLAS_PADDING);
+    let total_area: f32 = sizes.iter().map(|&s| padded(s).x * padded(s).y).sum();
+    let widest = sizes.iter().map(|&s| padded(s).x).fold(0.0, f32::max);
+    let shelf_width = total_area.sqrt().max(widest) + ATLAS_PADDING;
+
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|&a, &b| sizes[b].y.total_cmp(&sizes[a].y));
+
+    let mut positions = vec![Vec2::ZERO; sizes.len()];
+    let mut cursor = Vec2::splat(ATLAS_PADDING);
+    let mut shelf_height: f32 = 0.0;
+    let mut extent = Vec2::ZERO;
+    for i in order {
+        let size = sizes[i];
+        if cursor.x + size.x + ATLAS_PADDING > shelf_width && cursor.x > ATLAS_PADDING {
+            cursor = Vec2::new(ATLAS_PADDING, cursor.y + shelf_height + ATLAS_PADDING);
+            shelf_height = 0.0;
+        }
+        positions[i] = cursor;
+        extent = extent.max(cursor + padded(size));
+        cursor.x += padded(size).x;
+        shelf_height = shelf_height.max(size.y);
+    }
+
+    (positions, extent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_shelves() {
+        let sizes: Vec<Vec2> = (1..=20)
+            .map(|i| Vec2::new(0.1 * (i % 7 + 1) as f32, 0.05 * (i % 5 + 1) as f32))
+            .collect();
+        let (positions, extent) = pack_shelves(&sizes);
+
+        let rects: Vec<Rect> = positions
+            .iter()
+            .zip(&sizes)
+            .map(|(&min, &size)| Rect {
+                min,
+                max: min + size,
+            })
+            .collect();
+        for (i, a) in rects.iter().enumerate() {
+            assert!(a.min.cmpge(Vec2::ZERO).all() && a.max.cmple(extent).all());
+            for b in &rects[i + 1..] {
+                assert!(!a.overlaps(b), "{:?} overlaps {:?}", a, b);
+            }
+        }
+        // Roughly square
+        assert!(extent.x / extent.y < 2.0 && extent.y / extent.x < 2.0);
+    }
+}
@@ -0,0 +1,298 @@
+//! Compact binary serialization for meshes (requires the `binary` feature)
+//!
+//! The layout is little-endian and intentionally simple so it can be read back
+//! with a couple of bulk copies:
+//!
+//! | Offset | Size  | Content                              |
+//! |--------|-------|--------------------------------------|
+//! | 0      | 4     | Magic `b"FMSH"`                      |
+//! | 4      | 2     | Format version (`u16`)               |
+//! | 6      | 2     | Attribute flags (`u16`)              |
+//! | 8      | 4     | Vertex count `n` (`u32`)             |
+//! | 12     | 4     | Index count `m` (`u32`)              |
+//! | 16     | 12·n  | Positions (`f32` x, y, z)            |
+//! | ...    | 12·n  | Normals (`f32` x, y, z)              |
+//! | ...    | 4·m   | Indices (`u32`)                      |
+//! | ...    | 8·n   | UVs (`f32` u, v), if flag bit 0      |
+//! | ...    | 16·n  | Colors (`f32` r, g, b, a), if bit 1  |
+//! | ...    | 16·n  | Tangents (`f32` x, y, z, w), if bit 2|
+//!
+//! The version is bumped whenever the layout changes, and [`Mesh3D::from_bytes`]
+//! rejects data written with any other version.
+
+use crate::error::{FontMeshError, Result};
+use crate::types::Mesh3D;
+use glam::{Vec2, Vec3, Vec4};
+
+/// Magic bytes at the start of every serialized mesh
+pub const MAGIC: [u8; 4] = *b"FMSH";
+
+/// Current binary format version
+pub const FORMAT_VERSION: u16 = 2;
+
+const HEADER_LEN: usize = 16;
+
+const HAS_UVS: u16 = 1 << 0;
+const HAS_COLORS: u16 = 1 << 1;
+const HAS_TANGENTS: u16 = 1 << 2;
+
+impl Mesh3D {
+    /// Serialize this mesh into the compact binary format
+    ///
+    /// UVs, colors and tangents are stored when present, so they should
+    /// have one entry per vertex as the [`Mesh3D`] fields document.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d, Mesh3D};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'A', 5.0, 20)?;
+    ///
+    /// let bytes = mesh.to_bytes();
+    /// let loaded = Mesh3D::from_bytes(&bytes)?;
+    /// assert_eq!(mesh.vertices, loaded.vertices);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let n = self.vertices.len();
+        let m = self.indices.len();
+        let mut flags = 0;
+        if !self.uvs.is_empty() {
+            flags |= HAS_UVS;
+        }
+        if !self.colors.is_empty() {
+            flags |= HAS_COLORS;
+        }
+        if !self.tangents.is_empty() {
+            flags |= HAS_TANGENTS;
+        }
+        let mut out = Vec::with_capacity(HEADER_LEN + n * (24 + attribute_len(flags)) + m * 4);
+
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        out.extend_from_slice(&flags.to_le_bytes());
+        out.extend_from_slice(&(n as u32).to_le_bytes());
+        out.extend_from_slice(&(m as u32).to_le_bytes());
+
+        for v in self.vertices.iter().chain(&self.normals) {
+            out.extend_from_slice(&v.x.to_le_bytes());
+            out.extend_from_slice(&v.y.to_le_bytes());
+            out.extend_from_slice(&v.z.to_le_bytes());
+        }
+        for &i in &self.indices {
+            out.extend_from_slice(&i.to_le_bytes());
+        }
+        let floats = self
+            .uvs
+            .iter()
+            .flat_map(|uv| uv.to_array())
+            .chain(self.colors.iter().flatten().copied())
+            .chain(self.tangents.iter().flat_map(|t| t.to_array()));
+        for f in floats {
+            out.extend_from_slice(&f.to_le_bytes());
+        }
+
+        out
+    }
+
+    /// Deserialize a mesh written by [`Mesh3D::to_bytes`]
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidMeshData`] if the magic or version does
+    /// not match, if unknown attribute flags are set, or if the data is
+    /// truncated, and [`FontMeshError::ParseError`] if a count in the header
+    /// doesn't fit in `usize`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN || bytes[0..4] != MAGIC {
+            return Err(FontMeshError::InvalidMeshData(
+                "missing fontmesh header".to_string(),
+            ));
+        }
+
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != FORMAT_VERSION {
+            return Err(FontMeshError::InvalidMeshData(format!(
+                "unsupported format version {} (expected {})",
+                version, FORMAT_VERSION
+            )));
+        }
+
+        let flags = u16::from_le_bytes([bytes[6], bytes[7]]);
+        if flags & !(HAS_UVS | HAS_COLORS | HAS_TANGENTS) != 0 {
+            return Err(FontMeshError::InvalidMeshData(format!(
+                "unknown attribute flags {:#06x}",
+                flags
+            )));
+        }
+
+        let count = |offset| {
+            let count = read_u32(bytes, offset);
+            usize::try_from(count).map_err(|_| {
+                FontMeshError::ParseError(format!("count {} exceeds the address space", count))
+            })
+        };
+        let n = count(8)?;
+        let m = count(12)?;
+        let expected = n
+            .checked_mul(24 + attribute_len(flags))
+            .and_then(|v| v.checked_add(m.checked_mul(4)?))
+            .and_then(|v| v.checked_add(HEADER_LEN));
+        if expected != Some(bytes.len()) {
+            return Err(FontMeshError::InvalidMeshData(format!(
+                "expected {} vertices and {} indices, got {} bytes",
+                n,
+                m,
+                bytes.len()
+            )));
+        }
+
+        let body = &bytes[HEADER_LEN..];
+        let (vec_data, rest) = body.split_at(n * 24);
+        let (index_data, attribute_data) = rest.split_at(m * 4);
+        let mut vecs = vec_data.chunks_exact(12).map(|c| {
+            Vec3::new(
+                f32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                f32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+                f32::from_le_bytes([c[8], c[9], c[10], c[11]]),
+            )
+        });
+
+        let vertices: Vec<Vec3> = vecs.by_ref().take(n).collect();
+        let normals: Vec<Vec3> = vecs.collect();
+        let indices: Vec<u32> = index_data
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+
+        if indices.iter().any(|&i| i as usize >= n) {
+            return Err(FontMeshError::InvalidMeshData(
+                "index out of bounds".to_string(),
+            ));
+        }
+
+        let mut floats = attribute_data
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]));
+        let mut read = |flag: u16, width: usize| -> Vec<f32> {
+            if flags & flag == 0 {
+                return Vec::new();
+            }
+            floats.by_ref().take(n * width).collect()
+        };
+        let uvs = read(HAS_UVS, 2)
+            .chunks_exact(2)
+            .map(Vec2::from_slice)
+            .collect();
+        let colors = read(HAS_COLORS, 4)
+            .chunks_exact(4)
+            .map(|c| [c[0], c[1], c[2], c[3]])
+            .collect();
+        let tangents = read(HAS_TANGENTS, 4)
+            .chunks_exact(4)
+            .map(Vec4::from_slice)
+            .collect();
+
+        Ok(Mesh3D {
+            vertices,
+            normals,
+            indices,
+            uvs,
+            colors,
+            tangents,
+        })
+    }
+}
+
+/// Bytes per vertex taken by the optional attributes in `flags`
+fn attribute_len(flags: u16) -> usize {
+    [(HAS_UVS, 8), (HAS_COLORS, 16), (HAS_TANGENTS, 16)]
+        .into_iter()
+        .filter(|&(flag, _)| flags & flag != 0)
+        .map(|(_, len)| len)
+        .sum()
+}
+
+#[inline(always)]
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Mesh3D {
+        Mesh3D {
+            vertices: vec![
+                Vec3::new(0.0, 0.0, 0.5),
+                Vec3::new(1.0, 0.0, 0.5),
+                Vec3::new(0.0, 1.0, 0.5),
+            ],
+            normals: vec![Vec3::Z; 3],
+            indices: vec![0, 1, 2],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mesh = triangle();
+        let bytes = mesh.to_bytes();
+        assert_eq!(bytes.len(), HEADER_LEN + 3 * 24 + 3 * 4);
+
+        let loaded = Mesh3D::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.vertices, mesh.vertices);
+        assert_eq!(loaded.normals, mesh.normals);
+        assert_eq!(loaded.indices, mesh.indices);
+        assert!(loaded.uvs.is_empty() && loaded.colors.is_empty() && loaded.tangents.is_empty());
+    }
+
+    #[test]
+    fn test_round_trip_attributes() {
+        let mut mesh = triangle();
+        mesh.uvs = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        mesh.tangents = vec![Vec4::new(1.0, 0.0, 0.0, -1.0); 3];
+        let loaded = Mesh3D::from_bytes(&mesh.to_bytes()).unwrap();
+        assert_eq!(loaded.uvs, mesh.uvs);
+        assert!(loaded.colors.is_empty());
+        assert_eq!(loaded.tangents, mesh.tangents);
+
+        mesh.colors = vec![[1.0, 0.5, 0.25, 1.0]; 3];
+        let bytes = mesh.to_bytes();
+        assert_eq!(bytes.len(), HEADER_LEN + 3 * (24 + 40) + 3 * 4);
+        assert_eq!(Mesh3D::from_bytes(&bytes).unwrap().colors, mesh.colors);
+
+        // Flags this version doesn't know about are rejected
+        let mut bytes = triangle().to_bytes();
+        bytes[6] = 0x08;
+        assert!(Mesh3D::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_rejects_bad_header_and_truncation() {
+        let mut bytes = triangle().to_bytes();
+        assert!(Mesh3D::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        bytes[4] = 99;
+        assert!(matches!(
+            Mesh3D::from_bytes(&bytes),
+            Err(FontMeshError::InvalidMeshData(_))
+        ));
+
+        bytes[0] = b'X';
+        assert!(Mesh3D::from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+//! Invariant checks run between pipeline stages (`debug-checks` feature)
+//!
+//! Each check panics with the stage name and the broken invariant, so a bad
+//! font fails at the stage that produced the bad data instead of somewhere
+//! downstream. They mirror the assertions in `tests/comparison_test.rs`.
+
+use crate::types::{Mesh2D, Mesh3D, Outline2D};
+use glam::{IVec2, Vec2};
+use rustc_hash::FxHashMap;
+
+/// Linearized closed contours have at least 3 points
+///
+/// Open contours (strokes from [`crate::PathBuilder`]) are left alone.
+pub(crate) fn linearized(outline: &Outline2D) {
+    for (i, contour) in outline.contours.iter().enumerate() {
+        if !contour.closed {
+            continue;
+        }
+        assert!(
+            contour.points.len() >= 3,
+            "debug-checks: linearize: contour {} has {} points (need at least 3)",
+            i,
+            contour.points.len()
+        );
+    }
+}
+
+/// Triangulated indices come in triples and stay within the vertex buffer
+pub(crate) fn triangulated(mesh: &Mesh2D) {
+    assert_eq!(
+        mesh.indices.len() % 3,
+        0,
+        "debug-checks: triangulate: index count is not a multiple of 3"
+    );
+    check_indices("triangulate", &mesh.indices, mesh.vertices.len());
+}
+
+/// Extruded meshes have one normal per vertex and in-bounds triangles
+pub(crate) fn extruded(mesh: &Mesh3D) {
+    assert_eq!(
+        mesh.vertices.len(),
+        mesh.normals.len(),
+        "debug-checks: extrude: vertex and normal counts differ"
+    );
+    assert_eq!(
+        mesh.indices.len() % 3,
+        0,
+        "debug-checks: extrude: index count is not a multiple of 3"
+    );
+    check_indices("extrude", &mesh.indices, mesh.vertices.len());
+}
+
+/// Every outline point the side walls are built from is a vertex of the cap
+///
+/// Catches an outline that doesn't belong to the mesh (another glyph, or
+/// linearized with other settings), which would give walls that don't meet
+/// the caps.
+pub(crate) fn extrude_inputs(mesh: &Mesh2D, outline: &Outline2D) {
+    let (min, max) = mesh.aabb();
+    let tolerance = 1e-4 * (max - min).max_element().max(1.0);
+
+    // Bucket the vertices in a grid of tolerance-sized cells, so each point
+    // only has to be compared against the vertices in its neighboring cells
+    let cell = |p: Vec2| (p / tolerance).floor().as_ivec2();
+    let mut grid: FxHashMap<IVec2, Vec<Vec2>> = FxHashMap::default();
+    for &v in &mesh.vertices {
+        grid.entry(cell(v)).or_default().push(v);
+    }
+    let near = |p: Vec2| {
+        let center = cell(p);
+        (-1..=1).any(|dy| {
+            (-1..=1).any(|dx| {
+                grid.get(&(center + IVec2::new(dx, dy))).is_some_and(|vs| {
+                    vs.iter()
+                        .any(|v| v.distance_squared(p) <= tolerance * tolerance)
+                })
+            })
+        })
+    };
+
+    for (i, contour) in outline.contours.iter().enumerate() {
+        for (j, cp) in contour.points.iter().enumerate() {
+            assert!(
+                near(cp.point),
+                "debug-checks: extrude: point {} of contour {} ({:?}) is not a mesh vertex; \
+                 was the outline linearized differently from the mesh?",
+                j,
+                i,
+                cp.point
+            );
+        }
+    }
+}
+
+fn check_indices(stage: &str, indices: &[u32], vertex_count: usize) {
+    if let Some(&index) = indices.iter().find(|&&i| i as usize >= vertex_count) {
+        panic!(
+            "debug-checks: {}: index {} out of bounds for {} vertices",
+            stage, index, vertex_count
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "debug-checks: triangulate: index 3 out of bounds")]
+    fn test_out_of_bounds_index() {
+        let mesh = Mesh2D {
+            vertices: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
+            indices: vec![0, 1, 3],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+        triangulated(&mesh);
+    }
+
+    #[test]
+    fn test_open_contour_linearizes() {
+        let path = crate::PathBuilder::new()
+            .move_to(0.0, 0.0)
+            .line_to(1.0, 0.0)
+            .quad_to(2.0, 0.0, 2.0, 1.0)
+            .build();
+        let outline = crate::linearize::linearize_outline(path, 4).unwrap();
+        assert!(!outline.contours[0].closed);
+    }
+
+    #[test]
+    #[should_panic(expected = "debug-checks: extrude: point 0 of contour 0")]
+    fn test_mismatched_outline() {
+        let mut outline = Outline2D::new();
+        outline.add_contour(crate::types::square(0.0, 1.0, false));
+        let mesh = crate::triangulate::triangulate(&outline).unwrap();
+        let mut other = outline.clone();
+        other.translate(Vec2::new(0.5, 0.0));
+        let _ = crate::extrude::extrude(&mesh, &other, 1.0);
+    }
+
+    #[test]
+    fn test_outline_matches_within_tolerance() {
+        let mut outline = Outline2D::new();
+        outline.add_contour(crate::types::square(0.0, 1.0, false));
+        let mesh = crate::triangulate::triangulate(&outline).unwrap();
+        // Half the tolerance off, into the neighboring grid cells
+        outline.translate(Vec2::splat(-5e-5));
+        extrude_inputs(&mesh, &outline);
+    }
+}
@@ -3,7 +3,10 @@
 use std::fmt;
 
 /// Errors that can occur during font mesh generation
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum FontMeshError {
     /// Failed to parse the font file
     ParseError(String),
@@ -11,6 +14,9 @@ pub enum FontMeshError {
     /// Glyph not found for the given character
     GlyphNotFound(char),
 
+    /// Glyph ID is not below the font's glyph count
+    InvalidGlyphId(u16),
+
     /// Failed to extract glyph outline
     OutlineExtractionFailed(String),
 
@@ -26,8 +32,23 @@ pub enum FontMeshError {
     /// Invalid quality parameter
     InvalidQuality(u8),
 
-    /// The glyph has no outline (e.g., space character)
-    NoOutline,
+    /// The glyph exists but draws nothing (e.g., space character)
+    EmptyOutline,
+
+    /// The font has no outline data for the glyph (e.g., a bitmap-only glyph)
+    NoGlyphData,
+
+    /// The glyph has more contours than the configured limit (carries the contour count)
+    ComplexityExceeded(usize),
+
+    /// Serialized mesh data is malformed or from an unsupported format version
+    InvalidMeshData(String),
+
+    /// The font lacks a metric the operation needs (carries the metric's name)
+    MissingMetric(&'static str),
+
+    /// An argument is out of its valid range
+    InvalidParameter(String),
 }
 
 impl fmt::Display for FontMeshError {
@@ -35,12 +56,20 @@ impl fmt::Display for FontMeshError {
         match self {
             Self::ParseError(msg) => write!(f, "Font parse error: {}", msg),
             Self::GlyphNotFound(c) => write!(f, "Glyph not found for character: '{}'", c),
+            Self::InvalidGlyphId(id) => write!(f, "Glyph ID {} is out of range for this font", id),
             Self::OutlineExtractionFailed(msg) => write!(f, "Outline extraction failed: {}", msg),
             Self::LinearizationFailed(msg) => write!(f, "Linearization failed: {}", msg),
             Self::TriangulationFailed(msg) => write!(f, "Triangulation failed: {}", msg),
             Self::ExtrusionFailed(msg) => write!(f, "Extrusion failed: {}", msg),
             Self::InvalidQuality(q) => write!(f, "Invalid quality parameter: {}", q),
-            Self::NoOutline => write!(f, "Glyph has no outline"),
+            Self::EmptyOutline => write!(f, "Glyph has an empty outline"),
+            Self::NoGlyphData => write!(f, "Font has no outline data for the glyph"),
+            Self::ComplexityExceeded(n) => {
+                write!(f, "Glyph too complex: {} contours exceeds the limit", n)
+            }
+            Self::InvalidMeshData(msg) => write!(f, "Invalid mesh data: {}", msg),
+            Self::MissingMetric(name) => write!(f, "Font has no {} metric", name),
+            Self::InvalidParameter(msg) => write!(f, "Invalid parameter: {}", msg),
         }
     }
 }
@@ -0,0 +1,337 @@
+//! Binary glTF 2.0 (`.glb`) export for meshes (requires the `export` feature)
+//!
+//! A mesh is written as a single triangle-list primitive with `POSITION` and
+//! `NORMAL` accessors and a `u32` index buffer, plus `TEXCOORD_0`, `TANGENT`
+//! and `COLOR_0` when the mesh has one UV, tangent or color per vertex. The
+//! file holds one scene with one node, so it can be loaded as is by three.js,
+//! Bevy, Blender and other glTF pipelines.
+//!
+//! glTF puts the texture origin at the top-left corner, so `v` is flipped
+//! (`1 - v`) on the way out; textures line up as they do with the mesh's own
+//! bottom-left UVs.
+
+use crate::types::Mesh3D;
+use std::io::{self, Write};
+
+/// Magic bytes at the start of every binary glTF file
+const GLB_MAGIC: [u8; 4] = *b"glTF";
+/// Binary glTF container version
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+impl Mesh3D {
+    /// Encode this mesh as a binary glTF 2.0 (`.glb`) file
+    ///
+    /// See the [module documentation](crate::export) for what is written.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'A', 0.1, 20)?;
+    ///
+    /// let glb = mesh.to_gltf();
+    /// assert_eq!(&glb[0..4], b"glTF");
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn to_gltf(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_gltf(&mut out)
+            .expect("writing to a Vec cannot fail");
+        out
+    }
+
+    /// Write this mesh as a binary glTF 2.0 (`.glb`) file
+    ///
+    /// Same output as [`Mesh3D::to_gltf`], streamed to `writer`.
+    ///
+    /// # Errors
+    /// Returns any error from `writer`.
+    ///
+    /// Example
+    /// ```no_run
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'A', 0.1, 20)?;
+    ///
+    /// mesh.write_gltf(BufWriter::new(File::create("A.glb")?))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_gltf<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let (json, bin) = self.gltf_chunks();
+        let json_len = json.len().next_multiple_of(4);
+        let bin_len = bin.len().next_multiple_of(4);
+        let mut total = 12 + 8 + json_len;
+        if !bin.is_empty() {
+            total += 8 + bin_len;
+        }
+
+        writer.write_all(&GLB_MAGIC)?;
+        writer.write_all(&GLB_VERSION.to_le_bytes())?;
+        writer.write_all(&(total as u32).to_le_bytes())?;
+
+        // The JSON chunk is padded with spaces, the binary one with zeros
+        writer.write_all(&(json_len as u32).to_le_bytes())?;
+        writer.write_all(&CHUNK_JSON.to_le_bytes())?;
+        writer.write_all(json.as_bytes())?;
+        writer.write_all(&b"   "[..json_len - json.len()])?;
+
+        if !bin.is_empty() {
+            writer.write_all(&(bin_len as u32).to_le_bytes())?;
+            writer.write_all(&CHUNK_BIN.to_le_bytes())?;
+            writer.write_all(&bin)?;
+            writer.write_all(&[0; 3][..bin_len - bin.len()])?;
+        }
+        writer.flush()
+    }
+
+    /// Build the JSON document and the binary buffer it describes
+    fn gltf_chunks(&self) -> (String, Vec<u8>) {
+        let n = self.vertices.len();
+        let asset = r#""asset":{"version":"2.0","generator":"fontmesh"}"#;
+        if n == 0 {
+            // glTF accessors can't be empty, so an empty mesh is an empty scene
+            return (
+                format!(r#"{{{},"scene":0,"scenes":[{{}}]}}"#, asset),
+                Vec::new(),
+            );
+        }
+
+        let mut bin = Vec::new();
+        let mut views = Vec::new();
+        let mut accessors = Vec::new();
+        let mut attributes = Vec::new();
+        let mut add =
+            |bin: &mut Vec<u8>, data: &[f32], kind: &str, bounds: Option<&str>, semantic: &str| {
+                let offset = bin.len();
+                data.iter()
+                    .for_each(|x| bin.extend_from_slice(&x.to_le_bytes()));
+                views.push(format!(
+                    r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+                    offset,
+                    bin.len() - offset,
+                    ARRAY_BUFFER
+                ));
+                accessors.push(format!(
+                    r#"{{"bufferView":{},"componentType":{},"count":{},"type":"{}"{}}}"#,
+                    views.len() - 1,
+                    FLOAT,
+                    n,
+                    kind,
+                    bounds.unwrap_or_default()
+                ));
+                attributes.push(format!(r#""{}":{}"#, semantic, accessors.len() - 1));
+            };
+
+        let (min, max) = self.aabb();
+        let bounds = format!(
+            r#","min":[{},{},{}],"max":[{},{},{}]"#,
+            min.x, min.y, min.z, max.x, max.y, max.z
+        );
+        let positions: Vec<f32> = self.vertices.iter().flat_map(|v| v.to_array()).collect();
+        add(&mut bin, &positions, "VEC3", Some(&bounds), "POSITION");
+        if self.normals.len() == n {
+            let normals: Vec<f32> = self.normals.iter().flat_map(|v| v.to_array()).collect();
+            add(&mut bin, &normals, "VEC3", None, "NORMAL");
+        }
+        if self.uvs.len() == n {
+            let uvs: Vec<f32> = self.uvs.iter().flat_map(|uv| [uv.x, 1.0 - uv.y]).collect();
+            add(&mut bin, &uvs, "VEC2", None, "TEXCOORD_0");
+        }
+        if self.tangents.len() == n {
+            let tangents: Vec<f32> = self.tangents.iter().flat_map(|t| t.to_array()).collect();
+            add(&mut bin, &tangents, "VEC4", None, "TANGENT");
+        }
+        if self.colors.len() == n {
+            let colors: Vec<f32> = self.colors.iter().flatten().copied().collect();
+            add(&mut bin, &colors, "VEC4", None, "COLOR_0");
+        }
+
+        let mut primitive = format!(r#""attributes":{{{}}},"mode":4"#, attributes.join(","));
+        if !self.indices.is_empty() {
+            let offset = bin.len();
+            self.indices
+                .iter()
+                .for_each(|i| bin.extend_from_slice(&i.to_le_bytes()));
+            views.push(format!(
+                r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+                offset,
+                bin.len() - offset,
+                ELEMENT_ARRAY_BUFFER
+            ));
+            accessors.push(format!(
+                r#"{{"bufferView":{},"componentType":{},"count":{},"type":"SCALAR"}}"#,
+                views.len() - 1,
+                UNSIGNED_INT,
+                self.indices.len()
+            ));
+            primitive += &format!(r#","indices":{}"#, accessors.len() - 1);
+        }
+
+        let json = format!(
+            concat!(
+                r#"{{{},"scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0}}],"#,
+                r#""meshes":[{{"primitives":[{{{}}}]}}],"#,
+                r#""buffers":[{{"byteLength":{}}}],"bufferViews":[{}],"accessors":[{}]}}"#
+            ),
+            asset,
+            primitive,
+            bin.len(),
+            views.join(","),
+            accessors.join(",")
+        );
+        (json, bin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{Vec2, Vec3, Vec4};
+    use serde_json::Value;
+
+    /// Split a GLB into its parsed JSON and binary chunks, checking the container
+    fn parse_glb(glb: &[u8]) -> (Value, &[u8]) {
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(glb[offset..offset + 4].try_into().unwrap());
+        assert_eq!(glb[0..4], GLB_MAGIC);
+        assert_eq!(u32_at(4), 2);
+        assert_eq!(u32_at(8) as usize, glb.len());
+        assert_eq!(glb.len() % 4, 0);
+
+        let json_len = u32_at(12) as usize;
+        assert_eq!(u32_at(16), CHUNK_JSON);
+        let json = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+        let bin_start = 20 + json_len;
+        if bin_start == glb.len() {
+            return (json, &[]);
+        }
+        assert_eq!(u32_at(bin_start + 4), CHUNK_BIN);
+        let bin_len = u32_at(bin_start) as usize;
+        (json, &glb[bin_start + 8..bin_start + 8 + bin_len])
+    }
+
+    /// Read the data of the accessor with index `accessor` as raw 4-byte words
+    fn read_accessor(json: &Value, bin: &[u8], accessor: &Value) -> Vec<[u8; 4]> {
+        let accessor = &json["accessors"][accessor.as_u64().unwrap() as usize];
+        let view = &json["bufferViews"][accessor["bufferView"].as_u64().unwrap() as usize];
+        let offset = view["byteOffset"].as_u64().unwrap() as usize;
+        let length = view["byteLength"].as_u64().unwrap() as usize;
+        let components = match accessor["type"].as_str().unwrap() {
+            "SCALAR" => 1,
+            "VEC2" => 2,
+            "VEC3" => 3,
+            "VEC4" => 4,
+            other => panic!("unexpected accessor type {}", other),
+        };
+        assert_eq!(
+            accessor["count"].as_u64().unwrap() as usize * components * 4,
+            length
+        );
+        bin[offset..offset + length]
+            .chunks_exact(4)
+            .map(|w| w.try_into().unwrap())
+            .collect()
+    }
+
+    fn floats(words: Vec<[u8; 4]>) -> Vec<f32> {
+        words.into_iter().map(f32::from_le_bytes).collect()
+    }
+
+    fn quad() -> Mesh3D {
+        Mesh3D {
+            vertices: vec![
+                Vec3::new(0.0, 0.0, 0.5),
+                Vec3::new(1.0, 0.0, 0.5),
+                Vec3::new(1.0, 1.0, 0.5),
+                Vec3::new(0.0, 1.0, 0.5),
+            ],
+            normals: vec![Vec3::Z; 4],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_gltf_round_trip() {
+        let mesh = quad();
+        let glb = mesh.to_gltf();
+        let (json, bin) = parse_glb(&glb);
+
+        assert_eq!(json["asset"]["version"], "2.0");
+        let primitive = &json["meshes"][0]["primitives"][0];
+        let attributes = primitive["attributes"].as_object().unwrap();
+        assert_eq!(attributes.len(), 2);
+
+        let position = &json["accessors"][attributes["POSITION"].as_u64().unwrap() as usize];
+        let bound = |key: &str| -> Vec<f64> {
+            position[key]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|x| x.as_f64().unwrap())
+                .collect()
+        };
+        assert_eq!(bound("min"), [0.0, 0.0, 0.5]);
+        assert_eq!(bound("max"), [1.0, 1.0, 0.5]);
+
+        let positions = floats(read_accessor(&json, bin, &attributes["POSITION"]));
+        let expected: Vec<f32> = mesh.vertices.iter().flat_map(|v| v.to_array()).collect();
+        assert_eq!(positions, expected);
+        let normals = floats(read_accessor(&json, bin, &attributes["NORMAL"]));
+        assert!(normals.chunks_exact(3).all(|n| n == [0.0, 0.0, 1.0]));
+        let indices: Vec<u32> = read_accessor(&json, bin, &primitive["indices"])
+            .into_iter()
+            .map(u32::from_le_bytes)
+            .collect();
+        assert_eq!(indices, mesh.indices);
+
+        let mut streamed = Vec::new();
+        mesh.write_gltf(&mut streamed).unwrap();
+        assert_eq!(streamed, glb);
+    }
+
+    #[test]
+    fn test_gltf_optional_attributes() {
+        let mut mesh = quad();
+        mesh.uvs = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        mesh.tangents = vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 4];
+        let glb = mesh.to_gltf();
+        let (json, bin) = parse_glb(&glb);
+
+        let attributes = &json["meshes"][0]["primitives"][0]["attributes"];
+        assert_eq!(attributes.as_object().unwrap().len(), 4);
+        // v is flipped to glTF's top-left texture origin
+        let uvs = floats(read_accessor(&json, bin, &attributes["TEXCOORD_0"]));
+        assert_eq!(uvs, [0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+        let tangents = floats(read_accessor(&json, bin, &attributes["TANGENT"]));
+        assert!(tangents.chunks_exact(4).all(|t| t == [1.0, 0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_gltf_empty_mesh() {
+        let glb = Mesh3D::new().to_gltf();
+        let (json, bin) = parse_glb(&glb);
+        assert!(json.get("meshes").is_none());
+        assert!(bin.is_empty());
+    }
+}
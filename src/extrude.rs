@@ -1,9 +1,169 @@
 //! 3D extrusion - converts 2D meshes to 3D with depth
 
-use crate::error::Result;
-use crate::types::{Mesh2D, Mesh3D, Outline2D};
-use glam::Vec3;
-use rustc_hash::FxHashMap;
+use crate::error::{FontMeshError, Result};
+use crate::types::{Contour, Mesh2D, Mesh3D, Outline2D};
+use glam::{Vec2, Vec3};
+use rustc_hash::{FxHashMap, FxHashSet};
+use ttf_parser::Face;
+
+/// How texture coordinates are generated for the front and back caps
+///
+/// Side faces always use arc-length UVs: `u` runs along the contour and `v`
+/// from the front to the back, at the same texel density as the caps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UvMode {
+    /// Cap UVs span `0..1` across the glyph's bounding box
+    ///
+    /// A texture is stretched over each glyph separately.
+    PerGlyphBounds,
+    /// Cap UVs are the vertex `xy` multiplied by `scale`
+    ///
+    /// Glyphs laid out side by side continue the same texture instead of
+    /// restarting it per letter. Positions are taken as given, so mesh glyphs
+    /// from outlines already moved to their pen position, or add
+    /// `pen * scale` to the UVs of a glyph placed at `pen`.
+    WorldPlanar {
+        /// UV units per outline unit (1.0 em for fontmesh outlines)
+        scale: f32,
+    },
+}
+
+/// How normals are assigned to the front and back cap vertices
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CapNormals {
+    /// Every cap normal points straight along +Z (front) or -Z (back)
+    #[default]
+    Flat,
+    /// Normals tilt outward near the contour, like the surface of a lens
+    ///
+    /// A vertex at distance `d` from the nearest outline edge is tilted
+    /// toward that edge's outward side normal by a spherical profile: fully
+    /// sideways on the boundary, straight along Z from `falloff` inward.
+    /// Triangulated outlines have every vertex on the boundary, so each cap
+    /// triangle is split at its centroid to give the profile somewhere to
+    /// level out. Only the shading changes; the caps stay flat.
+    Bulge {
+        /// Distance from the boundary over which the normals level out
+        falloff: f32,
+    },
+}
+
+/// An extrusion depth, in em or relative to the font's cap height
+///
+/// A depth in em only means something once the em is known; tying it to
+/// the cap height instead makes `RelativeToCapHeight(0.3)` look equally deep
+/// in every font. Fonts without a cap height are treated as having one of
+/// 0.7 em.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExtrudeDepth {
+    /// A depth in em, as taken by [`extrude`]
+    Em(f32),
+    /// A fraction of the font's cap height, see [`crate::cap_height`]
+    RelativeToCapHeight(f32),
+}
+
+impl ExtrudeDepth {
+    /// The depth in em for glyphs of `face`
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, ExtrudeDepth, cap_height};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let depth = ExtrudeDepth::RelativeToCapHeight(0.3).resolve(&face);
+    /// assert_eq!(depth, 0.3 * cap_height(&face).unwrap());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn resolve(self, face: &Face) -> f32 {
+        match self {
+            ExtrudeDepth::Em(depth) => depth,
+            ExtrudeDepth::RelativeToCapHeight(fraction) => {
+                let cap_height =
+                    crate::font::cap_height(face).unwrap_or(crate::font::FALLBACK_CAP_HEIGHT);
+                fraction * cap_height
+            }
+        }
+    }
+}
+
+impl From<f32> for ExtrudeDepth {
+    fn from(depth: f32) -> Self {
+        ExtrudeDepth::Em(depth)
+    }
+}
+
+/// The shape of the side walls between the front and back caps
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ExtrudeMode {
+    /// The back cap is an exact copy of the front: straight, vertical walls
+    #[default]
+    Straight,
+    /// The back cap is scaled about the glyph's centroid, like a stamp
+    ///
+    /// Walls slope from the front outline to the scaled back outline and get
+    /// normals tilted to match, giving a pyramid-like cross-section. A
+    /// `scale` below 1.0 shrinks the back, above 1.0 flares it. Unlike a
+    /// draft offset the change is proportional, so thin strokes and wide
+    /// bowls taper alike. For arbitrary back outlines see [`extrude_loft`].
+    Relief {
+        /// Back outline size relative to the front (positive and finite)
+        scale: f32,
+    },
+}
+
+/// Options for [`extrude_with`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ExtrudeOptions {
+    /// Generate texture coordinates into [`Mesh3D::uvs`] using this mode
+    ///
+    /// `None` (the default) leaves `uvs` empty.
+    pub uv_mode: Option<UvMode>,
+    /// Build the side walls from the cap boundary vertices instead of
+    /// duplicating them
+    ///
+    /// Every outline point is matched to the lyon vertex at the same position
+    /// and shared between the cap and the two side quads that meet there,
+    /// giving a smaller, manifold (watertight) mesh in one pass. The price is
+    /// one normal per rim vertex: shared vertices get the normalized sum of
+    /// the cap and side normals, so the rim shades as a soft edge. With a
+    /// `uv_mode`, every vertex gets the planar cap UV. Points lyon didn't
+    /// keep fall back to unwelded side vertices.
+    pub weld_sides: bool,
+    /// How to shade the front and back caps
+    pub cap_normals: CapNormals,
+    /// Straight or sloped side walls
+    ///
+    /// `weld_sides` has no effect on [`ExtrudeMode::Relief`] walls, whose
+    /// back rim doesn't coincide with the front one.
+    pub mode: ExtrudeMode,
+}
+
+/// The cross-section of a bevel, see [`extrude_beveled`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum BevelStyle {
+    /// A single flat 45° cut, creased against the cap and the side wall
+    #[default]
+    Chamfer,
+    /// A quarter circle, shaded smoothly from the cap into the side wall
+    Round,
+}
+
+/// Options for [`extrude_beveled`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BevelOptions {
+    /// How far the bevel cuts into the caps and down the side walls
+    ///
+    /// In outline units (em for fontmesh outlines); at most half the depth.
+    pub width: f32,
+    /// Number of bands a [`BevelStyle::Round`] bevel is built from
+    ///
+    /// Clamped to at least 1. Chamfers are always a single band.
+    pub segments: u8,
+    /// The bevel's cross-section
+    pub style: BevelStyle,
+}
 
 /// Extrude a 2D mesh into 3D with the given depth
 ///
@@ -17,12 +177,135 @@ use rustc_hash::FxHashMap;
 /// * `outline` - The original outline (used for edge detection)
 /// * `depth` - The extrusion depth
 ///
+/// The outline must be the one `mesh_2d` was triangulated from, linearized
+/// the same way; otherwise the side walls won't meet the caps. Nothing
+/// checks this unless the `debug-checks` feature is enabled, which panics
+/// on an outline point that isn't a vertex of the mesh.
+///
 /// # Returns
 /// A 3D triangle mesh with normals
 #[inline]
 pub fn extrude(mesh_2d: &Mesh2D, outline: &Outline2D, depth: f32) -> Result<Mesh3D> {
+    extrude_with(mesh_2d, outline, depth, &ExtrudeOptions::default())
+}
+
+/// Extrude a 2D mesh into 3D with the given depth and options
+///
+/// Behaves like [`extrude`], additionally generating the data requested by
+/// `options`.
+///
+/// # Arguments
+/// * `mesh_2d` - The 2D triangle mesh to extrude
+/// * `outline` - The original outline (used for edge detection)
+/// * `depth` - The extrusion depth
+/// * `options` - Extra outputs to generate
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidParameter`] for a relief scale that
+/// isn't positive and finite.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph};
+/// use fontmesh::extrude::{extrude_with, ExtrudeOptions, UvMode};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let outline = Glyph::new(&face, 'A')?.with_subdivisions(20).to_outline()?;
+/// let mesh_2d = outline.triangulate()?;
+///
+/// let options = ExtrudeOptions {
+///     uv_mode: Some(UvMode::WorldPlanar { scale: 1.0 }),
+///     ..Default::default()
+/// };
+/// let mesh = extrude_with(&mesh_2d, &outline, 0.1, &options)?;
+/// assert_eq!(mesh.uvs.len(), mesh.vertices.len());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn extrude_with(
+    mesh_2d: &Mesh2D,
+    outline: &Outline2D,
+    depth: f32,
+    options: &ExtrudeOptions,
+) -> Result<Mesh3D> {
+    let mut mesh_3d = Mesh3D::new();
+    extrude_into(mesh_2d, outline, depth, options, &mut mesh_3d)?;
+    Ok(mesh_3d)
+}
+
+/// Extrude a 2D mesh into an existing 3D mesh
+///
+/// Produces exactly the same mesh as [`extrude_with`], but writes it into
+/// `out`, replacing its contents. The vertex, normal and index buffers keep
+/// their capacity, so extruding a batch of glyphs into the same mesh stops
+/// allocating once they have grown to fit the largest one.
+///
+/// # Arguments
+/// * `mesh_2d` - The 2D triangle mesh to extrude
+/// * `outline` - The original outline (used for edge detection)
+/// * `depth` - The extrusion depth
+/// * `options` - Extra outputs to generate
+/// * `out` - Receives the extruded mesh
+///
+/// # Errors
+/// The errors of [`extrude_with`].
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph, Mesh3D};
+/// use fontmesh::extrude::{extrude_into, ExtrudeOptions};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mut mesh = Mesh3D::new();
+/// for c in "Hello".chars() {
+///     let outline = Glyph::new(&face, c)?.with_subdivisions(20).to_outline()?;
+///     let mesh_2d = outline.triangulate()?;
+///     extrude_into(&mesh_2d, &outline, 0.1, &ExtrudeOptions::default(), &mut mesh)?;
+///     // ... upload `mesh` ...
+/// }
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn extrude_into(
+    mesh_2d: &Mesh2D,
+    outline: &Outline2D,
+    depth: f32,
+    options: &ExtrudeOptions,
+    out: &mut Mesh3D,
+) -> Result<()> {
+    #[cfg(feature = "debug-checks")]
+    crate::checks::extrude_inputs(mesh_2d, outline);
+
+    // Bulged caps need vertices away from the outline to be flat anywhere
+    let split;
+    let mesh_2d = match options.cap_normals {
+        CapNormals::Bulge { falloff } if falloff > 0.0 => {
+            split = split_at_centroids(mesh_2d);
+            &split
+        }
+        _ => mesh_2d,
+    };
+
     let half_depth = depth / 2.0;
 
+    // The back cap is `centroid + (p - centroid) * scale`
+    let relief = match options.mode {
+        ExtrudeMode::Straight => None,
+        ExtrudeMode::Relief { scale } => {
+            if !scale.is_finite() || scale <= 0.0 {
+                return Err(FontMeshError::InvalidParameter(
+                    "relief scale must be positive and finite".to_string(),
+                ));
+            }
+            Some((fill_centroid(mesh_2d), scale))
+        }
+    };
+    let to_back = |p: Vec2| match relief {
+        Some((centroid, scale)) => centroid + (p - centroid) * scale,
+        None => p,
+    };
+
     // Pre-calculate total size to avoid reallocations
     let outline_edge_count: usize = outline
         .contours
@@ -39,11 +322,16 @@ pub fn extrude(mesh_2d: &Mesh2D, outline: &Outline2D, depth: f32) -> Result<Mesh
     let total_vertices = mesh_2d.vertices.len() * 2 + outline_edge_count * 4;
     let total_indices = mesh_2d.indices.len() * 2 + outline_edge_count * 6;
 
-    let mut mesh_3d = Mesh3D {
-        vertices: Vec::with_capacity(total_vertices),
-        normals: Vec::with_capacity(total_vertices),
-        indices: Vec::with_capacity(total_indices),
-    };
+    let mesh_3d = out;
+    mesh_3d.vertices.clear();
+    mesh_3d.normals.clear();
+    mesh_3d.indices.clear();
+    mesh_3d.uvs.clear();
+    mesh_3d.colors.clear();
+    mesh_3d.tangents.clear();
+    mesh_3d.vertices.reserve(total_vertices);
+    mesh_3d.normals.reserve(total_vertices);
+    mesh_3d.indices.reserve(total_indices);
 
     // 1. Create front face (z = half_depth)
     let normal_front = Vec3::new(0.0, 0.0, 1.0);
@@ -54,93 +342,1132 @@ pub fn extrude(mesh_2d: &Mesh2D, outline: &Outline2D, depth: f32) -> Result<Mesh
         mesh_3d.normals.push(normal_front);
     });
 
-    // Add front face triangles (reversed winding to convert CW input to CCW)
-    mesh_2d.indices.chunks_exact(3).for_each(|chunk| {
-        mesh_3d.indices.push(chunk[0]);
-        mesh_3d.indices.push(chunk[2]);
-        mesh_3d.indices.push(chunk[1]);
-    });
+    push_cap_indices(&mut mesh_3d.indices, mesh_2d, 0, true);
 
     // 2. Create back face (z = -half_depth) with reversed winding
     let back_offset = mesh_3d.vertices.len() as u32;
     let normal_back = Vec3::new(0.0, 0.0, -1.0);
-    mesh_2d.vertices.iter().for_each(|vertex| {
+    mesh_2d.vertices.iter().for_each(|&vertex| {
+        mesh_3d.vertices.push(to_back(vertex).extend(-half_depth));
+        mesh_3d.normals.push(normal_back);
+    });
+
+    push_cap_indices(&mut mesh_3d.indices, mesh_2d, back_offset, false);
+
+    if let Some((centroid, scale)) = relief {
+        let mut back_outline = outline.clone();
+        back_outline.translate(-centroid);
+        back_outline.scale(scale);
+        back_outline.translate(centroid);
+
+        let cap_vertices = mesh_3d.vertices.len();
+        create_loft_faces(mesh_3d, outline, &back_outline, half_depth);
+        apply_cap_normals(mesh_3d, mesh_2d, outline, options.cap_normals, scale);
+
+        if let Some(mode) = options.uv_mode {
+            mesh_3d.uvs = relief_uvs(mesh_3d, mesh_2d, cap_vertices, mode);
+        }
+        #[cfg(feature = "debug-checks")]
+        crate::checks::extruded(mesh_3d);
+        return Ok(());
+    }
+
+    if options.weld_sides {
+        apply_cap_normals(mesh_3d, mesh_2d, outline, options.cap_normals, 1.0);
+        create_welded_side_faces(mesh_3d, mesh_2d, outline, half_depth);
+
+        if let Some(mode) = options.uv_mode {
+            let (origin, scale, _) = uv_transform(mesh_2d, mode);
+            mesh_3d.uvs = mesh_3d
+                .vertices
+                .iter()
+                .map(|v| (v.truncate() - origin) * scale)
+                .collect();
+        }
+        #[cfg(feature = "debug-checks")]
+        crate::checks::extruded(mesh_3d);
+        return Ok(());
+    }
+
+    // 3. Create side faces
+    create_side_faces(mesh_3d, outline, half_depth);
+
+    // 4. Make every triangle's winding agree with its stored normals
+    orient_triangles(mesh_3d);
+
+    // 5. Shading-only cap normals, after orientation relied on the flat ones
+    apply_cap_normals(mesh_3d, mesh_2d, outline, options.cap_normals, 1.0);
+
+    if let Some(mode) = options.uv_mode {
+        mesh_3d.uvs = extrusion_uvs(mesh_2d, outline, half_depth, mode);
+    }
+
+    #[cfg(feature = "debug-checks")]
+    crate::checks::extruded(mesh_3d);
+
+    Ok(())
+}
+
+/// Compute UVs for a mesh laid out by [`extrude_with`]
+///
+/// Relies on the vertex order produced there: front cap, back cap, then four
+/// vertices per non-degenerate side edge.
+fn extrusion_uvs(
+    mesh_2d: &Mesh2D,
+    outline: &Outline2D,
+    half_depth: f32,
+    mode: UvMode,
+) -> Vec<Vec2> {
+    let (origin, scale, density) = uv_transform(mesh_2d, mode);
+
+    let mut uvs = Vec::with_capacity(mesh_2d.vertices.len() * 2);
+    let cap = mesh_2d.vertices.iter().map(|&v| (v - origin) * scale);
+    uvs.extend(cap.clone());
+    uvs.extend(cap);
+
+    let v_back = 2.0 * half_depth.abs() * density;
+    for contour in &outline.contours {
+        let num_points = contour.points.len();
+        if num_points < 2 {
+            continue;
+        }
+        let edge_count = if contour.closed {
+            num_points
+        } else {
+            num_points - 1
+        };
+
+        let mut arc_length = 0.0;
+        for i in 0..edge_count {
+            let p0 = contour.points[i].point;
+            let p1 = contour.points[(i + 1) % num_points].point;
+            let length_sq = (p1 - p0).length_squared();
+            // Must match the degenerate-edge skip in `create_side_faces`
+            if length_sq < 1e-10 {
+                continue;
+            }
+            let u0 = arc_length * density;
+            arc_length += length_sq.sqrt();
+            let u1 = arc_length * density;
+            uvs.extend_from_slice(&[
+                Vec2::new(u0, 0.0),
+                Vec2::new(u1, 0.0),
+                Vec2::new(u1, v_back),
+                Vec2::new(u0, v_back),
+            ]);
+        }
+    }
+
+    uvs
+}
+
+/// Compute UVs for a relief mesh laid out by [`extrude_with`]
+///
+/// Both caps use the planar UVs of the front outline, so the back carries a
+/// shrunken copy of the front texture. Each side quad (front edge, then the
+/// matching back edge) continues `u` from the previous one by the length of
+/// its front edge, with `v` running down the sloped wall.
+fn relief_uvs(mesh_3d: &Mesh3D, mesh_2d: &Mesh2D, cap_vertices: usize, mode: UvMode) -> Vec<Vec2> {
+    let (origin, scale, density) = uv_transform(mesh_2d, mode);
+
+    let mut uvs = Vec::with_capacity(mesh_3d.vertices.len());
+    let cap = mesh_2d.vertices.iter().map(|&v| (v - origin) * scale);
+    uvs.extend(cap.clone());
+    uvs.extend(cap);
+
+    let mut arc_length = 0.0;
+    for quad in mesh_3d.vertices[cap_vertices..].chunks_exact(4) {
+        let [f0, f1, b1, b0] = [quad[0], quad[1], quad[2], quad[3]];
+        let u0 = arc_length * density;
+        arc_length += f0.distance(f1);
+        let u1 = arc_length * density;
+        uvs.extend_from_slice(&[
+            Vec2::new(u0, 0.0),
+            Vec2::new(u1, 0.0),
+            Vec2::new(u1, f1.distance(b1) * density),
+            Vec2::new(u0, f0.distance(b0) * density),
+        ]);
+    }
+
+    uvs
+}
+
+/// Area-weighted centroid of a triangle mesh, or its box center if it has no area
+fn fill_centroid(mesh_2d: &Mesh2D) -> Vec2 {
+    let area = mesh_2d.area();
+    if area > 0.0 {
+        let mut moment = Vec2::ZERO;
+        for tri in mesh_2d.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh_2d.vertices[i as usize]);
+            moment += (a + b + c) / 3.0 * ((b - a).perp_dot(c - a).abs() / 2.0);
+        }
+        moment / area
+    } else {
+        let (min, max) = mesh_2d.aabb();
+        (min + max) / 2.0
+    }
+}
+
+/// Split every triangle in three at its centroid
+///
+/// Boundary edges are left whole, so the side walls still meet the caps.
+fn split_at_centroids(mesh_2d: &Mesh2D) -> Mesh2D {
+    let mut split = mesh_2d.clone();
+    split.indices = Vec::with_capacity(mesh_2d.indices.len() * 3);
+    for tri in mesh_2d.indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]];
+        let [pa, pb, pc] = [a, b, c].map(|i| mesh_2d.vertices[i as usize]);
+        if (pb - pa).perp_dot(pc - pa).abs() <= f32::EPSILON {
+            split.indices.extend_from_slice(tri);
+            continue;
+        }
+
+        let center = split.vertices.len() as u32;
+        if split.boundary_mask.len() == split.vertices.len() {
+            split.boundary_mask.push(false);
+        }
+        if split.contour_ids.len() == split.vertices.len() {
+            let id = split.contour_ids[a as usize];
+            split.contour_ids.push(id);
+        }
+        split.vertices.push((pa + pb + pc) / 3.0);
+        split
+            .indices
+            .extend_from_slice(&[a, b, center, b, c, center, c, a, center]);
+    }
+    split
+}
+
+/// Replace the flat cap normals according to `mode`
+///
+/// Expects the front cap followed by the back cap at the start of the mesh,
+/// as laid out by [`extrude_with`]. The back cap is the front one scaled by
+/// `back_scale`, which scales its distances to the outline too.
+fn apply_cap_normals(
+    mesh_3d: &mut Mesh3D,
+    mesh_2d: &Mesh2D,
+    outline: &Outline2D,
+    mode: CapNormals,
+    back_scale: f32,
+) {
+    let CapNormals::Bulge { falloff } = mode else {
+        return;
+    };
+    if falloff.is_nan() || falloff <= 0.0 {
+        return;
+    }
+
+    // Every outline edge with its outward (side wall) normal
+    let fill_sides = contour_fill_sides(outline);
+    let mut edges = Vec::new();
+    for (contour, &filled_left) in outline.contours.iter().zip(&fill_sides) {
+        let n = contour.points.len();
+        let edge_count = if contour.closed {
+            n
+        } else {
+            n.saturating_sub(1)
+        };
+        for i in 0..edge_count {
+            let p0 = contour.points[i].point;
+            let p1 = contour.points[(i + 1) % n].point;
+            let dir = (p1 - p0).normalize_or_zero();
+            if dir != Vec2::ZERO {
+                let outward = if filled_left { -dir.perp() } else { dir.perp() };
+                edges.push((p0, p1, outward));
+            }
+        }
+    }
+    if edges.is_empty() {
+        return;
+    }
+
+    let back_offset = mesh_2d.vertices.len();
+    for (i, &p) in mesh_2d.vertices.iter().enumerate() {
+        // Nearest edge; vertices on a corner average the edges meeting there
+        let mut nearest = f32::INFINITY;
+        let mut side = Vec2::ZERO;
+        for &(a, b, outward) in &edges {
+            let distance = distance_to_segment(p, a, b);
+            if distance < nearest - 1e-6 {
+                nearest = distance;
+                side = outward;
+            } else if distance <= nearest + 1e-6 {
+                side += outward;
+            }
+        }
+
+        let side = side.normalize_or_zero();
+        let bulge = |distance: f32| {
+            let tilted = side * (1.0 - distance / falloff).clamp(0.0, 1.0);
+            tilted.extend((1.0 - tilted.length_squared()).max(0.0).sqrt())
+        };
+        mesh_3d.normals[i] = bulge(nearest);
+        mesh_3d.normals[back_offset + i] = bulge(nearest * back_scale) * Vec3::new(1.0, 1.0, -1.0);
+    }
+}
+
+/// Whether any two edges of the outline cross or touch, other than
+/// neighbours along a contour
+fn has_crossings(outline: &Outline2D) -> bool {
+    // (contour, edge index, edge count, segment) of every non-degenerate edge
+    let mut edges = Vec::new();
+    for (c, contour) in outline.contours.iter().enumerate() {
+        let n = contour.points.len();
+        let edge_count = if contour.closed {
+            n
+        } else {
+            n.saturating_sub(1)
+        };
+        for i in 0..edge_count {
+            let (a, b) = (contour.points[i].point, contour.points[(i + 1) % n].point);
+            if a != b {
+                edges.push((c, i, edge_count, (a, b)));
+            }
+        }
+    }
+
+    edges.iter().enumerate().any(|(k, &(c0, i0, count, e0))| {
+        edges[k + 1..].iter().any(|&(c1, i1, _, e1)| {
+            let adjacent = c0 == c1 && (i1 - i0 == 1 || (i0 == 0 && i1 + 1 == count));
+            !adjacent && crate::types::segment_intersection(e0, e1).is_some()
+        })
+    })
+}
+
+/// Width or height of the outline's bounds, whichever is larger
+fn outline_extent(outline: &Outline2D) -> f32 {
+    outline.bounds().map_or(0.0, |[min, max]| {
+        (Vec2::from(max) - Vec2::from(min)).max_element()
+    })
+}
+
+/// The outer boundary of the area filled by `outline` under the non-zero rule
+fn union_outline(outline: &Outline2D) -> Result<Outline2D> {
+    // The footprint's tolerances are in font units; bring the outline to a
+    // comparable size and back
+    let scale = 1000.0 / outline_extent(outline).max(f32::EPSILON);
+    let mut scaled = outline.clone();
+    scaled.scale(scale);
+
+    let mut union = Outline2D::new();
+    for contour in crate::layout::union_footprint(&scaled)?.loops {
+        union.add_contour(contour);
+    }
+    union.scale(scale.recip());
+    Ok(union)
+}
+
+/// Distance from `p` to the segment `a`-`b`
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0);
+    p.distance(a + ab * t)
+}
+
+/// Cap UV = (xy - origin) * scale; sides use a uniform `density`
+fn uv_transform(mesh_2d: &Mesh2D, mode: UvMode) -> (Vec2, Vec2, f32) {
+    match mode {
+        UvMode::PerGlyphBounds => {
+            let (min, max) = mesh_2d.aabb();
+            let size = (max - min).max(Vec2::splat(f32::EPSILON));
+            (min, size.recip(), size.max_element().recip())
+        }
+        UvMode::WorldPlanar { scale } => (Vec2::ZERO, Vec2::splat(scale), scale),
+    }
+}
+
+/// Extrude with independent front and back outlines, lofting the sides between them
+///
+/// The front cap comes from `front_mesh` at z = +depth/2 and the back cap is
+/// triangulated from `back_outline` at z = -depth/2. Side quads connect point
+/// `i` of each front contour to point `i` of the matching back contour, so a
+/// rotated or scaled back outline produces twisted or tapered walls.
+///
+/// # Arguments
+/// * `front_mesh` - The triangulated front outline
+/// * `front_outline` - The linearized front outline
+/// * `back_outline` - The linearized back outline
+/// * `depth` - The extrusion depth
+///
+/// # Errors
+/// Returns [`FontMeshError::ExtrusionFailed`] if the outlines don't have the
+/// same number of contours, or if any pair of corresponding contours has a
+/// different number of points.
+pub fn extrude_loft(
+    front_mesh: &Mesh2D,
+    front_outline: &Outline2D,
+    back_outline: &Outline2D,
+    depth: f32,
+) -> Result<Mesh3D> {
+    if !depth.is_finite() {
+        return Err(FontMeshError::ExtrusionFailed(
+            "depth must be a finite value".to_string(),
+        ));
+    }
+    if front_outline.contours.len() != back_outline.contours.len() {
+        return Err(FontMeshError::ExtrusionFailed(format!(
+            "front outline has {} contours but back outline has {}",
+            front_outline.contours.len(),
+            back_outline.contours.len()
+        )));
+    }
+    for (i, (front, back)) in front_outline
+        .contours
+        .iter()
+        .zip(&back_outline.contours)
+        .enumerate()
+    {
+        if front.points.len() != back.points.len() {
+            return Err(FontMeshError::ExtrusionFailed(format!(
+                "contour {} has {} front points but {} back points",
+                i,
+                front.points.len(),
+                back.points.len()
+            )));
+        }
+    }
+
+    let back_mesh = crate::triangulate::triangulate(back_outline)?;
+    let half_depth = depth / 2.0;
+    let mut mesh_3d = Mesh3D::new();
+
+    let normal_front = Vec3::new(0.0, 0.0, 1.0);
+    for vertex in &front_mesh.vertices {
+        mesh_3d
+            .vertices
+            .push(Vec3::new(vertex.x, vertex.y, half_depth));
+        mesh_3d.normals.push(normal_front);
+    }
+    push_cap_indices(&mut mesh_3d.indices, front_mesh, 0, true);
+
+    let back_offset = mesh_3d.vertices.len() as u32;
+    let normal_back = Vec3::new(0.0, 0.0, -1.0);
+    for vertex in &back_mesh.vertices {
         mesh_3d
             .vertices
             .push(Vec3::new(vertex.x, vertex.y, -half_depth));
         mesh_3d.normals.push(normal_back);
-    });
+    }
+    push_cap_indices(&mut mesh_3d.indices, &back_mesh, back_offset, false);
 
-    // Add back face triangles (keep original CW winding so it faces back)
-    mesh_2d.indices.chunks_exact(3).for_each(|chunk| {
-        mesh_3d.indices.push(back_offset + chunk[0]);
-        mesh_3d.indices.push(back_offset + chunk[1]);
-        mesh_3d.indices.push(back_offset + chunk[2]);
-    });
+    create_loft_faces(&mut mesh_3d, front_outline, back_outline, half_depth);
+
+    Ok(mesh_3d)
+}
+
+/// Append a cap's triangles, wound counter-clockwise seen from outside
+///
+/// A front cap faces +Z and a back cap -Z. lyon emits clockwise triangles,
+/// but meshes from elsewhere may not, so the dominant winding decides which
+/// corners to swap instead of assuming it.
+fn push_cap_indices(indices: &mut Vec<u32>, mesh_2d: &Mesh2D, offset: u32, front: bool) {
+    let swap = is_clockwise(mesh_2d) == front;
+    indices.extend(mesh_2d.indices.chunks_exact(3).flat_map(|tri| {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| offset + i);
+        if swap {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        }
+    }));
+}
+
+/// Extrude a 2D mesh into 3D with beveled edges, for the classic "3D title" look
+///
+/// Like [`extrude`], but the front and back caps are inset by `bevel.width`
+/// and joined to the side walls by a bevel that also reaches `bevel.width`
+/// down the walls, so the glyph keeps its outer silhouette. A
+/// [`BevelStyle::Chamfer`] is one flat 45° band with creased edges; a
+/// [`BevelStyle::Round`] is a quarter circle of `bevel.segments` bands whose
+/// normals blend smoothly from the cap into the wall.
+///
+/// The caps are triangulated anew from `outline` inset by the width, so
+/// `mesh_2d` is only used as is for a width of 0, which gives the plain
+/// [`extrude`]. Contours that cross themselves or each other, as strokes
+/// of script fonts often do, are replaced by the boundary of their union
+/// first. UVs and the other [`ExtrudeOptions`] outputs aren't generated.
+///
+/// # Arguments
+/// * `mesh_2d` - The 2D triangle mesh to extrude
+/// * `outline` - The original outline (used for edge detection)
+/// * `depth` - The extrusion depth
+/// * `bevel` - Bevel width, style and smoothness
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidParameter`] if the width is negative,
+/// not finite or more than half the depth, and
+/// [`FontMeshError::ExtrusionFailed`] if `depth` isn't finite or if
+/// insetting the caps by the width would fold them over or make them
+/// overlap: bevels wider than half a stroke, or than the radius of a tight
+/// curve, don't fit.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph};
+/// use fontmesh::extrude::{extrude_beveled, BevelOptions, BevelStyle};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let outline = Glyph::new(&face, 'A')?.with_subdivisions(20).to_outline()?;
+/// let mesh_2d = outline.triangulate()?;
+///
+/// let bevel = BevelOptions {
+///     width: 0.01,
+///     segments: 4,
+///     style: BevelStyle::Round,
+/// };
+/// let mesh = extrude_beveled(&mesh_2d, &outline, 0.1, bevel)?;
+/// assert!(mesh.is_solid());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn extrude_beveled(
+    mesh_2d: &Mesh2D,
+    outline: &Outline2D,
+    depth: f32,
+    bevel: BevelOptions,
+) -> Result<Mesh3D> {
+    let width = bevel.width;
+    if !depth.is_finite() {
+        return Err(FontMeshError::ExtrusionFailed(
+            "depth must be a finite value".to_string(),
+        ));
+    }
+    if !width.is_finite() || width < 0.0 {
+        return Err(FontMeshError::InvalidParameter(
+            "bevel width must be non-negative and finite".to_string(),
+        ));
+    }
+    if width * 2.0 > depth {
+        return Err(FontMeshError::InvalidParameter(format!(
+            "bevel width {} is more than half the depth {}",
+            width, depth
+        )));
+    }
+    if width == 0.0 {
+        return extrude(mesh_2d, outline, depth);
+    }
+
+    #[cfg(feature = "debug-checks")]
+    crate::checks::extrude_inputs(mesh_2d, outline);
+
+    // Overlapping strokes would be inset into each other; bevel the
+    // boundary of their union instead
+    let union;
+    let outline = if has_crossings(outline) {
+        union = union_outline(outline)?;
+        &union
+    } else {
+        outline
+    };
+
+    let half_depth = depth / 2.0;
+    let wall_half_depth = half_depth - width;
+    let fill_sides = contour_fill_sides(outline);
+    let miters: Vec<Vec<Vec2>> = outline
+        .contours
+        .iter()
+        .zip(&fill_sides)
+        .map(|(contour, &filled_left)| contour.miter_offsets(filled_left))
+        .collect();
+    // Cap rims and bevel rings must compute the same positions bit for bit,
+    // or the mesh won't be closed
+    let inset = |point: Vec2, miter: Vec2, distance: f32| point - miter * distance;
+
+    // The caps are the outline inset by the width. A contour that's too
+    // thin or too tightly curved for it folds over, reversing edges
+    let mut cap_outline = Outline2D::new();
+    for (contour, contour_miters) in outline.contours.iter().zip(&miters) {
+        let mut cap_contour = Contour::new(contour.closed);
+        for (cp, &miter) in contour.points.iter().zip(contour_miters) {
+            cap_contour.push_on_curve(inset(cp.point, miter, width));
+        }
+        let n = contour.points.len();
+        let edge_count = if contour.closed {
+            n
+        } else {
+            n.saturating_sub(1)
+        };
+        let folded = (0..edge_count).any(|i| {
+            let j = (i + 1) % n;
+            let before = contour.points[j].point - contour.points[i].point;
+            let after = cap_contour.points[j].point - cap_contour.points[i].point;
+            before.dot(after) < 0.0
+        });
+        if folded {
+            return Err(FontMeshError::ExtrusionFailed(format!(
+                "bevel width {} folds the caps over; the outline is too thin for it",
+                width
+            )));
+        }
+        cap_outline.add_contour(cap_contour);
+    }
+    let cap = crate::triangulate::triangulate(&cap_outline)?;
+    // Insets that run into each other or get too thin to triangulate leave
+    // the caps with a different rim, which the bevel wouldn't meet
+    let edge_key = |a: Vec2, b: Vec2| {
+        let (a, b) = (point_key(a), point_key(b));
+        if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    let rim: FxHashSet<_> = cap_outline
+        .contours
+        .iter()
+        .flat_map(|c| {
+            let n = c.points.len();
+            (0..n).map(move |i| edge_key(c.points[i].point, c.points[(i + 1) % n].point))
+        })
+        .filter(|(a, b)| a != b)
+        .collect();
+    let mut cap_edges: FxHashMap<_, u32> = FxHashMap::default();
+    for tri in cap.indices.chunks_exact(3) {
+        for k in 0..3 {
+            let [a, b] = [tri[k], tri[(k + 1) % 3]].map(|i| cap.vertices[i as usize]);
+            *cap_edges.entry(edge_key(a, b)).or_default() += 1;
+        }
+    }
+    let cap_rim: FxHashSet<_> = cap_edges
+        .into_iter()
+        .filter_map(|(edge, uses)| (uses == 1).then_some(edge))
+        .collect();
+    if cap_rim != rim {
+        return Err(FontMeshError::ExtrusionFailed(format!(
+            "bevel width {} makes the caps overlap; the outline is too thin for it",
+            width
+        )));
+    }
+
+    let mut mesh_3d = Mesh3D::new();
+    for (z, normal, front) in [
+        (half_depth, Vec3::Z, true),
+        (-half_depth, Vec3::NEG_Z, false),
+    ] {
+        let offset = mesh_3d.vertices.len() as u32;
+        mesh_3d
+            .vertices
+            .extend(cap.vertices.iter().map(|v| v.extend(z)));
+        mesh_3d
+            .normals
+            .extend(std::iter::repeat_n(normal, cap.vertices.len()));
+        push_cap_indices(&mut mesh_3d.indices, &cap, offset, front);
+    }
+
+    create_side_faces(&mut mesh_3d, outline, wall_half_depth);
+
+    // Rings of the bevel from the cap rim to the top of the wall: (inset,
+    // drop below the cap, angle of the normal from the cap normal)
+    let quarter = std::f32::consts::FRAC_PI_2;
+    let profile: Vec<(f32, f32, f32)> = match bevel.style {
+        BevelStyle::Chamfer => vec![(width, 0.0, quarter / 2.0), (0.0, width, quarter / 2.0)],
+        BevelStyle::Round => {
+            let segments = bevel.segments.max(1) as usize;
+            (0..=segments)
+                .map(|k| {
+                    let angle = k as f32 / segments as f32 * quarter;
+                    match k {
+                        0 => (width, 0.0, 0.0),
+                        k if k == segments => (0.0, width, quarter),
+                        _ => (
+                            width * (1.0 - angle.sin()),
+                            width * (1.0 - angle.cos()),
+                            angle,
+                        ),
+                    }
+                })
+                .collect()
+        }
+    };
+
+    for ((contour, contour_miters), &filled_left) in
+        outline.contours.iter().zip(&miters).zip(&fill_sides)
+    {
+        let num_points = contour.points.len();
+        if num_points < 2 {
+            continue;
+        }
+        let edge_count = if contour.closed {
+            num_points
+        } else {
+            num_points - 1
+        };
+
+        for i in 0..edge_count {
+            let next = (i + 1) % num_points;
+            let (p0, p1) = (contour.points[i].point, contour.points[next].point);
+            // Must match the degenerate-edge skip in `create_side_faces`
+            let edge_vec = p1 - p0;
+            let edge_len_sq = edge_vec.length_squared();
+            if edge_len_sq < 1e-10 {
+                continue;
+            }
+            let edge_dir = edge_vec * (1.0 / edge_len_sq.sqrt());
+            let outward = if filled_left {
+                Vec2::new(edge_dir.y, -edge_dir.x)
+            } else {
+                Vec2::new(-edge_dir.y, edge_dir.x)
+            };
+            let (m0, m1) = (contour_miters[i], contour_miters[next]);
+
+            for side in [1.0, -1.0] {
+                // Two vertices per ring, shared by the bands on either side
+                // of it, so round bevels shade smoothly across the rings
+                let base = mesh_3d.vertices.len() as u32;
+                for &(distance, drop, angle) in &profile {
+                    let z = side * (half_depth - drop);
+                    let normal = (outward * angle.sin()).extend(side * angle.cos());
+                    mesh_3d.vertices.extend_from_slice(&[
+                        inset(p0, m0, distance).extend(z),
+                        inset(p1, m1, distance).extend(z),
+                    ]);
+                    mesh_3d.normals.extend_from_slice(&[normal; 2]);
+                }
+                // `a, b, c` winds counter-clockwise seen from outside on the
+                // back of a contour with the body on its left, and on the
+                // front of one with the body on its right
+                let flip = (side > 0.0) == filled_left;
+                for band in 0..profile.len() as u32 - 1 {
+                    let [a, b, c, d] = [0, 1, 3, 2].map(|k| base + band * 2 + k);
+                    let quad = if flip {
+                        [a, c, b, a, d, c]
+                    } else {
+                        [a, b, c, a, c, d]
+                    };
+                    mesh_3d.indices.extend_from_slice(&quad);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "debug-checks")]
+    crate::checks::extruded(&mesh_3d);
+
+    Ok(mesh_3d)
+}
+
+/// Hash key for a point at an exact position
+///
+/// `+ 0.0` folds -0.0 into 0.0 so both hash alike.
+#[inline]
+fn point_key(p: Vec2) -> (u32, u32) {
+    ((p.x + 0.0).to_bits(), (p.y + 0.0).to_bits())
+}
+
+/// Create side quads between corresponding points of two outlines.
+///
+/// Normals are taken from the quad's averaged edge and depth directions, so
+/// sloped walls get correctly tilted normals.
+fn create_loft_faces(mesh_3d: &mut Mesh3D, front: &Outline2D, back: &Outline2D, half_depth: f32) {
+    let fill_sides = contour_fill_sides(front);
+
+    for ((front_contour, back_contour), &filled_left) in
+        front.contours.iter().zip(&back.contours).zip(&fill_sides)
+    {
+        let num_points = front_contour.points.len();
+        if num_points < 2 {
+            continue;
+        }
+
+        let edge_count = if front_contour.closed {
+            num_points
+        } else {
+            num_points - 1
+        };
+
+        for i in 0..edge_count {
+            let next = (i + 1) % num_points;
+            let f0 = front_contour.points[i].point.extend(half_depth);
+            let f1 = front_contour.points[next].point.extend(half_depth);
+            let b0 = back_contour.points[i].point.extend(-half_depth);
+            let b1 = back_contour.points[next].point.extend(-half_depth);
+
+            let along = (f1 - f0) + (b1 - b0);
+            let down = (b0 - f0) + (b1 - f1);
+            // Points away from the filled side (see `create_side_faces`)
+            let face_normal = if filled_left {
+                down.cross(along)
+            } else {
+                along.cross(down)
+            };
+            if face_normal.length_squared() < 1e-12 {
+                continue;
+            }
+            let face_normal = face_normal.normalize();
+
+            let base_idx = mesh_3d.vertices.len() as u32;
+            mesh_3d.vertices.extend_from_slice(&[f0, f1, b1, b0]);
+            mesh_3d.normals.extend_from_slice(&[face_normal; 4]);
+            // CCW when viewed from the outward normal
+            let quad = if filled_left {
+                [0, 2, 1, 0, 3, 2]
+            } else {
+                [0, 1, 2, 0, 2, 3]
+            };
+            mesh_3d.indices.extend(quad.map(|k| base_idx + k));
+        }
+    }
+}
+
+/// For each contour, whether the filled glyph area lies to the left of its direction of travel.
+///
+/// Combines the contour's winding with its even-odd nesting depth, so both
+/// TrueType (clockwise outer) and PostScript (counter-clockwise outer)
+/// conventions, as well as inconsistently wound fonts, are handled.
+pub(crate) fn contour_fill_sides(outline: &Outline2D) -> Vec<bool> {
+    outline
+        .contours
+        .iter()
+        .zip(outline.nesting_depths())
+        .map(|(contour, depth)| (contour.signed_area() > 0.0) != (depth % 2 == 1))
+        .collect()
+}
+
+/// Create side faces by connecting outline edges with outward-facing normals.
+#[inline]
+fn create_side_faces(mesh_3d: &mut Mesh3D, outline: &Outline2D, half_depth: f32) {
+    let fill_sides = contour_fill_sides(outline);
+
+    for (contour, &filled_left) in outline.contours.iter().zip(&fill_sides) {
+        let num_points = contour.points.len();
+        if num_points < 2 {
+            continue;
+        }
+
+        let points = &contour.points;
+
+        for i in 0..num_points {
+            let next = if contour.closed {
+                (i + 1) % num_points
+            } else if i == num_points - 1 {
+                break;
+            } else {
+                i + 1
+            };
+
+            let p0 = points[i].point;
+            let p1 = points[next].point;
+            let edge_vec = p1 - p0;
+
+            // Skip degenerate edges
+            let edge_len_sq = edge_vec.length_squared();
+            if edge_len_sq < 1e-10 {
+                continue;
+            }
+
+            let edge_dir = edge_vec * (1.0 / edge_len_sq.sqrt());
+
+            // The outward normal points away from the filled side of the edge:
+            // the right perpendicular when the glyph body lies to the left.
+            let face_normal = if filled_left {
+                Vec3::new(edge_dir.y, -edge_dir.x, 0.0)
+            } else {
+                Vec3::new(-edge_dir.y, edge_dir.x, 0.0)
+            };
+
+            let base_idx = mesh_3d.vertices.len() as u32;
+
+            mesh_3d.vertices.push(Vec3::new(p0.x, p0.y, half_depth)); // 0: p0 front
+            mesh_3d.normals.push(face_normal);
+            mesh_3d.vertices.push(Vec3::new(p1.x, p1.y, half_depth)); // 1: p1 front
+            mesh_3d.normals.push(face_normal);
+            mesh_3d.vertices.push(Vec3::new(p1.x, p1.y, -half_depth)); // 2: p1 back
+            mesh_3d.normals.push(face_normal);
+            mesh_3d.vertices.push(Vec3::new(p0.x, p0.y, -half_depth)); // 3: p0 back
+            mesh_3d.normals.push(face_normal);
+
+            // CCW when viewed from the outward normal
+            if filled_left {
+                mesh_3d.indices.extend_from_slice(&[
+                    base_idx,
+                    base_idx + 2,
+                    base_idx + 1,
+                    base_idx,
+                    base_idx + 3,
+                    base_idx + 2,
+                ]);
+            } else {
+                mesh_3d.indices.extend_from_slice(&[
+                    base_idx,
+                    base_idx + 1,
+                    base_idx + 2,
+                    base_idx,
+                    base_idx + 2,
+                    base_idx + 3,
+                ]);
+            }
+        }
+    }
+}
+
+/// Create side faces that reuse the front and back cap vertices.
+///
+/// Expects the mesh to hold exactly the front cap followed by the back cap,
+/// as laid out by [`extrude_with`]. Rim vertex normals are replaced by the
+/// normalized sum of their cap normal and the adjacent side normals.
+fn create_welded_side_faces(
+    mesh_3d: &mut Mesh3D,
+    mesh_2d: &Mesh2D,
+    outline: &Outline2D,
+    half_depth: f32,
+) {
+    let cap_index: FxHashMap<(u32, u32), u32> = mesh_2d
+        .vertices
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| (point_key(v), i as u32))
+        .collect();
+    let back_offset = mesh_2d.vertices.len() as u32;
+    let fill_sides = contour_fill_sides(outline);
+
+    for (contour, &filled_left) in outline.contours.iter().zip(&fill_sides) {
+        let num_points = contour.points.len();
+        if num_points < 2 {
+            continue;
+        }
+        let edge_count = if contour.closed {
+            num_points
+        } else {
+            num_points - 1
+        };
+
+        for i in 0..edge_count {
+            let p0 = contour.points[i].point;
+            let p1 = contour.points[(i + 1) % num_points].point;
+            let edge_vec = p1 - p0;
+
+            // Skip degenerate edges
+            let edge_len_sq = edge_vec.length_squared();
+            if edge_len_sq < 1e-10 {
+                continue;
+            }
+            let edge_dir = edge_vec * (1.0 / edge_len_sq.sqrt());
+            let face_normal = if filled_left {
+                Vec3::new(edge_dir.y, -edge_dir.x, 0.0)
+            } else {
+                Vec3::new(-edge_dir.y, edge_dir.x, 0.0)
+            };
+
+            // Front p0, front p1, back p1, back p0
+            let mut quad = [0u32; 4];
+            for (slot, p) in [p0, p1, p1, p0].into_iter().enumerate() {
+                let front = slot < 2;
+                let z = if front { half_depth } else { -half_depth };
+                quad[slot] = match cap_index.get(&point_key(p)) {
+                    Some(&index) => {
+                        let index = if front { index } else { back_offset + index };
+                        mesh_3d.normals[index as usize] += face_normal;
+                        index
+                    }
+                    None => {
+                        mesh_3d.vertices.push(p.extend(z));
+                        mesh_3d.normals.push(face_normal);
+                        mesh_3d.vertices.len() as u32 - 1
+                    }
+                };
+            }
 
-    // 3. Create side faces
-    create_side_faces(&mut mesh_3d, outline, half_depth);
+            let [a, b, c, d] = quad;
+            if filled_left {
+                mesh_3d.indices.extend_from_slice(&[a, c, b, a, d, c]);
+            } else {
+                mesh_3d.indices.extend_from_slice(&[a, b, c, a, c, d]);
+            }
+        }
+    }
 
-    Ok(mesh_3d)
+    for normal in &mut mesh_3d.normals {
+        *normal = normal.normalize_or_zero();
+    }
 }
 
-/// Create side faces by connecting outline edges with outward-facing normals.
-#[inline]
-fn create_side_faces(mesh_3d: &mut Mesh3D, outline: &Outline2D, half_depth: f32) {
+/// Sweep a circular tube of constant radius along every contour of an outline
+///
+/// The tube's axis follows the (linearized) contour points in the z = 0 plane.
+/// At each corner the cross-section is mitered, stretched along the bisector
+/// of the two adjacent edges so the straight runs keep their radius. Very
+/// sharp corners are limited to 4x the radius to avoid spikes.
+///
+/// Closed contours produce closed tubes (a round dot becomes a torus); open
+/// contours get flat caps at both ends. Contours with fewer than two distinct
+/// points are skipped.
+///
+/// # Arguments
+/// * `outline` - The linearized outline to follow
+/// * `radius` - The tube radius
+/// * `radial_segments` - Number of vertices around the tube, clamped to at least 3
+///
+/// # Returns
+/// A 3D triangle mesh with smooth normals pointing away from the tube axis
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let outline = Glyph::new(&face, 'S')?.with_subdivisions(20).to_outline()?;
+/// let neon = outline.sweep_tube(0.01, 12);
+/// assert!(!neon.is_empty());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn sweep_tube(outline: &Outline2D, radius: f32, radial_segments: u8) -> Mesh3D {
+    const MAX_MITER: f32 = 4.0;
+
+    let segments = radial_segments.max(3) as usize;
+    let ring: Vec<(f32, f32)> = (0..segments)
+        .map(|j| {
+            let angle = j as f32 / segments as f32 * std::f32::consts::TAU;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+
+    let mut mesh = Mesh3D::new();
+
     for contour in &outline.contours {
-        let num_points = contour.points.len();
-        if num_points < 2 {
+        // Drop repeated points, including a closing point equal to the first
+        let mut points: Vec<Vec2> = Vec::with_capacity(contour.points.len());
+        for cp in &contour.points {
+            if points
+                .last()
+                .is_none_or(|last| last.distance_squared(cp.point) > 1e-12)
+            {
+                points.push(cp.point);
+            }
+        }
+        if contour.closed
+            && points.len() > 2
+            && points[0].distance_squared(points[points.len() - 1]) <= 1e-12
+        {
+            points.pop();
+        }
+        let n = points.len();
+        if n < 2 {
             continue;
         }
+        let closed = contour.closed && n > 2;
 
-        let points = &contour.points;
+        // Unit in-plane normal (right perpendicular) of each edge
+        let edge_count = if closed { n } else { n - 1 };
+        let edge_normals: Vec<Vec2> = (0..edge_count)
+            .map(|i| {
+                let dir = (points[(i + 1) % n] - points[i]).normalize();
+                Vec2::new(dir.y, -dir.x)
+            })
+            .collect();
 
-        for i in 0..num_points {
-            let next = if contour.closed {
-                (i + 1) % num_points
-            } else if i == num_points - 1 {
-                break;
+        let base = mesh.vertices.len() as u32;
+        for (i, &point) in points.iter().enumerate() {
+            let prev = if i > 0 {
+                Some(edge_normals[i - 1])
+            } else if closed {
+                Some(edge_normals[n - 1])
             } else {
-                i + 1
+                None
             };
+            let next = edge_normals.get(i).copied();
 
-            let p0 = points[i].point;
-            let p1 = points[next].point;
-            let edge_vec = p1 - p0;
+            let (miter, stretch) = match (prev, next) {
+                (Some(a), Some(b)) => {
+                    let bisector = (a + b).normalize_or(a);
+                    (bisector, (1.0 / bisector.dot(a)).clamp(1.0, MAX_MITER))
+                }
+                (Some(a), None) | (None, Some(a)) => (a, 1.0),
+                (None, None) => unreachable!(),
+            };
 
-            // Skip degenerate edges
-            let edge_len_sq = edge_vec.length_squared();
-            if edge_len_sq < 1e-10 {
-                continue;
+            for &(cos, sin) in &ring {
+                let normal = Vec3::new(miter.x * cos, miter.y * cos, sin);
+                let offset =
+                    Vec3::new(miter.x * cos * stretch, miter.y * cos * stretch, sin) * radius;
+                mesh.vertices.push(point.extend(0.0) + offset);
+                mesh.normals.push(normal);
             }
+        }
 
-            let edge_dir = edge_vec * (1.0 / edge_len_sq.sqrt());
+        let seg = segments as u32;
+        for i in 0..edge_count as u32 {
+            let a = base + i * seg;
+            let b = base + ((i + 1) % n as u32) * seg;
+            for j in 0..seg {
+                let k = (j + 1) % seg;
+                mesh.indices
+                    .extend_from_slice(&[a + j, b + j, b + k, a + j, b + k, a + k]);
+            }
+        }
 
-            // Right perpendicular of the edge direction points outward from the
-            // glyph surface (away from the letter body) for all contour types.
-            // Winding [0,2,1],[0,3,2] is CCW when viewed from that outward direction.
-            let face_normal = Vec3::new(edge_dir.y, -edge_dir.x, 0.0); // right perp = outward
+        if !closed {
+            for (end, inward) in [
+                (0, points[1] - points[0]),
+                (n - 1, points[n - 2] - points[n - 1]),
+            ] {
+                let normal = -inward.normalize().extend(0.0);
+                let ring_start = base + (end * segments) as u32;
+                let cap_base = mesh.vertices.len() as u32;
+                for j in 0..segments {
+                    mesh.vertices.push(mesh.vertices[ring_start as usize + j]);
+                    mesh.normals.push(normal);
+                }
+                // The ring runs counter-clockwise seen from the start
+                // of the tube, which the start cap faces and the end cap
+                // faces away from
+                for j in 1..seg - 1 {
+                    let tri = if end == 0 {
+                        [cap_base, cap_base + j, cap_base + j + 1]
+                    } else {
+                        [cap_base, cap_base + j + 1, cap_base + j]
+                    };
+                    mesh.indices.extend_from_slice(&tri);
+                }
+            }
+        }
+    }
 
-            let base_idx = mesh_3d.vertices.len() as u32;
+    mesh
+}
 
-            mesh_3d.vertices.push(Vec3::new(p0.x, p0.y, half_depth)); // 0: p0 front
-            mesh_3d.normals.push(face_normal);
-            mesh_3d.vertices.push(Vec3::new(p1.x, p1.y, half_depth)); // 1: p1 front
-            mesh_3d.normals.push(face_normal);
-            mesh_3d.vertices.push(Vec3::new(p1.x, p1.y, -half_depth)); // 2: p1 back
-            mesh_3d.normals.push(face_normal);
-            mesh_3d.vertices.push(Vec3::new(p0.x, p0.y, -half_depth)); // 3: p0 back
-            mesh_3d.normals.push(face_normal);
+/// Whether a 2D mesh's triangles mostly wind clockwise, by total signed area
+fn is_clockwise(mesh_2d: &Mesh2D) -> bool {
+    let signed_area: f32 = mesh_2d
+        .indices
+        .chunks_exact(3)
+        .map(|tri| {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh_2d.vertices[i as usize]);
+            (b - a).perp_dot(c - a)
+        })
+        .sum();
+    signed_area <= 0.0
+}
 
-            // Reversed winding: CCW from the direction the right perp points.
-            mesh_3d.indices.extend_from_slice(&[
-                base_idx,
-                base_idx + 2,
-                base_idx + 1,
-                base_idx,
-                base_idx + 3,
-                base_idx + 2,
-            ]);
+/// Reorient triangles so their winding agrees with the stored vertex normals
+///
+/// For every triangle, the geometric normal (from counter-clockwise winding)
+/// is compared against the sum of its three per-vertex normals. Triangles that
+/// face the opposite way are flipped by swapping two indices, so every
+/// triangle is CCW when viewed from the side its normals point to. This keeps
+/// back-face culling consistent with the shading normals.
+///
+/// Only the plain straight path of `extrude` runs this pass; the other
+/// generators wind their faces correctly as they build them. Call it manually
+/// after editing a mesh's indices or normals yourself. Degenerate triangles
+/// are left untouched.
+///
+/// # Arguments
+/// * `mesh` - The mesh to reorient (modified in-place)
+pub fn orient_triangles(mesh: &mut Mesh3D) {
+    for triangle in mesh.indices.chunks_exact_mut(3) {
+        let (i0, i1, i2) = (
+            triangle[0] as usize,
+            triangle[1] as usize,
+            triangle[2] as usize,
+        );
+
+        let v0 = mesh.vertices[i0];
+        let geometric = (mesh.vertices[i1] - v0).cross(mesh.vertices[i2] - v0);
+        let shading = mesh.normals[i0] + mesh.normals[i1] + mesh.normals[i2];
+
+        if geometric.dot(shading) < 0.0 {
+            triangle.swap(1, 2);
         }
     }
 }
@@ -242,12 +1569,117 @@ pub fn compute_smooth_normals(mesh: &mut Mesh3D) {
     }
 }
 
+/// Compute per-vertex tangents for normal mapping (optional post-processing)
+///
+/// Companion to [`compute_smooth_normals`]: derives tangents from the UVs
+/// and positions with Lengyel's method. Each triangle contributes the
+/// directions in which u and v increase across it; per vertex, the summed u
+/// direction is made orthogonal to the normal and normalized, and `w` holds
+/// the handedness (±1) so shaders can rebuild the bitangent as
+/// `normal.cross(tangent.xyz) * tangent.w`. Results go to
+/// [`Mesh3D::tangents`], one per vertex.
+///
+/// Tangents follow the UVs, so the mesh needs them: if `uvs` doesn't have
+/// one entry per vertex (e.g. extruded without
+/// [`ExtrudeOptions::uv_mode`]), this does nothing and leaves `tangents`
+/// empty. Vertices whose triangles have degenerate UVs get an arbitrary
+/// tangent perpendicular to the normal.
+///
+/// # Arguments
+/// * `mesh` - The mesh to compute tangents for (modified in-place)
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, Glyph, compute_tangents, char_to_mesh_3d};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let mut mesh = char_to_mesh_3d(&face, 'A', 0.1, 20)?;
+///
+/// // No UVs, no tangents
+/// compute_tangents(&mut mesh);
+/// assert!(mesh.tangents.is_empty());
+///
+/// let glyph = Glyph::new(&face, 'A')?;
+/// let mut mesh = glyph.with_subdivisions(20).with_uvs(true).to_mesh_3d(0.1)?;
+/// compute_tangents(&mut mesh);
+/// assert_eq!(mesh.tangents.len(), mesh.vertices.len());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn compute_tangents(mesh: &mut Mesh3D) {
+    mesh.tangents.clear();
+    if mesh.uvs.len() != mesh.vertices.len() || mesh.normals.len() != mesh.vertices.len() {
+        return;
+    }
+
+    let mut u_dirs = vec![Vec3::ZERO; mesh.vertices.len()];
+    let mut v_dirs = vec![Vec3::ZERO; mesh.vertices.len()];
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [i0, i1, i2] = [triangle[0], triangle[1], triangle[2]].map(|i| i as usize);
+
+        let edge1 = mesh.vertices[i1] - mesh.vertices[i0];
+        let edge2 = mesh.vertices[i2] - mesh.vertices[i0];
+        let duv1 = mesh.uvs[i1] - mesh.uvs[i0];
+        let duv2 = mesh.uvs[i2] - mesh.uvs[i0];
+
+        let det = duv1.perp_dot(duv2);
+        if det.abs() <= f32::EPSILON {
+            continue;
+        }
+        let r = det.recip();
+        let u_dir = (edge1 * duv2.y - edge2 * duv1.y) * r;
+        let v_dir = (edge2 * duv1.x - edge1 * duv2.x) * r;
+        for i in [i0, i1, i2] {
+            u_dirs[i] += u_dir;
+            v_dirs[i] += v_dir;
+        }
+    }
+
+    mesh.tangents = mesh
+        .normals
+        .iter()
+        .zip(u_dirs.iter().zip(&v_dirs))
+        .map(|(&normal, (&u_dir, &v_dir))| {
+            // Gram-Schmidt: drop the part of u_dir along the normal
+            let tangent = (u_dir - normal * normal.dot(u_dir))
+                .try_normalize()
+                .unwrap_or_else(|| normal.any_orthonormal_vector());
+            let handedness = if normal.cross(tangent).dot(v_dir) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            tangent.extend(handedness)
+        })
+        .collect();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Contour, Outline2D};
+    use crate::types::{square, Contour, Outline2D};
     use glam::Vec2;
 
+    /// Every non-degenerate triangle winds counter-clockwise seen along its
+    /// stored vertex normals
+    fn assert_wound_by_normals(mesh: &Mesh3D) {
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+            let geometric = (b - a).cross(c - a);
+            if geometric.length_squared() < 1e-14 {
+                continue;
+            }
+            let normal: Vec3 = tri.iter().map(|&i| mesh.normals[i as usize]).sum();
+            assert!(
+                geometric.dot(normal) > 0.0,
+                "triangle {:?} faces {:?}, normals {:?}",
+                [a, b, c],
+                geometric,
+                normal
+            );
+        }
+    }
+
     #[test]
     fn test_extrude_square() {
         // Create a simple square mesh
@@ -259,15 +1691,12 @@ mod tests {
                 Vec2::new(0.0, 1.0),
             ],
             indices: vec![0, 1, 2, 0, 2, 3],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
         };
 
         let mut outline = Outline2D::new();
-        let mut contour = Contour::new(true);
-        contour.push_on_curve(Vec2::new(0.0, 0.0));
-        contour.push_on_curve(Vec2::new(1.0, 0.0));
-        contour.push_on_curve(Vec2::new(1.0, 1.0));
-        contour.push_on_curve(Vec2::new(0.0, 1.0));
-        outline.add_contour(contour);
+        outline.add_contour(square(0.0, 1.0, false));
 
         let mesh_3d = extrude(&mesh_2d, &outline, 1.0).expect("Extrusion should succeed");
 
@@ -275,5 +1704,556 @@ mod tests {
         assert!(!mesh_3d.vertices.is_empty());
         assert!(mesh_3d.triangle_count() > 0);
         assert_eq!(mesh_3d.vertices.len(), mesh_3d.normals.len());
+        assert!(mesh_3d.uvs.is_empty());
+    }
+
+    #[test]
+    fn test_compute_tangents() {
+        let font_data = include_bytes!("../assets/test_font.ttf");
+        let face = Face::parse(font_data, 0).unwrap();
+        let mut plain = crate::glyph::char_to_mesh_3d(&face, 'O', 0.2, 20).unwrap();
+        compute_tangents(&mut plain);
+        assert!(plain.tangents.is_empty());
+
+        let mut mesh = crate::Glyph::new(&face, 'O')
+            .unwrap()
+            .with_subdivisions(20)
+            .with_uvs(true)
+            .to_mesh_3d(0.2)
+            .unwrap();
+        compute_tangents(&mut mesh);
+        assert_eq!(mesh.tangents.len(), mesh.vertices.len());
+        for (t, n) in mesh.tangents.iter().zip(&mesh.normals) {
+            let xyz = t.truncate();
+            assert!(
+                xyz.dot(*n).abs() < 1e-3,
+                "{:?} not orthogonal to {:?}",
+                t,
+                n
+            );
+            assert!((xyz.length() - 1.0).abs() < 1e-3);
+            assert!(t.w == 1.0 || t.w == -1.0);
+        }
+
+        // Caps are planar in xy with u along +x: the front tangent is +x
+        let (_, max) = mesh.aabb();
+        let front = (0..mesh.vertices.len())
+            .find(|&i| mesh.vertices[i].z == max.z && mesh.normals[i].z > 0.99)
+            .unwrap();
+        assert!(mesh.tangents[front].truncate().dot(Vec3::X) > 0.99);
+    }
+
+    #[test]
+    fn test_extrude_beveled() {
+        let font_data = include_bytes!("../assets/test_font.ttf");
+        let face = Face::parse(font_data, 0).unwrap();
+        let outline = crate::glyph::Glyph::new(&face, 'H')
+            .unwrap()
+            .with_subdivisions(20)
+            .to_outline()
+            .unwrap();
+        let mesh_2d = outline.triangulate().unwrap();
+        let plain = extrude(&mesh_2d, &outline, 0.1).unwrap();
+        let bevel = |style, segments, width| BevelOptions {
+            width,
+            segments,
+            style,
+        };
+
+        let chamfer =
+            extrude_beveled(&mesh_2d, &outline, 0.1, bevel(BevelStyle::Chamfer, 4, 0.01)).unwrap();
+        let round =
+            extrude_beveled(&mesh_2d, &outline, 0.1, bevel(BevelStyle::Round, 4, 0.01)).unwrap();
+        for mesh in [&chamfer, &round] {
+            assert!(mesh.is_solid());
+            assert_wound_by_normals(mesh);
+            assert_eq!(mesh.aabb(), plain.aabb());
+            // The caps are inset by the width
+            let front_min_x = (0..mesh.vertices.len())
+                .filter(|&i| mesh.normals[i] == Vec3::Z)
+                .map(|i| mesh.vertices[i].x)
+                .fold(f32::INFINITY, f32::min);
+            assert!((front_min_x - plain.aabb().0.x - 0.01).abs() < 1e-5);
+        }
+
+        // A chamfer is one flat 45° band, creased against cap and wall; a
+        // round bevel's normals sweep from the cap normal to the wall's
+        let tilts = |mesh: &Mesh3D| {
+            let mut tilts: Vec<f32> = mesh.normals.iter().map(|n| n.z.abs()).collect();
+            tilts.sort_by(f32::total_cmp);
+            tilts.dedup_by(|a, b| (*a - *b).abs() < 1e-4);
+            tilts
+        };
+        assert_eq!(tilts(&chamfer).len(), 3);
+        assert!(tilts(&chamfer)
+            .iter()
+            .any(|&z| (z - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-4));
+        assert_eq!(tilts(&round).len(), 5);
+
+        // Bands are wound up front, whichever way the contours run and
+        // whether they bound fill or a counter
+        let o = crate::glyph::Glyph::new(&face, 'O')
+            .unwrap()
+            .with_subdivisions(20)
+            .to_outline()
+            .unwrap();
+        let mut reversed = o.clone();
+        for contour in &mut reversed.contours {
+            contour.points.reverse();
+        }
+        for o in [o, reversed] {
+            let o_2d = o.triangulate().unwrap();
+            for style in [BevelStyle::Chamfer, BevelStyle::Round] {
+                let mesh = extrude_beveled(&o_2d, &o, 0.1, bevel(style, 4, 0.01)).unwrap();
+                assert!(mesh.is_solid());
+                assert_wound_by_normals(&mesh);
+            }
+        }
+
+        assert_eq!(
+            extrude_beveled(&mesh_2d, &outline, 0.1, bevel(BevelStyle::Round, 4, 0.0))
+                .unwrap()
+                .vertices,
+            plain.vertices
+        );
+        // More than half the depth, and more than half the stems
+        assert!(matches!(
+            extrude_beveled(&mesh_2d, &outline, 0.1, bevel(BevelStyle::Chamfer, 1, 0.06)),
+            Err(FontMeshError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            extrude_beveled(&mesh_2d, &outline, 0.2, bevel(BevelStyle::Chamfer, 1, 0.06)),
+            Err(FontMeshError::ExtrusionFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_extrude_uv_modes() {
+        let mesh_2d = Mesh2D {
+            vertices: vec![
+                Vec2::new(2.0, 0.0),
+                Vec2::new(4.0, 0.0),
+                Vec2::new(4.0, 1.0),
+                Vec2::new(2.0, 1.0),
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+        let mut outline = Outline2D::new();
+        let mut contour = Contour::new(true);
+        for &v in &mesh_2d.vertices {
+            contour.push_on_curve(v);
+        }
+        outline.add_contour(contour);
+
+        let with_mode = |mode| {
+            let options = ExtrudeOptions {
+                uv_mode: Some(mode),
+                ..Default::default()
+            };
+            extrude_with(&mesh_2d, &outline, 0.5, &options).unwrap()
+        };
+
+        let bounds = with_mode(UvMode::PerGlyphBounds);
+        assert_eq!(bounds.uvs.len(), bounds.vertices.len());
+        assert_eq!(bounds.uvs[0], Vec2::new(0.0, 0.0));
+        assert_eq!(bounds.uvs[2], Vec2::new(1.0, 1.0));
+
+        let world = with_mode(UvMode::WorldPlanar { scale: 0.5 });
+        assert_eq!(world.uvs.len(), world.vertices.len());
+        assert_eq!(world.uvs[2], Vec2::new(2.0, 0.5));
+
+        // Sides: u continues along the contour, v spans the depth
+        let sides = &world.uvs[8..];
+        assert_eq!(sides[0], Vec2::new(0.0, 0.0));
+        assert_eq!(sides[1], Vec2::new(1.0, 0.0));
+        assert_eq!(sides[2], Vec2::new(1.0, 0.25));
+        assert_eq!(sides[4], Vec2::new(1.0, 0.0));
+        assert_eq!(sides[sides.len() - 2], Vec2::new(3.0, 0.25));
+    }
+
+    #[test]
+    fn test_extrude_weld_sides() {
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 3.0, true));
+        outline.add_contour(square(1.0, 2.0, false));
+        let mesh_2d = crate::triangulate::triangulate(&outline).unwrap();
+
+        let plain = extrude(&mesh_2d, &outline, 1.0).unwrap();
+        let options = ExtrudeOptions {
+            weld_sides: true,
+            ..Default::default()
+        };
+        let welded = extrude_with(&mesh_2d, &outline, 1.0, &options).unwrap();
+
+        assert_eq!(welded.vertices.len(), mesh_2d.vertices.len() * 2);
+        assert!(welded.vertices.len() < plain.vertices.len());
+        assert_eq!(welded.triangle_count(), plain.triangle_count());
+        assert_wound_by_normals(&welded);
+
+        // Manifold and consistently wound: every directed edge appears once
+        // and is matched by its reverse
+        let mut edges = FxHashMap::default();
+        for tri in welded.indices.chunks_exact(3) {
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                *edges.entry((a, b)).or_insert(0) += 1;
+            }
+        }
+        for (&(a, b), &count) in &edges {
+            assert_eq!(count, 1);
+            assert_eq!(edges.get(&(b, a)), Some(&1));
+        }
+
+        // Outward facing: positive signed volume
+        let volume: f32 = welded
+            .indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| welded.vertices[i as usize]);
+                a.dot(b.cross(c)) / 6.0
+            })
+            .sum();
+        assert!((volume - 8.0).abs() < 1e-4, "volume {}", volume);
+    }
+
+    #[test]
+    fn test_extrude_bulge_cap_normals() {
+        let font_data = include_bytes!("../assets/test_font.ttf");
+        let face = Face::parse(font_data, 0).unwrap();
+        let outline = crate::glyph::Glyph::new(&face, 'O')
+            .unwrap()
+            .with_subdivisions(20)
+            .to_outline()
+            .unwrap();
+        let mesh_2d = outline.triangulate().unwrap();
+
+        let falloff = 0.02;
+        let scale = 0.5;
+        let options = ExtrudeOptions {
+            cap_normals: CapNormals::Bulge { falloff },
+            mode: ExtrudeMode::Relief { scale },
+            ..Default::default()
+        };
+        let bulged = extrude_with(&mesh_2d, &outline, 0.1, &options).unwrap();
+        assert!(bulged.is_solid());
+        assert_wound_by_normals(&bulged);
+
+        // The cap gained one vertex per triangle, after the outline vertices
+        let rim = mesh_2d.vertices.len();
+        let cap = rim + mesh_2d.indices.len() / 3;
+        for (v, p) in bulged.vertices.iter().zip(&mesh_2d.vertices) {
+            assert_eq!(v.truncate(), *p);
+        }
+
+        let edges: Vec<(Vec2, Vec2)> = outline
+            .contours
+            .iter()
+            .flat_map(|c| {
+                let n = c.points.len();
+                (0..n).map(move |i| (c.points[i].point, c.points[(i + 1) % n].point))
+            })
+            .collect();
+        let distance = |p: Vec3| {
+            edges
+                .iter()
+                .map(|&(a, b)| distance_to_segment(p.truncate(), a, b))
+                .fold(f32::INFINITY, f32::min)
+        };
+        let tilt = |n: Vec3| n.truncate().length();
+
+        // Rims lean fully sideways
+        for i in 0..rim {
+            assert!(
+                tilt(bulged.normals[i]) > 0.999,
+                "rim normal {}",
+                bulged.normals[i]
+            );
+        }
+        // Centers are flat, with the back cap measured at its own scale
+        let mut flat = 0;
+        for i in rim..cap {
+            let d = distance(bulged.vertices[i]);
+            let front = bulged.normals[i];
+            let back = bulged.normals[cap + i];
+            assert!((tilt(front) - (1.0 - d / falloff).max(0.0)).abs() < 1e-4);
+            assert!((tilt(back) - (1.0 - d * scale / falloff).max(0.0)).abs() < 1e-4);
+            assert!(front.z > 0.0 && back.z < 0.0);
+            if front == Vec3::Z {
+                flat += 1;
+            }
+        }
+        assert!(flat > 0);
+    }
+
+    #[test]
+    fn test_extrude_relief() {
+        // A 2x2 square around (1, 1), shrunk to half size at the back
+        let mesh_2d = Mesh2D {
+            vertices: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(2.0, 2.0),
+                Vec2::new(0.0, 2.0),
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+        let mut outline = Outline2D::new();
+        let mut contour = Contour::new(true);
+        for &v in &mesh_2d.vertices {
+            contour.push_on_curve(v);
+        }
+        outline.add_contour(contour);
+
+        let options = ExtrudeOptions {
+            mode: ExtrudeMode::Relief { scale: 0.5 },
+            uv_mode: Some(UvMode::WorldPlanar { scale: 1.0 }),
+            ..Default::default()
+        };
+        let mesh = extrude_with(&mesh_2d, &outline, 1.0, &options).unwrap();
+        assert!(mesh.is_solid());
+        assert_wound_by_normals(&mesh);
+        assert_eq!(mesh.uvs.len(), mesh.vertices.len());
+
+        // The back cap is the square [0.5, 1.5]^2
+        let back: Vec<_> = mesh.vertices[4..8].iter().map(|v| v.truncate()).collect();
+        assert_eq!(
+            back,
+            [
+                Vec2::new(0.5, 0.5),
+                Vec2::new(1.5, 0.5),
+                Vec2::new(1.5, 1.5),
+                Vec2::new(0.5, 1.5),
+            ]
+        );
+
+        // Walls slope in by 0.5 over the depth of 1.0, so face outward and
+        // partly backward
+        let center = Vec2::new(1.0, 1.0);
+        let quads = mesh.vertices[8..].chunks_exact(4);
+        for (quad, normals) in quads.zip(mesh.normals[8..].chunks_exact(4)) {
+            let mid = (quad[0] + quad[1]).truncate() / 2.0 - center;
+            let outward = if mid.x.abs() > mid.y.abs() {
+                Vec3::new(mid.x.signum(), 0.0, 0.0)
+            } else {
+                Vec3::new(0.0, mid.y.signum(), 0.0)
+            };
+            let expected = (outward - Vec3::Z * 0.5).normalize();
+            for n in normals {
+                assert!(n.distance(expected) < 1e-5, "{n} != {expected}");
+            }
+        }
+
+        let options = ExtrudeOptions {
+            mode: ExtrudeMode::Relief { scale: 0.0 },
+            ..Default::default()
+        };
+        assert!(matches!(
+            extrude_with(&mesh_2d, &outline, 1.0, &options),
+            Err(FontMeshError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_extrude_loft() {
+        let square = |size: f32| {
+            let mut outline = Outline2D::new();
+            outline.add_contour(square(-size, size, true));
+            outline
+        };
+
+        let front = square(2.0);
+        let back = square(1.0);
+        let front_mesh = crate::triangulate::triangulate(&front).unwrap();
+        let mesh = extrude_loft(&front_mesh, &front, &back, 1.0).unwrap();
+
+        assert_eq!(mesh.vertices.len(), mesh.normals.len());
+        // Triangles are emitted facing their normals, with no reorienting
+        // pass, whichever way the outlines wind
+        let mut reversed = [front.clone(), back.clone()];
+        for outline in &mut reversed {
+            outline.contours[0].points.reverse();
+        }
+        let [rev_front, rev_back] = &reversed;
+        let rev_front_mesh = crate::triangulate::triangulate(rev_front).unwrap();
+        for lofted in [
+            mesh.clone(),
+            extrude_loft(&rev_front_mesh, rev_front, rev_back, 1.0).unwrap(),
+        ] {
+            let mut oriented = lofted.clone();
+            orient_triangles(&mut oriented);
+            assert_eq!(oriented.indices, lofted.indices);
+            assert!(lofted.is_solid());
+        }
+        // Walls taper towards the back, so their normals tilt backwards
+        let side_normals = &mesh.normals[mesh.normals.len() - 16..];
+        assert!(side_normals.iter().all(|n| n.z < 0.0));
+        for (v, n) in mesh
+            .vertices
+            .iter()
+            .zip(&mesh.normals)
+            .skip(mesh.vertices.len() - 16)
+        {
+            assert!(v.truncate().dot(n.truncate()) > 0.0);
+        }
+
+        let mut mismatched = square(1.0);
+        mismatched.contours[0].push_on_curve(Vec2::new(0.5, -0.5));
+        assert!(matches!(
+            extrude_loft(&front_mesh, &front, &mismatched, 1.0),
+            Err(FontMeshError::ExtrusionFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_orient_triangles_matches_normals() {
+        // CCW input triangles: the front cap would otherwise face -Z
+        let mesh_2d = Mesh2D {
+            vertices: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(0.0, 1.0),
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 1.0, true));
+
+        let mesh_3d = extrude(&mesh_2d, &outline, 1.0).unwrap();
+
+        // Side normals point away from the square's center
+        let center = Vec3::new(0.5, 0.5, 0.0);
+        for (v, n) in mesh_3d.vertices.iter().zip(&mesh_3d.normals).skip(8) {
+            assert!(
+                (*v - center).dot(*n) > 0.0,
+                "side normal {:?} at {:?}",
+                n,
+                v
+            );
+        }
+
+        for tri in mesh_3d.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh_3d.vertices[i as usize]);
+            let geometric = (b - a).cross(c - a);
+            assert!(geometric.dot(mesh_3d.normals[tri[0] as usize]) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_sweep_tube() {
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 1.0, false));
+
+        let tube = sweep_tube(&outline, 0.1, 8);
+        // Closed contour: one ring per corner, one tube section per edge
+        assert_eq!(tube.vertices.len(), 4 * 8);
+        assert_eq!(tube.triangle_count(), 4 * 8 * 2);
+        assert!(tube.normals.iter().all(|n| (n.length() - 1.0).abs() < 1e-5));
+        let (min, max) = tube.aabb();
+        assert!((max.z - 0.1).abs() < 1e-6 && (min.z + 0.1).abs() < 1e-6);
+        // Mitered corners keep the straight runs at the tube radius
+        assert!((max.x - 1.1).abs() < 1e-5 && (min.y + 0.1).abs() < 1e-5);
+        assert_wound_by_normals(&tube);
+
+        let mut open = Outline2D::new();
+        let mut line = Contour::new(false);
+        line.push_on_curve(Vec2::new(0.0, 0.0));
+        line.push_on_curve(Vec2::new(1.0, 0.0));
+        open.add_contour(line);
+
+        let tube = sweep_tube(&open, 0.1, 8);
+        // Two rings plus two capped ends
+        assert_eq!(tube.vertices.len(), 4 * 8);
+        assert_eq!(tube.triangle_count(), 8 * 2 + 2 * 6);
+        assert_wound_by_normals(&tube);
+        for tri in tube.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| tube.vertices[i as usize]);
+            let centroid = (a + b + c) / 3.0;
+            // Caps face along the axis, walls away from it
+            let outward = if centroid.x <= 1e-6 {
+                -Vec3::X
+            } else if centroid.x >= 1.0 - 1e-6 {
+                Vec3::X
+            } else {
+                centroid - Vec3::new(centroid.x, 0.0, 0.0)
+            };
+            assert!((b - a).cross(c - a).dot(outward) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_ensure_outward_normals_cw_outline() {
+        // Clockwise square, the TrueType convention for outer contours
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 1.0, true));
+
+        let mesh_2d = crate::triangulate::triangulate(&outline).unwrap();
+        let extruded = extrude(&mesh_2d, &outline, 1.0).unwrap();
+
+        // Turn the mesh inside out, as a hand-built mesh with the opposite
+        // winding convention would be
+        let mut inverted = extruded.clone();
+        inverted.normals.iter_mut().for_each(|n| *n = -*n);
+        inverted
+            .indices
+            .chunks_exact_mut(3)
+            .for_each(|tri| tri.swap(1, 2));
+
+        for mut mesh in [extruded, inverted] {
+            mesh.ensure_outward_normals();
+
+            let center = Vec3::new(0.5, 0.5, 0.0);
+            for tri in mesh.indices.chunks_exact(3) {
+                let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+                let geometric = (b - a).cross(c - a);
+                let normal = mesh.normals[tri[0] as usize];
+                assert!(geometric.dot(normal) > 0.0);
+                assert!(geometric.dot((a + b + c) / 3.0 - center) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extrude_either_winding() {
+        let square = |clockwise: bool| {
+            let mut outline = Outline2D::new();
+            outline.add_contour(square(0.0, 1.0, clockwise));
+            outline
+        };
+
+        let cw = square(true);
+        let ccw = square(false);
+        let cw_mesh = extrude(&crate::triangulate::triangulate(&cw).unwrap(), &cw, 1.0).unwrap();
+        let ccw_mesh = extrude(&crate::triangulate::triangulate(&ccw).unwrap(), &ccw, 1.0).unwrap();
+
+        // A cap triangulated with the opposite winding extrudes the same
+        let mut flipped = crate::triangulate::triangulate(&ccw).unwrap();
+        flipped
+            .indices
+            .chunks_exact_mut(3)
+            .for_each(|tri| tri.swap(1, 2));
+        let flipped_mesh = extrude(&flipped, &ccw, 1.0).unwrap();
+
+        for mesh in [&cw_mesh, &ccw_mesh, &flipped_mesh] {
+            assert!(mesh.is_solid());
+            for tri in mesh.indices.chunks_exact(3) {
+                let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+                let geometric = (b - a).cross(c - a);
+                assert!(geometric.dot(mesh.normals[tri[0] as usize]) > 0.0);
+                if a.z == b.z && b.z == c.z {
+                    assert_eq!(geometric.z.signum(), a.z.signum());
+                }
+            }
+        }
+        let caps = |mesh: &Mesh3D| mesh.indices[..12].to_vec();
+        assert_eq!(caps(&flipped_mesh), caps(&ccw_mesh));
+        assert_eq!(cw_mesh.vertices.len(), ccw_mesh.vertices.len());
+        assert_eq!(cw_mesh.triangle_count(), ccw_mesh.triangle_count());
     }
 }
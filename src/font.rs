@@ -5,13 +5,19 @@
 //! `ttf_parser::Face` directly - see the `glyph` module for the main API.
 
 use crate::error::{FontMeshError, Result};
-use ttf_parser::Face;
+use ttf_parser::{name_id, Face, Language, Tag};
 
 /// Parse font data into a ttf-parser Face
 ///
 /// This is a convenience wrapper around `ttf_parser::Face::parse`.
 /// You can also use `Face::parse` directly if you prefer.
 ///
+/// # Errors
+/// Returns [`FontMeshError::ParseError`] if the data isn't a font. A font
+/// whose `maxp` table reports zero glyphs fails with "font contains no
+/// glyphs" rather than ttf-parser's `NoMaxpTable`, so truncated or corrupt
+/// input is reported at load time instead of at the first glyph lookup.
+///
 /// # Example
 /// ```ignore
 /// use fontmesh::parse_font;
@@ -20,8 +26,29 @@ use ttf_parser::Face;
 /// let mesh = fontmesh::char_to_mesh_3d(&face, 'A', 5.0, 20)?;
 /// ```
 pub fn parse_font(data: &[u8]) -> Result<Face<'_>> {
-    Face::parse(data, 0)
-        .map_err(|e| FontMeshError::ParseError(format!("Failed to parse font: {:?}", e)))
+    match Face::parse(data, 0) {
+        Ok(face) if face.number_of_glyphs() > 0 => Ok(face),
+        Ok(_) => Err(no_glyphs()),
+        // ttf-parser rejects a zero glyph count as a missing maxp table
+        Err(_) if declares_no_glyphs(data) => Err(no_glyphs()),
+        Err(e) => Err(FontMeshError::ParseError(format!(
+            "Failed to parse font: {:?}",
+            e
+        ))),
+    }
+}
+
+fn no_glyphs() -> FontMeshError {
+    FontMeshError::ParseError("font contains no glyphs".to_string())
+}
+
+/// Whether the font's `maxp` table is present and gives a glyph count of 0
+fn declares_no_glyphs(data: &[u8]) -> bool {
+    ttf_parser::RawFace::parse(data, 0)
+        .ok()
+        .and_then(|raw| raw.table(Tag::from_bytes(b"maxp")))
+        .and_then(|maxp| maxp.get(4..6))
+        .is_some_and(|count| count == [0, 0])
 }
 
 /// Get font metrics helpers
@@ -49,6 +76,215 @@ pub fn glyph_advance(face: &Face, character: char) -> Option<f32> {
     Some(h_metrics as f32 / face.units_per_em() as f32)
 }
 
+/// List the characters of a string the font can't render
+///
+/// A character is unsupported when the font's `cmap` has no glyph for it or
+/// maps it to glyph 0, the `.notdef` box. These are the characters the
+/// layout functions fail on with [`crate::FontMeshError::GlyphNotFound`] or
+/// would draw as a box, so a UI can warn or switch to a fallback font before
+/// meshing anything. Invisible formatting characters and combining marks,
+/// which layout skips, are never reported. Each character is listed once,
+/// in order of first appearance. Only `cmap` lookups are done.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, unsupported_chars};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// assert_eq!(unsupported_chars(&face, "Hello \u{E000}\u{E000}!"), vec!['\u{E000}']);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn unsupported_chars(face: &Face, text: &str) -> Vec<char> {
+    let mut unsupported = Vec::new();
+    for c in text.chars() {
+        let missing = !matches!(face.glyph_index(c), Some(id) if id.0 != 0);
+        if missing && !crate::layout::is_zero_width(c) && !unsupported.contains(&c) {
+            unsupported.push(c);
+        }
+    }
+    unsupported
+}
+
+/// Check whether a character has a glyph with real geometry
+///
+/// True when the font maps the character to a glyph other than `.notdef`
+/// and that glyph has a bounding box, i.e. meshing it would produce
+/// triangles rather than [`crate::FontMeshError::GlyphNotFound`] or
+/// [`crate::FontMeshError::EmptyOutline`]. No outline is extracted and
+/// nothing is allocated, so this is cheap enough to filter thousands of
+/// codepoints, e.g. before building an atlas.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, is_meshable};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// assert!(is_meshable(&face, 'A'));
+/// assert!(!is_meshable(&face, ' '));
+/// assert!(!is_meshable(&face, '\u{E000}'));
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn is_meshable(face: &Face, c: char) -> bool {
+    match face.glyph_index(c) {
+        Some(id) if id.0 != 0 => face.glyph_bounding_box(id).is_some(),
+        _ => false,
+    }
+}
+
+/// Cap height assumed for fonts that don't provide one, in em
+pub(crate) const FALLBACK_CAP_HEIGHT: f32 = 0.7;
+
+/// Get the font's cap height (normalized to 1.0 em)
+///
+/// Uses the OS/2 `sCapHeight` value when the font provides one, and
+/// otherwise measures the top of the 'H' glyph's bounding box. Returns
+/// `None` if neither is available.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, cap_height, x_height};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let h = cap_height(&face).unwrap();
+/// assert!(h > x_height(&face).unwrap() && h < 1.0);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn cap_height(face: &Face) -> Option<f32> {
+    let units = face.capital_height().filter(|h| *h > 0).or_else(|| {
+        let glyph_id = face.glyph_index('H')?;
+        Some(face.glyph_bounding_box(glyph_id)?.y_max)
+    })?;
+    Some(units as f32 / face.units_per_em() as f32)
+}
+
+/// Get the font's x-height (normalized to 1.0 em)
+///
+/// Uses the OS/2 `sxHeight` value when the font provides one, and otherwise
+/// measures the top of the 'x' glyph's bounding box. Returns `None` if neither
+/// is available.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, x_height};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let h = x_height(&face).unwrap();
+/// assert!(h > 0.0 && h < 1.0);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn x_height(face: &Face) -> Option<f32> {
+    let units = face.x_height().filter(|h| *h > 0).or_else(|| {
+        let glyph_id = face.glyph_index('x')?;
+        Some(face.glyph_bounding_box(glyph_id)?.y_max)
+    })?;
+    Some(units as f32 / face.units_per_em() as f32)
+}
+
+/// Get the font's italic angle in degrees
+///
+/// Reads the `post` table: the angle counter-clockwise from vertical, so
+/// fonts that lean forward report a negative value (e.g. `-12.0`). Upright
+/// fonts, and fonts without a `post` table, return `0.0`. Pass the result to
+/// [`GlyphMeshBuilder::skew`](crate::glyph::GlyphMeshBuilder::skew) to slant
+/// another face by the same amount.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, italic_angle};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// assert_eq!(italic_angle(&face), 0.0);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn italic_angle(face: &Face) -> f32 {
+    face.italic_angle().unwrap_or(0.0)
+}
+
+/// Get the colors of one of a color font's palettes as RGBA
+///
+/// Reads the `CPAL` table that COLR color glyphs take their layer colors
+/// from; a layer's palette entry index is an index into the returned
+/// `Vec`. Palette 0 is the default.
+///
+/// Returns `None` if the font has no `CPAL` table or `index` is past its
+/// last palette.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, palette};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// assert_eq!(palette(&face, 0), None);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn palette(face: &Face, index: u16) -> Option<Vec<[u8; 4]>> {
+    let data = face.raw_face().table(Tag::from_bytes(b"CPAL"))?;
+    let table = ttf_parser::cpal::Table::parse(data)?;
+    // ttf-parser skips numPaletteEntries, the second field of the header
+    let entries = u16::from_be_bytes([*data.get(2)?, *data.get(3)?]);
+    (0..entries)
+        .map(|entry| {
+            table
+                .get(index, entry)
+                .map(|c| [c.red, c.green, c.blue, c.alpha])
+        })
+        .collect()
+}
+
+/// Get the font's family name, e.g. "Noto Sans"
+///
+/// Reads the `name` table, preferring the typographic family (name ID 16)
+/// and falling back to the legacy family (name ID 1), which fonts with
+/// more than four styles limit to e.g. "Noto Sans SemiBold". English
+/// Unicode records are preferred over other languages. Returns `None` if
+/// the font has no decodable record.
+///
+/// # Example
+/// ```
+/// use fontmesh::{Face, family_name, subfamily_name};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// assert_eq!(family_name(&face).as_deref(), Some("DejaVu Sans"));
+/// assert_eq!(subfamily_name(&face).as_deref(), Some("Book"));
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn family_name(face: &Face) -> Option<String> {
+    find_name(face, &[name_id::TYPOGRAPHIC_FAMILY, name_id::FAMILY])
+}
+
+/// Get the font's subfamily (style) name, e.g. "Bold Italic"
+///
+/// Prefers the typographic subfamily (name ID 17) over the legacy
+/// subfamily (name ID 2), like [`family_name`].
+pub fn subfamily_name(face: &Face) -> Option<String> {
+    find_name(face, &[name_id::TYPOGRAPHIC_SUBFAMILY, name_id::SUBFAMILY])
+}
+
+/// Decode the first `name` record found for the given IDs, in order of
+/// preference, taking English (US) records first
+fn find_name(face: &Face, ids: &[u16]) -> Option<String> {
+    ids.iter().find_map(|&id| {
+        let records = || {
+            face.names()
+                .into_iter()
+                .filter(move |name| name.name_id == id)
+        };
+        records()
+            .filter(|name| name.language() == Language::English_UnitedStates)
+            .chain(records())
+            .find_map(|name| name.to_string())
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1,12 +1,17 @@
 //! Glyph representation and outline extraction
 
 use crate::error::{FontMeshError, Result};
-use crate::types::{Contour, ContourPoint, Mesh2D, Mesh3D, Outline2D, Point2D};
+use crate::linearize::{LinearizeMode, LinearizeOptions};
+use crate::triangulate::TriangulateScratch;
+use crate::types::{
+    Contour, ContourCurves, ContourPoint, Mesh2D, Mesh3D, Outline2D, Point2D, Quality, Segment,
+};
 use glam::Vec2;
-use ttf_parser::{Face, GlyphId, OutlineBuilder};
+use std::cell::RefCell;
+use ttf_parser::{Face, GlyphId, OutlineBuilder, Tag, Transform};
 
 /// Default quality for curve linearization (20 subdivisions per curve)
-const DEFAULT_QUALITY: u8 = 20;
+pub(crate) const DEFAULT_QUALITY: u8 = 20;
 
 // ============================================================================
 // Pure Functions API - Stateless core functions
@@ -76,6 +81,212 @@ pub fn char_to_mesh_3d(
     crate::extrude::extrude(&mesh_2d, &outline, depth)
 }
 
+thread_local! {
+    /// Tessellator state and 2D mesh reused by the `_reuse` functions
+    static REUSE_SCRATCH: RefCell<(TriangulateScratch, Mesh2D)> =
+        RefCell::new((TriangulateScratch::new(), Mesh2D::new()));
+}
+
+/// Convert a character to a 2D triangle mesh, writing into an existing mesh
+///
+/// Produces the same mesh as [`char_to_mesh_2d`] at
+/// [`Quality::subdivisions`], but replaces the contents of `out` instead of
+/// returning a new mesh. Its buffers keep their capacity across calls, and
+/// the tessellator is reused per thread, so meshing glyph after glyph into
+/// the same mesh in a tight loop stops allocating for the mesh once it has
+/// grown to fit the largest glyph. On error `out` is left cleared or
+/// unchanged.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `character` - The character to convert
+/// * `quality` - Tessellation quality
+/// * `out` - Receives the mesh
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph_to_mesh_2d_reuse, Mesh2D, Quality};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mut mesh = Mesh2D::new();
+/// for c in "Hello".chars() {
+///     glyph_to_mesh_2d_reuse(&face, c, Quality::Normal, &mut mesh)?;
+///     // ... upload `mesh` ...
+/// }
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn glyph_to_mesh_2d_reuse(
+    face: &Face,
+    character: char,
+    quality: Quality,
+    out: &mut Mesh2D,
+) -> Result<()> {
+    let outline = extract_and_linearize_outline(face, character, quality.subdivisions())?;
+    REUSE_SCRATCH.with_borrow_mut(|(scratch, _)| {
+        crate::triangulate::triangulate_into(&outline, scratch, out)
+    })
+}
+
+/// Convert a character to an extruded 3D mesh, writing into an existing mesh
+///
+/// Produces the same mesh as [`char_to_mesh_3d`] at
+/// [`Quality::subdivisions`], but replaces the contents of `out` instead of
+/// returning a new mesh. As with [`glyph_to_mesh_2d_reuse`], the buffers of
+/// `out` keep their capacity across calls, and the intermediate 2D mesh and
+/// tessellator are reused per thread.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `character` - The character to convert
+/// * `quality` - Tessellation quality
+/// * `depth` - The extrusion depth
+/// * `out` - Receives the mesh
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph_to_mesh_3d_reuse, Mesh3D, Quality};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mut mesh = Mesh3D::new();
+/// for c in "Hello".chars() {
+///     glyph_to_mesh_3d_reuse(&face, c, Quality::High, 0.1, &mut mesh)?;
+///     // ... upload `mesh` ...
+/// }
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn glyph_to_mesh_3d_reuse(
+    face: &Face,
+    character: char,
+    quality: Quality,
+    depth: f32,
+    out: &mut Mesh3D,
+) -> Result<()> {
+    if !depth.is_finite() {
+        return Err(FontMeshError::ExtrusionFailed(
+            "depth must be a finite value".to_string(),
+        ));
+    }
+    let outline = extract_and_linearize_outline(face, character, quality.subdivisions())?;
+    REUSE_SCRATCH.with_borrow_mut(|(scratch, mesh_2d)| {
+        crate::triangulate::triangulate_into(&outline, scratch, mesh_2d)?;
+        crate::extrude::extrude_into(
+            mesh_2d,
+            &outline,
+            depth,
+            &crate::extrude::ExtrudeOptions::default(),
+            out,
+        )
+    })
+}
+
+/// Mesh the font's `.notdef` glyph (glyph 0), the box drawn for missing characters
+///
+/// Fonts whose `.notdef` is blank, or that have no outline for it, get a
+/// synthesized hollow box instead, so there is always something to render:
+/// as tall as the font's cap height (0.7 em if unknown) and as wide as the
+/// `.notdef` advance, less a tenth of it on each side.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidQuality`] if `subdivisions` is 0.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, notdef_mesh_2d};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let tofu = notdef_mesh_2d(&face, 20)?;
+/// assert!(!tofu.is_empty());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn notdef_mesh_2d(face: &Face, subdivisions: u8) -> Result<Mesh2D> {
+    if subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(subdivisions));
+    }
+    let outline = notdef_outline(face, subdivisions)?;
+    crate::triangulate::triangulate(&outline)
+}
+
+/// Mesh the font's `.notdef` glyph with extrusion
+///
+/// See [`notdef_mesh_2d`] for the fallback box used when the glyph is blank.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `depth` - The extrusion depth
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidQuality`] if `subdivisions` is 0 and
+/// [`FontMeshError::ExtrusionFailed`] if `depth` is not finite.
+pub fn notdef_mesh_3d(face: &Face, depth: f32, subdivisions: u8) -> Result<Mesh3D> {
+    if !depth.is_finite() {
+        return Err(FontMeshError::ExtrusionFailed(
+            "depth must be a finite value".to_string(),
+        ));
+    }
+    if subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(subdivisions));
+    }
+    let outline = notdef_outline(face, subdivisions)?;
+    let mesh_2d = crate::triangulate::triangulate(&outline)?;
+    crate::extrude::extrude(&mesh_2d, &outline, depth)
+}
+
+/// Linearized outline of glyph 0, or a hollow box if it draws nothing
+fn notdef_outline(face: &Face, subdivisions: u8) -> Result<Outline2D> {
+    match extract_outline(face, GlyphId(0)) {
+        Ok(outline) => linearize_for_face(face, outline, subdivisions),
+        Err(FontMeshError::EmptyOutline | FontMeshError::NoGlyphData) => Ok(notdef_box(face)),
+        Err(e) => Err(e),
+    }
+}
+
+/// A hollow rectangle sized like a typical glyph of the font, in em
+fn notdef_box(face: &Face) -> Outline2D {
+    let upm = face.units_per_em() as f32;
+    let height = face
+        .capital_height()
+        .filter(|&h| h > 0)
+        .map_or(crate::font::FALLBACK_CAP_HEIGHT, |h| h as f32 / upm);
+    let advance = face
+        .glyph_hor_advance(GlyphId(0))
+        .filter(|&a| a > 0)
+        .map_or(height * 0.75, |a| a as f32 / upm);
+    let (left, right) = (advance * 0.1, advance * 0.9);
+    let stroke = (right - left).min(height) * 0.1;
+
+    // Outer contour clockwise and inner counter-clockwise, as in TrueType
+    let rect = |x0: f32, y0: f32, x1: f32, y1: f32, clockwise: bool| {
+        let corners = [(x0, y0), (x0, y1), (x1, y1), (x1, y0)];
+        let mut contour = Contour::new(true);
+        for i in 0..4 {
+            let (x, y) = corners[if clockwise { i } else { (4 - i) % 4 }];
+            contour.push_on_curve(Point2D::new(x, y));
+        }
+        contour
+    };
+    let mut outline = Outline2D::new();
+    outline.add_contour(rect(left, 0.0, right, height, true));
+    outline.add_contour(rect(
+        left + stroke,
+        stroke,
+        right - stroke,
+        height - stroke,
+        false,
+    ));
+    outline
+}
+
 /// Extract and linearize a glyph outline from a parsed face
 ///
 /// This is a helper function used by the other pure functions.
@@ -89,21 +300,70 @@ fn extract_and_linearize_outline(
         .glyph_index(character)
         .ok_or(FontMeshError::GlyphNotFound(character))?;
 
-    let mut builder = OutlineExtractor::new(face.units_per_em());
+    let outline = extract_outline(face, glyph_id)?;
+    linearize_for_face(face, outline, subdivisions)
+}
+
+/// Linearize a glyph outline with options calibrated for the face's em size
+///
+/// See [`LinearizeOptions::for_units_per_em`].
+pub(crate) fn linearize_for_face(
+    face: &Face,
+    outline: Outline2D,
+    subdivisions: u8,
+) -> Result<Outline2D> {
+    let options = LinearizeOptions::for_units_per_em(subdivisions, face.units_per_em());
+    crate::linearize::linearize_outline_with(outline, &options)
+}
+
+/// Extract the raw (unlinearized) outline of a glyph, normalized to 1.0 em
+pub(crate) fn extract_outline(face: &Face, glyph_id: GlyphId) -> Result<Outline2D> {
+    extract_outline_scaled(face, glyph_id, 1.0 / face.units_per_em() as f32)
+}
+
+/// Extract the raw outline of a glyph with font units multiplied by `scale`
+fn extract_outline_scaled(face: &Face, glyph_id: GlyphId, scale: f32) -> Result<Outline2D> {
+    extract_outline_with(face, glyph_id, OutlineExtractor::with_scale(scale))
+}
+
+/// Run `builder` over a glyph and return the outline it collected
+fn extract_outline_with(
+    face: &Face,
+    glyph_id: GlyphId,
+    mut builder: OutlineExtractor,
+) -> Result<Outline2D> {
     face.outline_glyph(glyph_id, &mut builder)
-        .ok_or(FontMeshError::NoOutline)?;
+        .ok_or_else(|| missing_outline_error(face, glyph_id))?;
+    // CFF2 charstrings have no `endchar`, so ttf-parser never closes the
+    // final contour
+    builder.finish_contour();
 
     if builder.outline.is_empty() {
-        return Err(FontMeshError::NoOutline);
+        return Err(FontMeshError::EmptyOutline);
     }
 
-    crate::linearize::linearize_outline(builder.outline, subdivisions)
+    Ok(builder.outline)
+}
+
+/// Classify a glyph that ttf-parser couldn't outline
+///
+/// ttf-parser returns `None` both for blank glyphs (an empty `glyf` entry or
+/// a charstring that draws nothing) and when there is no outline data at
+/// all. A glyph in range of a font with outline tables is blank.
+fn missing_outline_error(face: &Face, glyph_id: GlyphId) -> FontMeshError {
+    let tables = face.tables();
+    let has_outlines = tables.glyf.is_some() || tables.cff.is_some() || tables.cff2.is_some();
+    if has_outlines && glyph_id.0 < face.number_of_glyphs() {
+        FontMeshError::EmptyOutline
+    } else {
+        FontMeshError::NoGlyphData
+    }
 }
 
 /// A glyph from a font
 pub struct Glyph<'a> {
-    /// The character this glyph represents
-    pub(crate) character: char,
+    /// The character this glyph represents, if it was looked up by one
+    pub(crate) character: Option<char>,
     /// The glyph ID in the font
     pub(crate) glyph_id: GlyphId,
     /// Reference to the font face
@@ -112,12 +372,20 @@ pub struct Glyph<'a> {
     pub(crate) advance: f32,
     /// Glyph bounds [[x_min, y_min], [x_max, y_max]] (normalized)
     pub(crate) bounds: Option<[[f32; 2]; 2]>,
+    /// Subdivisions used by the methods without an explicit quality
+    pub(crate) default_subdivisions: u8,
 }
 
 /// Builder for creating meshes from a glyph with configurable subdivisions
 pub struct GlyphMeshBuilder<'a> {
     glyph: &'a Glyph<'a>,
-    subdivisions: u8,
+    linearize: LinearizeOptions,
+    x_height_target: Option<f32>,
+    keep_left_bearing: bool,
+    skew: f32,
+    closing_tolerance: f32,
+    uvs: bool,
+    bevel: Option<crate::extrude::BevelOptions>,
 }
 
 impl<'a> GlyphMeshBuilder<'a> {
@@ -126,6 +394,15 @@ impl<'a> GlyphMeshBuilder<'a> {
     /// Higher values produce smoother curves but more vertices.
     /// Default is 20 subdivisions per curve.
     ///
+    /// Subdivision is adaptive: a curve gets at most `subdivisions / 4`
+    /// points (64 at 255), fewer the straighter it is. Past that, use
+    /// [`GlyphMeshBuilder::min_points_per_curve`]; see
+    /// [`LinearizeOptions::subdivisions`](crate::LinearizeOptions::subdivisions).
+    /// Building with 0 subdivisions fails with
+    /// [`FontMeshError::InvalidQuality`], except in
+    /// [`LinearizeMode::Chord`]. Replaces a tolerance set with
+    /// [`with_tolerance`](Self::with_tolerance).
+    ///
     /// # Example
     /// ```ignore
     /// let mesh = font.glyph_by_char('A')?
@@ -134,31 +411,434 @@ impl<'a> GlyphMeshBuilder<'a> {
     /// ```
     #[must_use = "builder methods are intended to be chained"]
     pub fn with_subdivisions(mut self, subdivisions: u8) -> Self {
-        self.subdivisions = subdivisions;
+        self.linearize.subdivisions = subdivisions;
+        if self.linearize.mode == LinearizeMode::Tolerance {
+            self.linearize.mode = LinearizeMode::Adaptive;
+        }
+        self
+    }
+
+    /// Flatten curves to within `max_deviation` instead of by subdivisions
+    ///
+    /// Switches to [`LinearizeMode::Tolerance`]: every curve is split until
+    /// no segment strays from it by more than `max_deviation` em, so the
+    /// same value gives the same visible smoothness at any glyph size. This
+    /// and [`with_subdivisions`](Self::with_subdivisions) are exclusive;
+    /// whichever is called last wins. Must be positive and finite, or
+    /// meshing fails with [`FontMeshError::InvalidParameter`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// // At most a tenth of a pixel off at 64 pixels per em
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .with_tolerance(0.1 / 64.0)
+    ///     .to_mesh_2d()?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn with_tolerance(mut self, max_deviation: f32) -> Self {
+        self.linearize.mode = LinearizeMode::Tolerance;
+        self.linearize.max_deviation = max_deviation;
+        self
+    }
+
+    /// Set the number of subdivisions per curve from a named [`Quality`]
+    ///
+    /// Same as [`GlyphMeshBuilder::with_subdivisions`] with
+    /// [`Quality::subdivisions`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .min_points_per_curve(2)
+    ///     .with_quality(Quality::High)
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn with_quality(self, quality: Quality) -> Self {
+        self.with_subdivisions(quality.subdivisions())
+    }
+
+    /// Give every curve at least `points` intermediate points
+    ///
+    /// Overrides adaptive subdivision's skipping of nearly straight curves,
+    /// which can leave gentle strokes faceted at large sizes. See
+    /// [`LinearizeOptions::min_points_per_curve`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .min_points_per_curve(4)
+    ///     .to_mesh_2d()?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn min_points_per_curve(mut self, points: u8) -> Self {
+        self.linearize.min_points_per_curve = points;
+        self
+    }
+
+    /// Set the area below which near-collinear points are removed
+    ///
+    /// Lower it for tiny glyphs that lose detail to the default of `1e-5`.
+    /// See [`LinearizeOptions::collinear_epsilon`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .collinear_epsilon(1e-8)
+    ///     .to_mesh_2d()?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn collinear_epsilon(mut self, epsilon: f32) -> Self {
+        self.linearize.collinear_epsilon = epsilon;
+        self
+    }
+
+    /// Never simplify away sharp corners
+    ///
+    /// See [`LinearizeOptions::preserve_corners`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .preserve_corners(true)
+    ///     .to_mesh_2d()?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn preserve_corners(mut self, preserve: bool) -> Self {
+        self.linearize.preserve_corners = preserve;
+        self
+    }
+
+    /// Drop solid contours enclosing less than `area` (in em²)
+    ///
+    /// A level-of-detail option for distant text. Holes are only dropped
+    /// with [`GlyphMeshBuilder::drop_small_holes`]. See
+    /// [`LinearizeOptions::min_contour_area`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(4)
+    ///     .min_contour_area(0.005)
+    ///     .to_mesh_2d()?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn min_contour_area(mut self, area: f32) -> Self {
+        self.linearize.min_contour_area = area;
+        self
+    }
+
+    /// Let [`GlyphMeshBuilder::min_contour_area`] drop small holes too
+    ///
+    /// See [`LinearizeOptions::drop_small_holes`].
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn drop_small_holes(mut self, drop: bool) -> Self {
+        self.linearize.drop_small_holes = drop;
+        self
+    }
+
+    /// Choose how curves are flattened
+    ///
+    /// [`LinearizeMode::Chord`] replaces every curve with a straight line for
+    /// the lowest vertex count, e.g. for thumbnails; subdivisions (even 0)
+    /// are then ignored.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(0)
+    ///     .linearize_mode(LinearizeMode::Chord)
+    ///     .to_mesh_2d()?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn linearize_mode(mut self, mode: LinearizeMode) -> Self {
+        self.linearize.mode = mode;
+        self
+    }
+
+    /// Scale the glyph so the font's x-height equals `target`
+    ///
+    /// Normalizing by x-height instead of em size gives a consistent visual
+    /// size when mixing fonts in one layout. The scale is applied to the
+    /// outline before triangulation, so the extrusion depth is unaffected.
+    /// See [`crate::x_height`] for how the x-height is determined.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .match_x_height(0.5)
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn match_x_height(mut self, target: f32) -> Self {
+        self.x_height_target = Some(target);
+        self
+    }
+
+    /// Choose whether the mesh keeps the glyph's left side bearing
+    ///
+    /// By default (`true`) the outline stays in font coordinates: x = 0 is the
+    /// glyph origin and the shape starts at its left side bearing, which is
+    /// what text layout with [`Glyph::advance`] expects. Pass `false` to shift
+    /// the outline so its leftmost point is at x = 0, e.g. to place a single
+    /// glyph flush against an edge. The advance is not adjusted, so don't mix
+    /// shifted glyphs into a pen-advance layout.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .origin_at_left_bearing(false)
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn origin_at_left_bearing(mut self, keep: bool) -> Self {
+        self.keep_left_bearing = keep;
+        self
+    }
+
+    /// Slant the glyph to synthesize an oblique style
+    ///
+    /// `angle` is in degrees using the `post` table convention, so negative
+    /// values lean forward and [`crate::italic_angle`] of an italic face can
+    /// be passed straight in. A typical synthetic oblique is `-12.0`. The
+    /// outline is sheared about the baseline before triangulation, so the
+    /// side walls of an extruded mesh slant with it. Only x coordinates
+    /// change: the vertical extent and the advance stay as they were.
+    ///
+    /// An angle that is NaN or outside -90..90 degrees makes the terminal
+    /// methods return [`FontMeshError::InvalidParameter`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .skew(-12.0)
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn skew(mut self, angle: f32) -> Self {
+        self.skew = angle;
+        self
+    }
+
+    /// Generate texture coordinates for 3D meshes
+    ///
+    /// With this set, [`to_mesh_3d`](Self::to_mesh_3d) fills
+    /// [`Mesh3D::uvs`](crate::Mesh3D::uvs) with one UV per vertex: the caps
+    /// are mapped planarly to `0..1` across the glyph's bounding box and the
+    /// side walls get `u` along the contour's arc length and `v` from front
+    /// to back ([`UvMode::PerGlyphBounds`](crate::UvMode::PerGlyphBounds)).
+    /// Off by default, so meshes without textures don't pay for UVs. 2D
+    /// meshes are unaffected.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .with_uvs(true)
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn with_uvs(mut self, uvs: bool) -> Self {
+        self.uvs = uvs;
+        self
+    }
+
+    /// Bevel the edges of 3D meshes
+    ///
+    /// [`to_mesh_3d`](Self::to_mesh_3d) then insets the caps and joins them
+    /// to the side walls with a chamfered or rounded bevel, as described in
+    /// [`extrude_beveled`](crate::extrude_beveled). Beveled meshes get no
+    /// UVs, even with [`with_uvs`](Self::with_uvs). 2D meshes are
+    /// unaffected.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .with_bevel(BevelOptions { width: 0.01, segments: 4, style: BevelStyle::Round })
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn with_bevel(mut self, bevel: crate::extrude::BevelOptions) -> Self {
+        self.bevel = Some(bevel);
+        self
+    }
+
+    /// Drop a contour's last point if it lies within `units` of its first
+    ///
+    /// Glyph contours are always closed, but some fonts end a contour with
+    /// a point that lands near, not on, its starting point; the tiny closing
+    /// edge left behind degrades triangulation and adds a sliver to the
+    /// side walls. The distance is in font units; the default is
+    /// [`DEFAULT_CLOSING_TOLERANCE`] (half a unit), which catches exact and
+    /// fractional near-misses. Use 0 (or any negative value) to only drop
+    /// exact duplicates.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .closing_tolerance(2.0)
+    ///     .to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn closing_tolerance(mut self, units: f32) -> Self {
+        self.closing_tolerance = units;
         self
     }
 
     /// Convert to a linearized outline
+    ///
+    /// This is the outline [`to_mesh_2d`](Self::to_mesh_2d) and
+    /// [`to_mesh_3d`](Self::to_mesh_3d) triangulate, with every builder
+    /// setting applied, so triangulating it yourself gives the same mesh.
+    ///
+    /// # Errors
+    /// - [`FontMeshError::InvalidQuality`] if subdivisions is 0, in
+    ///   [`LinearizeMode::Adaptive`]
+    /// - [`FontMeshError::InvalidParameter`] for a
+    ///   [`with_tolerance`](Self::with_tolerance) value that isn't positive
+    ///   and finite
+    /// - [`FontMeshError::EmptyOutline`] for a glyph that draws nothing,
+    ///   such as a space, and [`FontMeshError::NoGlyphData`] if the font has
+    ///   no outline for it
+    /// - [`FontMeshError::MissingMetric`] with
+    ///   [`match_x_height`](Self::match_x_height) on a font without an
+    ///   x-height
+    /// - [`FontMeshError::InvalidParameter`] for a [`skew`](Self::skew)
+    ///   angle outside -90..90 degrees
+    ///
+    /// Every terminal method returns a [`Result`], so a chain through the
+    /// fluent methods on [`Outline2D`] and [`Mesh2D`] composes with `?`:
+    ///
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let outline = Glyph::new(&face, 'A')?.with_subdivisions(20).to_outline()?;
+    /// let mesh = outline.triangulate()?.extrude(&outline, 0.1)?;
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
     pub fn to_outline(self) -> Result<crate::types::Outline2D> {
-        self.glyph.linearize_with(self.subdivisions)
+        self.build_outline()
     }
 
     /// Convert to a 2D triangle mesh
+    ///
+    /// # Errors
+    /// The errors of [`to_outline`](Self::to_outline), plus
+    /// [`FontMeshError::TriangulationFailed`] if lyon rejects the outline.
     pub fn to_mesh_2d(self) -> Result<crate::types::Mesh2D> {
-        let outline = self.glyph.linearize_with(self.subdivisions)?;
+        let outline = self.build_outline()?;
         crate::triangulate::triangulate(&outline)
     }
 
+    /// Convert to a 2D triangle mesh, panicking on error
+    ///
+    /// A shortcut for prototypes and examples where any failure is a bug;
+    /// use [`to_mesh_2d`](Self::to_mesh_2d) to handle errors.
+    ///
+    /// # Panics
+    /// On any error [`to_mesh_2d`](Self::to_mesh_2d) would return, with the
+    /// error in the message.
+    #[track_caller]
+    pub fn to_mesh_2d_or_panic(self) -> crate::types::Mesh2D {
+        self.to_mesh_2d()
+            .unwrap_or_else(|e| panic!("failed to mesh glyph: {}", e))
+    }
+
     /// Convert to a 3D triangle mesh with extrusion
+    ///
+    /// # Errors
+    /// The errors of [`to_mesh_2d`](Self::to_mesh_2d), plus
+    /// [`FontMeshError::ExtrusionFailed`] for a non-finite `depth` or a
+    /// bevel that doesn't fit the outline, and
+    /// [`FontMeshError::InvalidParameter`] for an invalid bevel width (see
+    /// [`extrude_beveled`](crate::extrude_beveled)).
     pub fn to_mesh_3d(self, depth: f32) -> Result<crate::types::Mesh3D> {
         if !depth.is_finite() {
             return Err(FontMeshError::ExtrusionFailed(
                 "depth must be a finite value".to_string(),
             ));
         }
-        let outline = self.glyph.linearize_with(self.subdivisions)?;
+        let outline = self.build_outline()?;
         let mesh_2d = crate::triangulate::triangulate(&outline)?;
-        crate::extrude::extrude(&mesh_2d, &outline, depth)
+        if let Some(bevel) = self.bevel {
+            return crate::extrude::extrude_beveled(&mesh_2d, &outline, depth, bevel);
+        }
+        let options = crate::extrude::ExtrudeOptions {
+            uv_mode: self.uvs.then_some(crate::extrude::UvMode::PerGlyphBounds),
+            ..Default::default()
+        };
+        crate::extrude::extrude_with(&mesh_2d, &outline, depth, &options)
+    }
+
+    /// Convert to a 3D triangle mesh, with the depth given in em or relative
+    /// to the font's cap height
+    ///
+    /// The depth is resolved against the font's unscaled cap height (see
+    /// [`ExtrudeDepth::resolve`](crate::ExtrudeDepth::resolve)), so
+    /// [`match_x_height`](Self::match_x_height) doesn't change it.
+    ///
+    /// # Errors
+    /// The errors of [`to_mesh_3d`](Self::to_mesh_3d).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_subdivisions(20)
+    ///     .to_mesh_3d_with_depth(ExtrudeDepth::RelativeToCapHeight(0.3))?;
+    /// ```
+    pub fn to_mesh_3d_with_depth(
+        self,
+        depth: crate::extrude::ExtrudeDepth,
+    ) -> Result<crate::types::Mesh3D> {
+        let depth = depth.resolve(self.glyph.face);
+        self.to_mesh_3d(depth)
+    }
+
+    /// Convert to a 3D triangle mesh with extrusion, panicking on error
+    ///
+    /// See [`to_mesh_2d_or_panic`](Self::to_mesh_2d_or_panic).
+    ///
+    /// # Panics
+    /// On any error [`to_mesh_3d`](Self::to_mesh_3d) would return, with the
+    /// error in the message.
+    #[track_caller]
+    pub fn to_mesh_3d_or_panic(self, depth: f32) -> crate::types::Mesh3D {
+        self.to_mesh_3d(depth)
+            .unwrap_or_else(|e| panic!("failed to mesh glyph: {}", e))
+    }
+
+    /// Linearize the glyph and apply the configured outline transforms
+    fn build_outline(&self) -> Result<Outline2D> {
+        if self.linearize.subdivisions == 0 && self.linearize.mode == LinearizeMode::Adaptive {
+            return Err(FontMeshError::InvalidQuality(0));
+        }
+        let mut extractor = OutlineExtractor::new(self.glyph.face.units_per_em());
+        extractor.closing_tolerance = self.closing_tolerance;
+        let raw = extract_outline_with(self.glyph.face, self.glyph.glyph_id, extractor)?;
+        let mut outline = crate::linearize::linearize_outline_with(raw, &self.linearize)?;
+
+        if let Some(target) = self.x_height_target {
+            let x_height = crate::font::x_height(self.glyph.face)
+                .filter(|h| *h > 0.0)
+                .ok_or(FontMeshError::MissingMetric("x-height"))?;
+            outline.scale(target / x_height);
+        }
+
+        if self.skew != 0.0 {
+            if self.skew.is_nan() || self.skew.abs() >= 90.0 {
+                return Err(FontMeshError::InvalidParameter(
+                    "skew angle must be between -90 and 90 degrees".to_string(),
+                ));
+            }
+            outline.shear_x(-self.skew.to_radians().tan());
+        }
+
+        if !self.keep_left_bearing {
+            if let Some([[x_min, _], _]) = outline.bounds() {
+                outline.translate(Vec2::new(-x_min, 0.0));
+            }
+        }
+
+        Ok(outline)
     }
 }
 
@@ -168,38 +848,124 @@ impl<'a> Glyph<'a> {
         let glyph_id = face
             .glyph_index(character)
             .ok_or(FontMeshError::GlyphNotFound(character))?;
+        Ok(Self::from_parts(face, Some(character), glyph_id))
+    }
 
+    /// Create a new Glyph wrapper from a Face and a glyph ID
+    ///
+    /// For glyphs with no character of their own, such as ligatures ("fi",
+    /// "ffl") or contextual forms returned by a shaper. The resulting glyph's
+    /// [`character`](Self::character) is `None`.
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidGlyphId`] if the font has no glyph
+    /// with this ID.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, GlyphId, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let glyph = Glyph::from_id(&face, GlyphId(36))?;
+    /// assert_eq!(glyph.character(), None);
+    /// let mesh = glyph.with_subdivisions(20).to_mesh_3d(0.1)?;
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn from_id(face: &'a Face<'a>, glyph_id: GlyphId) -> Result<Self> {
+        if glyph_id.0 >= face.number_of_glyphs() {
+            return Err(FontMeshError::InvalidGlyphId(glyph_id.0));
+        }
+        Ok(Self::from_parts(face, None, glyph_id))
+    }
+
+    fn from_parts(face: &'a Face<'a>, character: Option<char>, glyph_id: GlyphId) -> Self {
         let advance = face
             .glyph_hor_advance(glyph_id)
             .map(|adv| adv as f32 / face.units_per_em() as f32)
             .unwrap_or(0.0);
 
-        let bounds = face.glyph_bounding_box(glyph_id).map(|bb| {
-            let scale = 1.0 / face.units_per_em() as f32;
-            [
+        // ttf-parser reports no box when its own computation fails (e.g. CFF
+        // coordinates beyond the i16 range) even though the glyph drew
+        // points, so measure those points in the same pass instead
+        let scale = 1.0 / face.units_per_em() as f32;
+        let mut recorder = BoundsRecorder::new();
+        let bounds = match face.outline_glyph(glyph_id, &mut recorder) {
+            Some(bb) => Some([
                 [bb.x_min as f32 * scale, bb.y_min as f32 * scale],
                 [bb.x_max as f32 * scale, bb.y_max as f32 * scale],
-            ]
-        });
+            ]),
+            None => recorder.bounds(scale),
+        };
 
-        Ok(Self {
+        Self {
             character,
             glyph_id,
             face,
             advance,
             bounds,
-        })
+            default_subdivisions: DEFAULT_QUALITY,
+        }
+    }
+
+    /// Set the subdivisions used when none are given explicitly
+    ///
+    /// Affects [`Glyph::linearize`], [`Glyph::to_mesh_2d`],
+    /// [`Glyph::to_mesh_3d`] and the methods built on them, such as
+    /// [`Glyph::stroke_widths`] and [`Glyph::grid_fit`], so an app can pick
+    /// its quality once when creating glyphs. [`Glyph::with_subdivisions`]
+    /// still overrides it per call.
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidQuality`] if `subdivisions` is 0; the
+    /// default is left unchanged.
+    pub fn set_default_subdivisions(&mut self, subdivisions: u8) -> Result<()> {
+        if subdivisions == 0 {
+            return Err(FontMeshError::InvalidQuality(subdivisions));
+        }
+        self.default_subdivisions = subdivisions;
+        Ok(())
+    }
+
+    /// Return the glyph with a different default subdivision count
+    ///
+    /// See [`Glyph::set_default_subdivisions`].
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let coarse = Glyph::new(&face, 'O')?.with_default_subdivisions(4)?;
+    /// let fine = Glyph::new(&face, 'O')?;
+    /// assert!(coarse.to_mesh_2d()?.vertices.len() < fine.to_mesh_2d()?.vertices.len());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn with_default_subdivisions(mut self, subdivisions: u8) -> Result<Self> {
+        self.set_default_subdivisions(subdivisions)?;
+        Ok(self)
+    }
+
+    /// Get the subdivisions used when none are given explicitly
+    #[inline]
+    pub fn default_subdivisions(&self) -> u8 {
+        self.default_subdivisions
     }
 
     /// Get the character this glyph represents
     ///
+    /// Returns `None` for glyphs created by ID with [`Glyph::from_id`].
+    ///
     /// # Example
     /// ```ignore
     /// let glyph = font.glyph_by_char('A')?;
-    /// assert_eq!(glyph.character(), 'A');
+    /// assert_eq!(glyph.character(), Some('A'));
     /// ```
     #[inline]
-    pub fn character(&self) -> char {
+    pub fn character(&self) -> Option<char> {
         self.character
     }
 
@@ -230,14 +996,42 @@ impl<'a> Glyph<'a> {
     /// let width = glyph.advance();
     /// ```
     #[inline]
-    pub fn advance(&self) -> f32 {
-        self.advance
+    pub fn advance(&self) -> f32 {
+        self.advance
+    }
+
+    /// Get the horizontal advance width in font units, unnormalized
+    ///
+    /// The raw `hmtx` value, for exact integer pen arithmetic that must
+    /// match another renderer's layout. `0` if the font has no advance for
+    /// the glyph, as for [`Glyph::advance`].
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let glyph = Glyph::new(&face, 'A')?;
+    /// let mut pen_x = 0i32;
+    /// pen_x += i32::from(glyph.advance_units());
+    ///
+    /// let em = f32::from(face.units_per_em());
+    /// assert_eq!(pen_x as f32 / em, glyph.advance());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[inline]
+    pub fn advance_units(&self) -> u16 {
+        self.face.glyph_hor_advance(self.glyph_id).unwrap_or(0)
     }
 
     /// Get the glyph bounds (normalized to 1.0 em)
     ///
     /// Returns `[[x_min, y_min], [x_max, y_max]]` if the glyph has an outline,
-    /// or `None` for whitespace characters.
+    /// or `None` for whitespace characters. When the font doesn't provide a
+    /// bounding box for a glyph that does have an outline, the bounds are
+    /// computed from the outline points instead.
     ///
     /// # Example
     /// ```ignore
@@ -251,6 +1045,89 @@ impl<'a> Glyph<'a> {
         self.bounds
     }
 
+    /// Check whether this glyph only advances the pen, drawing nothing
+    ///
+    /// True for spaces and other glyphs without an outline, i.e. exactly when
+    /// [`Glyph::bounds`] is `None`. No outline is extracted, so this is cheap
+    /// to call in layout loops before meshing.
+    ///
+    /// # Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// assert!(Glyph::new(&face, ' ')?.is_whitespace());
+    /// assert!(!Glyph::new(&face, 'A')?.is_whitespace());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[inline]
+    pub fn is_whitespace(&self) -> bool {
+        self.bounds.is_none()
+    }
+
+    /// Check whether this glyph is a composite of other glyphs
+    ///
+    /// Composite glyphs (e.g. 'é' built from 'e' and an accent) reference
+    /// other glyphs with a transform instead of storing their own outline.
+    /// Only TrueType `glyf` fonts have composites; this is always `false` for
+    /// CFF fonts.
+    ///
+    /// # Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// assert!(Glyph::new(&face, 'é')?.is_composite());
+    /// assert!(!Glyph::new(&face, 'e')?.is_composite());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn is_composite(&self) -> bool {
+        glyf_data(self.face, self.glyph_id)
+            .is_some_and(|data| data.len() >= 2 && i16::from_be_bytes([data[0], data[1]]) < 0)
+    }
+
+    /// Get the components of a composite glyph
+    ///
+    /// Each entry is the referenced glyph and the transform applied to it.
+    /// The translation (`e`, `f`) is normalized to 1.0 em like every other
+    /// coordinate; components positioned by anchor points rather than offsets
+    /// report a zero translation. Components may themselves be composites.
+    ///
+    /// # Returns
+    /// The components in drawing order, or an empty list for simple glyphs
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let e_acute = Glyph::new(&face, 'é')?;
+    /// let e = face.glyph_index('e').unwrap();
+    /// assert!(e_acute.components().iter().any(|(id, _)| *id == e));
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn components(&self) -> Vec<(GlyphId, Transform)> {
+        if !self.is_composite() {
+            return Vec::new();
+        }
+        let Some(data) = glyf_data(self.face, self.glyph_id) else {
+            return Vec::new();
+        };
+
+        let scale = 1.0 / self.face.units_per_em() as f32;
+        let mut components = parse_components(data.get(10..).unwrap_or_default());
+        for (_, transform) in &mut components {
+            transform.e *= scale;
+            transform.f *= scale;
+        }
+        components
+    }
+
     /// Set the number of subdivisions per curve for mesh generation (builder pattern)
     ///
     /// Higher values produce smoother curves but more vertices.
@@ -266,38 +1143,222 @@ impl<'a> Glyph<'a> {
     pub fn with_subdivisions(&self, subdivisions: u8) -> GlyphMeshBuilder<'_> {
         GlyphMeshBuilder {
             glyph: self,
-            subdivisions,
+            linearize: LinearizeOptions::for_units_per_em(subdivisions, self.face.units_per_em()),
+            x_height_target: None,
+            keep_left_bearing: true,
+            skew: 0.0,
+            closing_tolerance: DEFAULT_CLOSING_TOLERANCE,
+            uvs: false,
+            bevel: None,
         }
     }
 
+    /// Start building a mesh at a named [`Quality`] (builder pattern)
+    ///
+    /// Same as [`Glyph::with_subdivisions`] with [`Quality::subdivisions`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mesh = glyph.with_quality(Quality::High).to_mesh_3d(0.1)?;
+    /// ```
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn with_quality(&self, quality: Quality) -> GlyphMeshBuilder<'_> {
+        self.with_subdivisions(quality.subdivisions())
+    }
+
     /// Extract the glyph's outline
     ///
     /// # Returns
     /// The 2D outline of the glyph, or an error if extraction fails
     #[inline]
     pub fn outline(&self) -> Result<Outline2D> {
+        extract_outline(self.face, self.glyph_id)
+    }
+
+    /// Extract the glyph's outline with coordinates snapped to whole font units
+    ///
+    /// Like [`Glyph::outline`], but every coordinate is rounded to the font's
+    /// unit grid before being normalized. CFF charstrings, variation deltas
+    /// and scaled composites can leave points a hair off the grid, so edges
+    /// of pixel-style fonts that should meet exactly end up slightly apart
+    /// and leave hairline cracks in the mesh. After snapping, points on the
+    /// same grid line have bit-identical coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph, linearize_outline};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let glyph = Glyph::new(&face, 'A')?;
+    ///
+    /// let outline = linearize_outline(glyph.outline_rounded()?, 20)?;
+    /// let mesh = outline.to_mesh_3d(0.1)?;
+    /// assert!(mesh.triangle_count() > 0);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn outline_rounded(&self) -> Result<Outline2D> {
         let mut builder = OutlineExtractor::new(self.face.units_per_em());
+        builder.snap_to_units = true;
+        extract_outline_with(self.face, self.glyph_id, builder)
+    }
+
+    /// Extract the glyph's outline in pixel units at a given em size
+    ///
+    /// Coordinates are multiplied by `ppem` rather than normalized to 1.0,
+    /// so one em spans `ppem` units. Curves are left unlinearized, as with
+    /// [`Glyph::outline`].
+    ///
+    /// # Arguments
+    /// * `ppem` - Pixels per em (must be positive and finite)
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidParameter`] for an invalid `ppem`,
+    /// otherwise the same errors as [`Glyph::outline`]
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let outline = Glyph::new(&face, 'H')?.outline_scaled(16.0)?;
+    /// let [_, max] = outline.bounds().unwrap();
+    /// assert!(max[1] > 10.0 && max[1] < 16.0);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn outline_scaled(&self, ppem: f32) -> Result<Outline2D> {
+        if !ppem.is_finite() || ppem <= 0.0 {
+            return Err(FontMeshError::InvalidParameter(
+                "ppem must be a positive finite value".to_string(),
+            ));
+        }
+        let scale = ppem / self.face.units_per_em() as f32;
+        extract_outline_scaled(self.face, self.glyph_id, scale)
+    }
+
+    /// Triangulate each filled component of the glyph as a separate mesh
+    ///
+    /// Every outer contour, together with the holes directly inside it,
+    /// becomes one mesh: 'i' yields the stem and the dot, 'B' a single mesh
+    /// with two holes. Meshes are in the order the font draws the outer
+    /// contours, which suits reveal animations that show them one at a
+    /// time. Coordinates match [`GlyphMeshBuilder::to_mesh_2d`] with the same
+    /// subdivisions.
+    ///
+    /// # Arguments
+    /// * `subdivisions` - Number of subdivisions per curve
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let parts = Glyph::new(&face, 'i')?.contour_meshes_2d(20)?;
+    /// assert_eq!(parts.len(), 2);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn contour_meshes_2d(&self, subdivisions: u8) -> Result<Vec<crate::types::Mesh2D>> {
+        let outline = self.with_subdivisions(subdivisions).to_outline()?;
+
+        outline
+            .contour_groups()
+            .into_iter()
+            .map(|group| {
+                let mut component = Outline2D::new();
+                for i in group {
+                    component.add_contour(outline.contours[i].clone());
+                }
+                crate::triangulate::triangulate(&component)
+            })
+            .collect()
+    }
 
+    /// Get the glyph's contours as typed line and Bézier segments
+    ///
+    /// Coordinates are normalized to 1.0 em. Every contour is closed: a
+    /// closing [`Segment::Line`] is added when the last segment doesn't end at
+    /// the contour's start.
+    ///
+    /// # Returns
+    /// One [`ContourCurves`] per contour, `FontMeshError::EmptyOutline` for
+    /// blank glyphs, or `FontMeshError::NoGlyphData` if the font has no
+    /// outline for the glyph
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph, types::Segment};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let contours = Glyph::new(&face, 'O')?.curve_segments()?;
+    /// assert_eq!(contours.len(), 2);
+    /// assert!(contours[0].iter().any(|s| matches!(s, Segment::Quad(..))));
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn curve_segments(&self) -> Result<Vec<ContourCurves>> {
+        let mut recorder = SegmentRecorder {
+            scale: 1.0 / self.face.units_per_em() as f32,
+            ..SegmentRecorder::default()
+        };
         self.face
-            .outline_glyph(self.glyph_id, &mut builder)
-            .ok_or(FontMeshError::NoOutline)?;
+            .outline_glyph(self.glyph_id, &mut recorder)
+            .ok_or_else(|| missing_outline_error(self.face, self.glyph_id))?;
+        recorder.finish_contour();
 
-        if builder.outline.is_empty() {
-            return Err(FontMeshError::NoOutline);
+        if recorder.contours.is_empty() {
+            return Err(FontMeshError::EmptyOutline);
         }
+        Ok(recorder.contours)
+    }
 
-        Ok(builder.outline)
+    /// Measure the local stroke width at points spread evenly along the outline
+    ///
+    /// For each sample on the linearized outline, a ray is cast into the glyph
+    /// body (perpendicular to the contour) until it hits the opposing edge. The
+    /// result pairs the midpoint between the two edges, an interior point on
+    /// the stroke's centerline, with the distance between them. Useful for
+    /// analysing stroke contrast.
+    ///
+    /// Samples whose ray escapes the glyph or whose midpoint isn't filled (e.g.
+    /// at overlapping contours) are skipped, so fewer than `samples` entries
+    /// may be returned.
+    ///
+    /// # Arguments
+    /// * `samples` - Number of points to sample along the outline
+    ///
+    /// # Returns
+    /// `(interior_point, width)` pairs in normalized em units
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let widths = Glyph::new(&face, 'o')?.stroke_widths(64)?;
+    /// let thinnest = widths.iter().map(|&(_, w)| w).fold(f32::MAX, f32::min);
+    /// assert!(thinnest > 0.0);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn stroke_widths(&self, samples: usize) -> Result<Vec<(Vec2, f32)>> {
+        let outline = self.linearize()?;
+        Ok(measure_stroke_widths(&outline, samples))
     }
 
     /// Linearize the glyph's outline by converting curves to line segments
     ///
-    /// Uses default quality (20 subdivisions per curve).
+    /// Uses the glyph's default quality (20 subdivisions per curve unless changed
+    /// with [`Glyph::set_default_subdivisions`]).
     ///
     /// # Returns
     /// A linearized outline ready for triangulation
     #[inline]
     pub fn linearize(&self) -> Result<Outline2D> {
-        self.linearize_with(DEFAULT_QUALITY)
+        self.linearize_with(self.default_subdivisions)
     }
 
     /// Linearize the glyph's outline with custom number of subdivisions
@@ -310,12 +1371,112 @@ impl<'a> Glyph<'a> {
     #[inline]
     pub fn linearize_with(&self, subdivisions: u8) -> Result<Outline2D> {
         let outline = self.outline()?;
-        crate::linearize::linearize_outline(outline, subdivisions)
+        linearize_for_face(self.face, outline, subdivisions)
+    }
+
+    /// Count the outline points linearization emits at a subdivision level
+    ///
+    /// Curves are subdivided adaptively, so `subdivisions` is an upper
+    /// bound per curve rather than the number of points produced. This runs
+    /// [`Glyph::linearize_with`] without triangulating and sums the points of
+    /// every contour, which is the vertex count of the 2D mesh (and of each
+    /// cap of the 3D mesh) unless lyon has to add vertices where contours
+    /// cross.
+    ///
+    /// # Arguments
+    /// * `subdivisions` - Number of subdivisions per curve
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let glyph = Glyph::new(&face, 'O')?;
+    ///
+    /// let mesh = glyph.with_subdivisions(20).to_mesh_2d()?;
+    /// assert_eq!(glyph.estimate_vertices(20)?, mesh.vertices.len());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn estimate_vertices(&self, subdivisions: u8) -> Result<usize> {
+        let outline = self.linearize_with(subdivisions)?;
+        Ok(outline.contours.iter().map(|c| c.points.len()).sum())
+    }
+
+    /// Measure the area the glyph fills, in em², without building a mesh
+    ///
+    /// Triangulates the linearized outline and sums the triangle areas, so
+    /// holes are excluded and overlapping contours count once. Useful for
+    /// balancing optical weight across a font. Whitespace glyphs return 0.
+    ///
+    /// # Arguments
+    /// * `subdivisions` - Number of subdivisions per curve
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidQuality`] if `subdivisions` is 0, or
+    /// the error triangulation fails with.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let period = Glyph::new(&face, '.')?.filled_area(20)?;
+    /// let w = Glyph::new(&face, 'W')?.filled_area(20)?;
+    /// assert!(0.0 < period && period < w);
+    /// assert_eq!(Glyph::new(&face, ' ')?.filled_area(20)?, 0.0);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn filled_area(&self, subdivisions: u8) -> Result<f32> {
+        if subdivisions == 0 {
+            return Err(FontMeshError::InvalidQuality(subdivisions));
+        }
+        let outline = match self.linearize_with(subdivisions) {
+            Ok(outline) => outline,
+            Err(FontMeshError::EmptyOutline) => return Ok(0.0),
+            Err(e) => return Err(e),
+        };
+        let mesh = crate::triangulate::triangulate(&outline)?;
+        Ok(mesh.area())
+    }
+
+    /// Linearize the glyph's outline and snap contour extrema to a pixel grid
+    ///
+    /// Lightweight grid fitting for crisp rasterization at small sizes: the
+    /// left/right/bottom/top extrema of each contour land on pixel boundaries
+    /// for the given ppem. This is not TrueType hinting - see
+    /// [`crate::hinting::grid_fit`] for its limitations.
+    ///
+    /// The result stays in normalized em units.
+    ///
+    /// # Arguments
+    /// * `ppem` - Target pixels per em (must be positive and finite)
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidParameter`] for an invalid `ppem`,
+    /// otherwise the same errors as [`Glyph::linearize`]
+    ///
+    /// # Example
+    /// ```ignore
+    /// let outline = glyph.grid_fit(12.0)?;
+    /// let mesh = outline.triangulate()?;
+    /// ```
+    pub fn grid_fit(&self, ppem: f32) -> Result<Outline2D> {
+        if !ppem.is_finite() || ppem <= 0.0 {
+            return Err(FontMeshError::InvalidParameter(
+                "ppem must be a positive finite value".to_string(),
+            ));
+        }
+        let outline = self.linearize()?;
+        Ok(crate::hinting::grid_fit(&outline, ppem))
     }
 
     /// Convert this glyph to a 2D triangle mesh
     ///
-    /// Uses default quality (20 subdivisions per curve).
+    /// Uses the glyph's default quality (20 subdivisions per curve unless changed
+    /// with [`Glyph::set_default_subdivisions`]).
     ///
     /// # Example
     /// ```ignore
@@ -329,7 +1490,8 @@ impl<'a> Glyph<'a> {
 
     /// Convert this glyph to a 3D triangle mesh with extrusion
     ///
-    /// Uses default quality (20 subdivisions per curve).
+    /// Uses the glyph's default quality (20 subdivisions per curve unless changed
+    /// with [`Glyph::set_default_subdivisions`]).
     ///
     /// # Arguments
     /// * `depth` - The extrusion depth
@@ -349,30 +1511,255 @@ impl<'a> Glyph<'a> {
         let mesh_2d = crate::triangulate::triangulate(&outline)?;
         crate::extrude::extrude(&mesh_2d, &outline, depth)
     }
+
+    /// Build 3D meshes of this glyph at several levels of detail
+    ///
+    /// Produces one extruded mesh per entry of `levels`, in the same order,
+    /// e.g. `[50, 20, 8]` for an LOD chain from near to far. The outline is
+    /// extracted from the font once and only linearization, triangulation
+    /// and extrusion are repeated per level.
+    ///
+    /// # Arguments
+    /// * `levels` - Number of subdivisions per curve for each mesh
+    /// * `depth` - The extrusion depth
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidQuality`] if any level is 0, before
+    /// meshing anything, and otherwise the first error a level fails with.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let lods = Glyph::new(&face, 'S')?.lod_meshes_3d(&[50, 20, 8], 0.1)?;
+    /// assert_eq!(lods.len(), 3);
+    /// assert!(lods[0].vertices.len() > lods[2].vertices.len());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn lod_meshes_3d(&self, levels: &[u8], depth: f32) -> Result<Vec<crate::types::Mesh3D>> {
+        if !depth.is_finite() {
+            return Err(FontMeshError::ExtrusionFailed(
+                "depth must be a finite value".to_string(),
+            ));
+        }
+        if let Some(&level) = levels.iter().find(|&&level| level == 0) {
+            return Err(FontMeshError::InvalidQuality(level));
+        }
+
+        let outline = self.outline()?;
+        levels
+            .iter()
+            .map(|&subdivisions| {
+                let linearized = linearize_for_face(self.face, outline.clone(), subdivisions)?;
+                let mesh_2d = crate::triangulate::triangulate(&linearized)?;
+                crate::extrude::extrude(&mesh_2d, &linearized, depth)
+            })
+            .collect()
+    }
+}
+
+/// Sample stroke widths along a linearized outline (see [`Glyph::stroke_widths`])
+fn measure_stroke_widths(outline: &Outline2D, samples: usize) -> Vec<(Vec2, f32)> {
+    let edges: Vec<(Vec2, Vec2, bool)> = outline
+        .contours
+        .iter()
+        .zip(crate::extrude::contour_fill_sides(outline))
+        .flat_map(|(contour, filled_left)| {
+            let n = contour.points.len();
+            (0..n).map(move |i| {
+                (
+                    contour.points[i].point,
+                    contour.points[(i + 1) % n].point,
+                    filled_left,
+                )
+            })
+        })
+        .filter(|(a, b, _)| a.distance_squared(*b) > 1e-12)
+        .collect();
+
+    let perimeter: f32 = edges.iter().map(|(a, b, _)| a.distance(*b)).sum();
+    if samples == 0 || perimeter <= 0.0 {
+        return Vec::new();
+    }
+    let spacing = perimeter / samples as f32;
+
+    let is_filled = |p: Vec2| outline.contours.iter().filter(|c| c.contains(p)).count() % 2 == 1;
+
+    let mut result = Vec::with_capacity(samples);
+    let mut next_sample = spacing * 0.5;
+    let mut travelled = 0.0;
+
+    for (index, &(a, b, filled_left)) in edges.iter().enumerate() {
+        let length = a.distance(b);
+        let dir = (b - a) / length;
+        let inward = if filled_left {
+            Vec2::new(-dir.y, dir.x)
+        } else {
+            Vec2::new(dir.y, -dir.x)
+        };
+
+        while next_sample < travelled + length && result.len() < samples {
+            let origin = a + dir * (next_sample - travelled);
+            next_sample += spacing;
+
+            let nearest = edges
+                .iter()
+                .enumerate()
+                .filter(|&(other, _)| other != index)
+                .filter_map(|(_, &(c, d, _))| ray_segment_distance(origin, inward, c, d))
+                .filter(|&t| t > 1e-6)
+                .fold(f32::INFINITY, f32::min);
+
+            if nearest.is_finite() {
+                let midpoint = origin + inward * (nearest * 0.5);
+                if is_filled(midpoint) {
+                    result.push((midpoint, nearest));
+                }
+            }
+        }
+        travelled += length;
+    }
+
+    result
+}
+
+/// Distance along the ray `origin + t * dir` to segment `cd`, if it hits
+fn ray_segment_distance(origin: Vec2, dir: Vec2, c: Vec2, d: Vec2) -> Option<f32> {
+    let edge = d - c;
+    let denom = dir.perp_dot(edge);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+    let to_start = c - origin;
+    let t = to_start.perp_dot(edge) / denom;
+    let s = to_start.perp_dot(dir) / denom;
+    (t >= 0.0 && (0.0..=1.0).contains(&s)).then_some(t)
+}
+
+/// Raw `glyf` table data of a glyph, if the font has TrueType outlines
+fn glyf_data<'a>(face: &Face<'a>, glyph_id: GlyphId) -> Option<&'a [u8]> {
+    let raw = face.raw_face();
+    let glyf = raw.table(Tag::from_bytes(b"glyf"))?;
+    let loca = ttf_parser::loca::Table::parse(
+        std::num::NonZeroU16::new(face.number_of_glyphs())?,
+        face.tables().head.index_to_location_format,
+        raw.table(Tag::from_bytes(b"loca"))?,
+    )?;
+    glyf.get(loca.glyph_range(glyph_id)?)
+}
+
+/// Parse the component records of a composite glyph (after its 10-byte header)
+fn parse_components(mut data: &[u8]) -> Vec<(GlyphId, Transform)> {
+    const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    const ARGS_ARE_XY_VALUES: u16 = 0x0002;
+    const WE_HAVE_A_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+    fn take<'a>(data: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+        let (head, tail) = (data.get(..n)?, data.get(n..)?);
+        *data = tail;
+        Some(head)
+    }
+    let u16_at = |b: &[u8]| u16::from_be_bytes([b[0], b[1]]);
+    let f2dot14 = |b: &[u8]| i16::from_be_bytes([b[0], b[1]]) as f32 / 16384.0;
+
+    let mut components = Vec::new();
+    while let Some(header) = take(&mut data, 4) {
+        let flags = u16_at(header);
+        let glyph_id = GlyphId(u16_at(&header[2..]));
+        let mut transform = Transform::default();
+
+        let (e, f) = if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            let Some(args) = take(&mut data, 4) else {
+                break;
+            };
+            (u16_at(args) as i16 as f32, u16_at(&args[2..]) as i16 as f32)
+        } else {
+            let Some(args) = take(&mut data, 2) else {
+                break;
+            };
+            (args[0] as i8 as f32, args[1] as i8 as f32)
+        };
+        if flags & ARGS_ARE_XY_VALUES != 0 {
+            transform.e = e;
+            transform.f = f;
+        }
+
+        if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            let Some(m) = take(&mut data, 8) else {
+                break;
+            };
+            transform.a = f2dot14(m);
+            transform.b = f2dot14(&m[2..]);
+            transform.c = f2dot14(&m[4..]);
+            transform.d = f2dot14(&m[6..]);
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            let Some(m) = take(&mut data, 4) else {
+                break;
+            };
+            transform.a = f2dot14(m);
+            transform.d = f2dot14(&m[2..]);
+        } else if flags & WE_HAVE_A_SCALE != 0 {
+            let Some(m) = take(&mut data, 2) else {
+                break;
+            };
+            transform.a = f2dot14(m);
+            transform.d = transform.a;
+        }
+
+        components.push((glyph_id, transform));
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+    components
 }
 
+/// Default for [`GlyphMeshBuilder::closing_tolerance`], in font units
+pub const DEFAULT_CLOSING_TOLERANCE: f32 = 0.5;
+
 /// Outline builder that extracts glyph contours
 struct OutlineExtractor {
     outline: Outline2D,
     current_contour: Option<Contour>,
     scale: f32,
+    /// Round coordinates to whole font units before scaling
+    snap_to_units: bool,
+    /// Drop a final point this close to the first one, in font units
+    closing_tolerance: f32,
     last_point: Option<Point2D>,
 }
 
 impl OutlineExtractor {
     #[inline]
     fn new(units_per_em: u16) -> Self {
+        Self::with_scale(1.0 / units_per_em as f32)
+    }
+
+    #[inline]
+    fn with_scale(scale: f32) -> Self {
         Self {
             outline: Outline2D::new(),
             current_contour: None,
-            scale: 1.0 / units_per_em as f32,
+            scale,
+            snap_to_units: false,
+            closing_tolerance: DEFAULT_CLOSING_TOLERANCE,
             last_point: None,
         }
     }
 
     #[inline(always)]
     fn point(&self, x: f32, y: f32) -> Point2D {
-        Vec2::new(x * self.scale, y * self.scale)
+        if self.snap_to_units {
+            Vec2::new(x.round() * self.scale, y.round() * self.scale)
+        } else {
+            Vec2::new(x * self.scale, y * self.scale)
+        }
     }
 
     #[inline(always)]
@@ -385,7 +1772,20 @@ impl OutlineExtractor {
 
     #[inline]
     fn finish_contour(&mut self) {
-        if let Some(contour) = self.current_contour.take() {
+        if let Some(mut contour) = self.current_contour.take() {
+            // Contours are implicitly closed, so last points on (or within
+            // the tolerance of) the first only add degenerate seam edges
+            let tolerance = if self.closing_tolerance >= 0.0 {
+                self.closing_tolerance * self.scale
+            } else {
+                0.0
+            };
+            while let [first, .., last] = contour.points[..] {
+                if !last.on_curve || first.point.distance(last.point) > tolerance {
+                    break;
+                }
+                contour.points.pop();
+            }
             if !contour.is_empty() {
                 self.outline.add_contour(contour);
             }
@@ -424,8 +1824,8 @@ impl OutlineBuilder for OutlineExtractor {
     #[inline]
     fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
         // Cubic Bezier: two control points (off-curve) + end point (on-curve)
-        self.push_point(ContourPoint::off_curve(self.point(x1, y1)));
-        self.push_point(ContourPoint::off_curve(self.point(x2, y2)));
+        self.push_point(ContourPoint::cubic_control(self.point(x1, y1)));
+        self.push_point(ContourPoint::cubic_control(self.point(x2, y2)));
         self.push_point(ContourPoint::on_curve(self.point(x, y)));
     }
 
@@ -435,6 +1835,122 @@ impl OutlineBuilder for OutlineExtractor {
     }
 }
 
+/// Outline builder that only tracks the extent of the points it is given
+///
+/// Control points are included, so the box may be loose around curves.
+struct BoundsRecorder {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl BoundsRecorder {
+    fn new() -> Self {
+        Self {
+            min: Vec2::INFINITY,
+            max: Vec2::NEG_INFINITY,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, x: f32, y: f32) {
+        let p = Vec2::new(x, y);
+        self.min = self.min.min(p);
+        self.max = self.max.max(p);
+    }
+
+    /// The box in font units times `scale`, or `None` if no point was seen
+    fn bounds(&self, scale: f32) -> Option<[[f32; 2]; 2]> {
+        (self.min.x <= self.max.x)
+            .then(|| [(self.min * scale).to_array(), (self.max * scale).to_array()])
+    }
+}
+
+impl OutlineBuilder for BoundsRecorder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.add(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.add(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.add(x1, y1);
+        self.add(x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.add(x1, y1);
+        self.add(x2, y2);
+        self.add(x, y);
+    }
+
+    fn close(&mut self) {}
+}
+
+/// Outline builder that records typed segments
+#[derive(Default)]
+struct SegmentRecorder {
+    contours: Vec<ContourCurves>,
+    current: ContourCurves,
+    scale: f32,
+    start: Point2D,
+    last: Point2D,
+}
+
+impl SegmentRecorder {
+    #[inline(always)]
+    fn point(&self, x: f32, y: f32) -> Point2D {
+        Vec2::new(x * self.scale, y * self.scale)
+    }
+
+    fn finish_contour(&mut self) {
+        if self.current.is_empty() {
+            return;
+        }
+        if self.last != self.start {
+            self.current.push(Segment::Line(self.last, self.start));
+        }
+        self.contours.push(std::mem::take(&mut self.current));
+    }
+}
+
+impl OutlineBuilder for SegmentRecorder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish_contour();
+        self.start = self.point(x, y);
+        self.last = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let end = self.point(x, y);
+        self.current.push(Segment::Line(self.last, end));
+        self.last = end;
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let end = self.point(x, y);
+        self.current
+            .push(Segment::Quad(self.last, self.point(x1, y1), end));
+        self.last = end;
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let end = self.point(x, y);
+        self.current.push(Segment::Cubic(
+            self.last,
+            self.point(x1, y1),
+            self.point(x2, y2),
+            end,
+        ));
+        self.last = end;
+    }
+
+    fn close(&mut self) {
+        self.finish_contour();
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,100 @@
+//! Lightweight grid fitting ("hinting-lite") for small-size rendering
+//!
+//! This is **not** TrueType hinting: no bytecode is executed and stems are not
+//! regularized across glyphs. Each contour's extrema are simply snapped to the
+//! pixel grid of a target ppem, and the points in between are stretched
+//! proportionally so the contour keeps its shape.
+
+use crate::types::Outline2D;
+
+/// Snap the extrema of every contour to the pixel grid for the given ppem
+///
+/// Coordinates are expected in normalized em units (as produced by the
+/// outline pipeline). For each contour, the left/right/bottom/top extrema are
+/// rounded to the nearest multiple of `1.0 / ppem`, and all points are remapped
+/// linearly between the snapped extrema. A contour never collapses below one
+/// pixel along an axis it had extent on.
+///
+/// # Limitations
+/// - Contours are fitted independently, so two stems of the same glyph may end
+///   up with different pixel widths.
+/// - Interior features (crossbars, serifs) are only moved proportionally and
+///   are not snapped themselves.
+/// - Diagonal and curved edges are stretched, not re-fitted.
+///
+/// # Arguments
+/// * `outline` - A linearized outline in em units
+/// * `ppem` - Target pixels per em (must be positive and finite)
+pub fn grid_fit(outline: &Outline2D, ppem: f32) -> Outline2D {
+    let mut result = outline.clone();
+    let pixel = 1.0 / ppem;
+
+    for contour in &mut result.contours {
+        if contour.points.is_empty() {
+            continue;
+        }
+
+        let (min, max) = contour.points.iter().fold(
+            (contour.points[0].point, contour.points[0].point),
+            |(min, max), cp| (min.min(cp.point), max.max(cp.point)),
+        );
+
+        let (x0, x1) = snap_span(min.x, max.x, ppem, pixel);
+        let (y0, y1) = snap_span(min.y, max.y, ppem, pixel);
+
+        for cp in &mut contour.points {
+            cp.point.x = remap(cp.point.x, min.x, max.x, x0, x1);
+            cp.point.y = remap(cp.point.y, min.y, max.y, y0, y1);
+        }
+    }
+
+    result
+}
+
+/// Snap a `[min, max]` span to the pixel grid, keeping at least one pixel of extent
+#[inline]
+fn snap_span(min: f32, max: f32, ppem: f32, pixel: f32) -> (f32, f32) {
+    let lo = (min * ppem).round() * pixel;
+    let mut hi = (max * ppem).round() * pixel;
+    if max > min && hi <= lo {
+        hi = lo + pixel;
+    }
+    (lo, hi)
+}
+
+/// Linearly remap `v` from `[from_lo, from_hi]` to `[to_lo, to_hi]`
+#[inline(always)]
+fn remap(v: f32, from_lo: f32, from_hi: f32, to_lo: f32, to_hi: f32) -> f32 {
+    let span = from_hi - from_lo;
+    if span <= f32::EPSILON {
+        return to_lo;
+    }
+    to_lo + (v - from_lo) * ((to_hi - to_lo) / span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Contour;
+    use glam::Vec2;
+
+    #[test]
+    fn test_grid_fit_snaps_extrema() {
+        let mut outline = Outline2D::new();
+        let mut contour = Contour::new(true);
+        contour.push_on_curve(Vec2::new(0.013, 0.021));
+        contour.push_on_curve(Vec2::new(0.287, 0.021));
+        contour.push_on_curve(Vec2::new(0.287, 0.688));
+        contour.push_on_curve(Vec2::new(0.013, 0.688));
+        outline.add_contour(contour);
+
+        let ppem = 12.0;
+        let fitted = grid_fit(&outline, ppem);
+
+        for cp in &fitted.contours[0].points {
+            let px = cp.point * ppem;
+            assert!((px.x - px.x.round()).abs() < 1e-4);
+            assert!((px.y - px.y.round()).abs() < 1e-4);
+        }
+    }
+}
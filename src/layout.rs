@@ -0,0 +1,1190 @@
+//! Text-level helpers for meshing whole strings
+//!
+//! These functions work on a parsed `Face` and a `&str`, producing meshes one
+//! character at a time. They don't perform shaping - use a shaping library and
+//! the glyph-level API if you need ligatures or complex scripts.
+
+use crate::error::{FontMeshError, Result};
+use crate::types::{weld, Contour, Mesh2D, Mesh3D, Outline2D};
+use glam::{Vec2, Vec3};
+use rustc_hash::FxHashMap;
+use std::io::{self, Write};
+use ttf_parser::{Face, GlyphId};
+
+/// Lazily generate a 3D mesh for every character of a string
+///
+/// Each call to `next()` meshes exactly one character, so memory stays bounded
+/// for very long strings and meshing can be interleaved with e.g. GPU uploads.
+///
+/// Characters whose glyph has an empty outline (whitespace) yield an **empty
+/// mesh** rather than an error, so the caller can still advance the pen using
+/// [`crate::glyph_advance`]. Characters missing from the font yield
+/// `Err(FontMeshError::GlyphNotFound)` and iteration continues afterwards,
+/// except invisible formatting characters (zero-width joiners, variation
+/// selectors and the like) and combining marks, which yield an empty mesh
+/// too, so emoji ZWJ sequences and decorated text don't produce errors.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `text` - The string to mesh
+/// * `depth` - The extrusion depth
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, mesh_iter};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// for item in mesh_iter(&face, "Hi there", 5.0, 20) {
+///     let (c, mesh) = item?;
+///     println!("'{}': {} triangles", c, mesh.triangle_count());
+/// }
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn mesh_iter<'a>(
+    face: &'a Face<'a>,
+    text: &'a str,
+    depth: f32,
+    subdivisions: u8,
+) -> impl Iterator<Item = Result<(char, Mesh3D)>> + 'a {
+    mesh_iter_with(face, text, TextMeshOptions::new(depth, subdivisions))
+}
+
+/// Options for the batch text meshing APIs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextMeshOptions {
+    /// The extrusion depth
+    pub depth: f32,
+    /// Number of subdivisions per curve
+    pub subdivisions: u8,
+    /// Refuse to mesh glyphs with more contours than this
+    ///
+    /// Such glyphs yield `Err(FontMeshError::ComplexityExceeded(count))` without
+    /// being linearized or triangulated, which keeps batch runtime predictable
+    /// when a few pathological glyphs would otherwise dominate it.
+    pub skip_if_contours_over: Option<usize>,
+}
+
+impl TextMeshOptions {
+    /// Create options with the given depth and subdivisions and no other limits
+    pub fn new(depth: f32, subdivisions: u8) -> Self {
+        Self {
+            depth,
+            subdivisions,
+            skip_if_contours_over: None,
+        }
+    }
+}
+
+/// Lazily generate a 3D mesh for every character of a string, with options
+///
+/// Behaves like [`mesh_iter`], additionally applying the limits in `options`.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, FontMeshError};
+/// use fontmesh::layout::{mesh_iter_with, TextMeshOptions};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let options = TextMeshOptions {
+///     skip_if_contours_over: Some(2),
+///     ..TextMeshOptions::new(5.0, 20)
+/// };
+/// let meshes: Vec<_> = mesh_iter_with(&face, "B8", options)
+///     .filter(|item| !matches!(item, Err(FontMeshError::ComplexityExceeded(_))))
+///     .collect();
+/// assert!(meshes.is_empty());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn mesh_iter_with<'a>(
+    face: &'a Face<'a>,
+    text: &'a str,
+    options: TextMeshOptions,
+) -> impl Iterator<Item = Result<(char, Mesh3D)>> + 'a {
+    text.chars()
+        .map(move |c| match mesh_char(face, c, &options) {
+            Ok(mesh) => Ok((c, mesh)),
+            Err(FontMeshError::EmptyOutline) => Ok((c, Mesh3D::new())),
+            Err(e) => Err(e),
+        })
+}
+
+/// Horizontal alignment of a line of text relative to x = 0
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    /// The line starts at x = 0
+    #[default]
+    Left,
+    /// The line's advance box is centered on x = 0
+    Center,
+    /// The line ends at x = 0
+    Right,
+}
+
+/// Get the total advance of a string, including pairwise kerning (normalized to 1.0 em)
+///
+/// This is exactly the distance [`layout_text`] and [`export_text_obj`]
+/// advance the pen over the string, so it can be used to center or
+/// right-align text precisely. Kerning comes from the font's `kern` table;
+/// GPOS kerning is not applied. Characters missing from the font advance by
+/// nothing.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph_advance, layout::text_width};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// // 'A' and 'V' are kerned closer together
+/// let sum = glyph_advance(&face, 'A').unwrap() + glyph_advance(&face, 'V').unwrap();
+/// assert!(text_width(&face, "AV") < sum);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn text_width(face: &Face, text: &str) -> f32 {
+    pen_positions(face, text)
+        .last()
+        .map(|(c, x)| x + crate::font::glyph_advance(face, c).unwrap_or(0.0))
+        .unwrap_or(0.0)
+}
+
+/// Aggregate size of the meshes for a piece of text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeshStats {
+    /// Number of vertices across all meshes
+    pub total_vertices: usize,
+    /// Number of indices across all meshes
+    pub total_indices: usize,
+    /// Number of triangles across all meshes
+    pub total_triangles: usize,
+}
+
+/// Count the vertices and triangles needed to mesh a string
+///
+/// Each distinct character is extruded once and its mesh dropped straight
+/// away, so memory stays bounded however long the text is; repeated
+/// characters reuse the first count. The counts match the meshes from
+/// [`mesh_iter`] with the same subdivisions, at any depth. Characters that
+/// can't be meshed (missing from the font, or failing triangulation)
+/// contribute nothing, as does whitespace.
+///
+/// Useful for picking the subdivisions that keep a string within a
+/// triangle budget.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `text` - The string to measure
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, text_mesh_stats};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let coarse = text_mesh_stats(&face, "Hello", 5);
+/// let fine = text_mesh_stats(&face, "Hello", 40);
+/// assert!(coarse.total_triangles < fine.total_triangles);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn text_mesh_stats(face: &Face, text: &str, subdivisions: u8) -> MeshStats {
+    let options = TextMeshOptions::new(1.0, subdivisions);
+    let mut seen: FxHashMap<char, MeshStats> = FxHashMap::default();
+    let mut stats = MeshStats::default();
+
+    for c in text.chars() {
+        let glyph = *seen
+            .entry(c)
+            .or_insert_with(|| match mesh_char(face, c, &options) {
+                Ok(mesh) => MeshStats {
+                    total_vertices: mesh.vertices.len(),
+                    total_indices: mesh.indices.len(),
+                    total_triangles: mesh.triangle_count(),
+                },
+                Err(_) => MeshStats::default(),
+            });
+        stats.total_vertices += glyph.total_vertices;
+        stats.total_indices += glyph.total_indices;
+        stats.total_triangles += glyph.total_triangles;
+    }
+
+    stats
+}
+
+/// How [`layout_text_with`] places a line of text
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LineOptions {
+    /// Where the line sits relative to x = 0
+    pub align: Align,
+    /// Leave trailing whitespace out of the width used for alignment
+    ///
+    /// Centered `"hi   "` then lands exactly where centered `"hi"` does,
+    /// the way text editors center visible content. Has no effect with
+    /// [`Align::Left`].
+    pub trim_trailing_whitespace: bool,
+    /// Move each glyph this much further along +z than the one before
+    ///
+    /// Glyphs that overlap, as in cursive fonts or tight kerning, have
+    /// coplanar caps that z-fight when the line is drawn as one mesh. A tiny
+    /// step (around `1e-4` for em-sized text) puts later glyphs just in
+    /// front without a visible gap. This deliberately breaks the
+    /// coplanarity of the caps, which is usually imperceptible but matters
+    /// if the mesh must be exactly flat, e.g. for 3D printing. The default
+    /// of 0 keeps every glyph at the same depth.
+    pub glyph_z_step: f32,
+}
+
+/// Mesh a line of text into a single 3D mesh, aligned horizontally
+///
+/// Glyphs are placed on the baseline at the pen positions used by
+/// [`text_width`], keeping their side bearings, and the whole line is
+/// shifted according to `align`. Whitespace only advances the pen.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `text` - The string to mesh
+/// * `depth` - The extrusion depth
+/// * `subdivisions` - Number of subdivisions per curve
+/// * `align` - Where the line sits relative to x = 0
+///
+/// # Errors
+/// Returns the first meshing error, e.g. `FontMeshError::GlyphNotFound` for
+/// a character missing from the font.
+///
+/// Example
+/// ```
+/// use fontmesh::Face;
+/// use fontmesh::layout::{layout_text, Align};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mesh = layout_text(&face, "Hello", 0.1, 20, Align::Center)?;
+/// let (min, max) = mesh.aabb();
+/// assert!(min.x < 0.0 && max.x > 0.0);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn layout_text(
+    face: &Face,
+    text: &str,
+    depth: f32,
+    subdivisions: u8,
+    align: Align,
+) -> Result<Mesh3D> {
+    let line = LineOptions {
+        align,
+        ..LineOptions::default()
+    };
+    layout_text_with(
+        face,
+        text,
+        &TextMeshOptions::new(depth, subdivisions),
+        &line,
+    )
+}
+
+/// Mesh a line of text into a single 3D mesh, with options
+///
+/// Behaves like [`layout_text`], additionally applying the meshing limits in
+/// `options` and the placement settings in `line`.
+///
+/// Example
+/// ```
+/// use fontmesh::Face;
+/// use fontmesh::layout::{layout_text_with, Align, LineOptions, TextMeshOptions};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let line = LineOptions {
+///     align: Align::Center,
+///     trim_trailing_whitespace: true,
+///     ..LineOptions::default()
+/// };
+/// let mesh = layout_text_with(&face, "Hello  ", &TextMeshOptions::new(0.1, 20), &line)?;
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn layout_text_with(
+    face: &Face,
+    text: &str,
+    options: &TextMeshOptions,
+    line: &LineOptions,
+) -> Result<Mesh3D> {
+    layout_line(face, text, options, line, None)
+}
+
+/// Mesh several differently colored runs as one line, with per-vertex colors
+///
+/// The runs are laid out back to back exactly as their concatenated text
+/// would be by [`layout_text_with`], including kerning across run
+/// boundaries. Every vertex of a glyph gets its run's color in
+/// [`Mesh3D::colors`], so the whole line can be drawn in one call with a
+/// vertex-color attribute. Whitespace adds no vertices and so no colors.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `runs` - Text and linear RGBA color of each run, in order
+/// * `options` - Depth, subdivisions and limits for every glyph
+/// * `line` - Alignment of the whole line
+///
+/// Example
+/// ```
+/// use fontmesh::Face;
+/// use fontmesh::layout::{layout_text_colored, LineOptions, TextMeshOptions};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let runs = [("let ", [0.8, 0.2, 0.6, 1.0]), ("x", [1.0, 1.0, 1.0, 1.0])];
+/// let options = TextMeshOptions::new(0.1, 20);
+/// let mesh = layout_text_colored(&face, &runs, &options, &LineOptions::default())?;
+/// assert_eq!(mesh.colors.len(), mesh.vertices.len());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn layout_text_colored(
+    face: &Face,
+    runs: &[(&str, [f32; 4])],
+    options: &TextMeshOptions,
+    line: &LineOptions,
+) -> Result<Mesh3D> {
+    let text: String = runs.iter().map(|(run, _)| *run).collect();
+    let colors: Vec<[f32; 4]> = runs
+        .iter()
+        .flat_map(|&(run, color)| run.chars().map(move |_| color))
+        .collect();
+    layout_line(face, &text, options, line, Some(&colors))
+}
+
+/// Shared implementation of the line layout functions
+///
+/// `colors`, if given, holds one color per character of `text`.
+fn layout_line(
+    face: &Face,
+    text: &str,
+    options: &TextMeshOptions,
+    line: &LineOptions,
+    colors: Option<&[[f32; 4]]>,
+) -> Result<Mesh3D> {
+    let width = || {
+        if line.trim_trailing_whitespace {
+            text_width(face, text.trim_end())
+        } else {
+            text_width(face, text)
+        }
+    };
+    let start = match line.align {
+        Align::Left => 0.0,
+        Align::Center => -width() / 2.0,
+        Align::Right => -width(),
+    };
+    let mut result = Mesh3D::new();
+    let mut placed = 0;
+
+    for (index, (c, x)) in pen_positions(face, text).enumerate() {
+        let mesh = match mesh_char(face, c, options) {
+            Ok(mesh) => mesh,
+            Err(FontMeshError::EmptyOutline) => continue,
+            Err(e) => return Err(e),
+        };
+
+        let offset = Vec3::new(start + x, 0.0, placed as f32 * line.glyph_z_step);
+        placed += 1;
+        let base = result.vertices.len() as u32;
+        result
+            .vertices
+            .extend(mesh.vertices.iter().map(|&v| v + offset));
+        result.normals.extend_from_slice(&mesh.normals);
+        result
+            .indices
+            .extend(mesh.indices.iter().map(|&i| base + i));
+        if let Some(colors) = colors {
+            let count = mesh.vertices.len();
+            result
+                .colors
+                .extend(std::iter::repeat_n(colors[index], count));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Margin of the [`print_solid`] base slab around the text, in em
+const PRINT_BASE_MARGIN: f32 = 0.05;
+
+/// Mesh a word as one watertight solid standing on a base, for 3D printing
+///
+/// The text is laid out as by [`layout_text`], and glyphs that overlap or
+/// touch are fused into their union (non-zero winding), so the letters
+/// don't contain internal faces. The union is extruded by `depth` on top of
+/// a rectangular slab `base_depth` thick that extends
+/// 0.05 em beyond the text on every side, connecting every letter into a
+/// single piece. The slab's top surface has the letters' footprint cut out
+/// and joined to their walls, so the result is one closed, manifold,
+/// outward-facing surface that passes [`Mesh3D::is_solid`]. Where outlines
+/// touch at a single point, as script letters often do, the two sides are
+/// pulled apart by a tenth of a font unit to keep the surface manifold.
+///
+/// The slab spans z = 0 to `base_depth` and the letters rise to
+/// `base_depth + depth`. Whitespace advances the pen as usual.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `text` - The word or line to print
+/// * `depth` - Height of the letters above the base
+/// * `base_depth` - Thickness of the base slab
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// # Errors
+/// Returns [`FontMeshError::ExtrusionFailed`] if a depth isn't positive and
+/// finite, [`FontMeshError::GlyphNotFound`] for characters missing from the
+/// font, and [`FontMeshError::EmptyOutline`] if nothing in `text` is
+/// visible.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, print_solid};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mesh = print_solid(&face, "Hi", 0.2, 0.05, 20)?;
+/// assert!(mesh.is_solid());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn print_solid(
+    face: &Face,
+    text: &str,
+    depth: f32,
+    base_depth: f32,
+    subdivisions: u8,
+) -> Result<Mesh3D> {
+    if subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(subdivisions));
+    }
+    for value in [depth, base_depth] {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(FontMeshError::ExtrusionFailed(
+                "depth and base depth must be positive finite values".to_string(),
+            ));
+        }
+    }
+
+    // 1. Every visible glyph's outline, in font units
+    let letters = text_outline_units(face, text, subdivisions)?;
+    let units_per_em = face.units_per_em() as f32;
+
+    // 2. The union of the letters and its boundary loops
+    let Footprint { cap, walls, loops } = union_footprint(&letters)?;
+
+    // 3. The slab's top: a clockwise rectangle with the letters cut out
+    let (min, max) = cap.aabb();
+    let min = min - Vec2::splat(PRINT_BASE_MARGIN * units_per_em);
+    let max = max + Vec2::splat(PRINT_BASE_MARGIN * units_per_em);
+    let rect = clockwise_rect(min, max);
+    let mut slab = Outline2D::new();
+    slab.add_contour(rect.clone());
+    slab.contours.extend(loops);
+    let slab_top = without_t_junctions(crate::triangulate::triangulate_nonzero(&slab)?);
+
+    // 4. Assemble
+    let top = base_depth + depth;
+    let mut mesh = Mesh3D::new();
+    push_cap(&mut mesh, &cap, top, Vec3::Z);
+    push_cap(&mut mesh, &slab_top, base_depth, Vec3::Z);
+    let slab_bottom = Mesh2D {
+        vertices: rect.points.iter().map(|cp| cp.point).collect(),
+        indices: vec![0, 1, 2, 0, 2, 3],
+        boundary_mask: Vec::new(),
+        contour_ids: Vec::new(),
+    };
+    push_cap(&mut mesh, &slab_bottom, 0.0, Vec3::NEG_Z);
+    for &(a, b) in &walls {
+        push_wall(&mut mesh, a, b, base_depth, top);
+    }
+    // The rectangle is clockwise, so its inside is on the right
+    let corners = &slab_bottom.vertices;
+    for i in 0..corners.len() {
+        push_wall(&mut mesh, corners[(i + 1) % 4], corners[i], 0.0, base_depth);
+    }
+    for vertex in &mut mesh.vertices {
+        vertex.x /= units_per_em;
+        vertex.y /= units_per_em;
+    }
+
+    match mesh.solid_defect() {
+        None => Ok(mesh),
+        Some(defect) => Err(FontMeshError::ExtrusionFailed(format!(
+            "text did not form a solid: {:?}",
+            defect
+        ))),
+    }
+}
+
+/// How the text sits on the face of a [`plaque`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlaqueText {
+    /// Letters stand this far (in em) out of the face
+    Raised(f32),
+    /// Letters are cut this far (in em) into the face
+    Engraved(f32),
+}
+
+/// Options for [`plaque`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlaqueOptions {
+    /// Width and height of the plaque in em, or `None` to fit the text
+    ///
+    /// A fitted plaque leaves `margin` between the text and the bevel on
+    /// every side.
+    pub size: Option<Vec2>,
+    /// Space between the text's ink and the bevel, in em
+    ///
+    /// With an explicit `size`, only the aligned side keeps this margin
+    /// ([`Align::Left`] or [`Align::Right`]); centered text ignores it.
+    pub margin: f32,
+    /// Thickness of the plaque at its face, in em
+    pub thickness: f32,
+    /// Width and depth of the 45° chamfer around the face, in em; 0 for a
+    /// square edge
+    pub bevel: f32,
+    /// Whether the text is raised or engraved, and by how much
+    pub text: PlaqueText,
+    /// Horizontal placement of the text on the face; it is always centered
+    /// vertically
+    pub align: Align,
+    /// Number of subdivisions per curve
+    pub subdivisions: u8,
+}
+
+impl Default for PlaqueOptions {
+    fn default() -> Self {
+        Self {
+            size: None,
+            margin: 0.15,
+            thickness: 0.2,
+            bevel: 0.05,
+            text: PlaqueText::Raised(0.1),
+            align: Align::Center,
+            subdivisions: crate::glyph::DEFAULT_QUALITY,
+        }
+    }
+}
+
+/// Mesh a line of text on a rectangular plaque with a beveled edge
+///
+/// The plaque is centered on the origin with its back at z = 0 and its
+/// face at z = `thickness`; the bevel slopes from the face down to the
+/// plaque's sides. The text is laid out as by [`print_solid`], with
+/// overlapping glyphs fused, and either stands out of the face or is cut
+/// into it with vertical walls. Either way the result is a single closed,
+/// outward-facing solid that passes [`Mesh3D::is_solid`], ready to print.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `text` - The line of text to put on the plaque
+/// * `options` - Plaque dimensions and text placement
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidQuality`] for 0 subdivisions,
+/// [`FontMeshError::InvalidParameter`] if a dimension is negative or not
+/// finite, the bevel or an engraving is as deep as the plaque, or the text
+/// doesn't fit inside the bevel, and the errors of [`print_solid`] for the
+/// text.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, layout::{plaque, PlaqueOptions, PlaqueText}};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let options = PlaqueOptions {
+///     text: PlaqueText::Engraved(0.05),
+///     ..PlaqueOptions::default()
+/// };
+/// let mesh = plaque(&face, "Room 101", &options)?;
+/// assert!(mesh.is_solid());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn plaque(face: &Face, text: &str, options: &PlaqueOptions) -> Result<Mesh3D> {
+    if options.subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(options.subdivisions));
+    }
+    let text_depth = match options.text {
+        PlaqueText::Raised(depth) | PlaqueText::Engraved(depth) => depth,
+    };
+    let size = options.size.unwrap_or(Vec2::ONE);
+    let valid = |value: f32| value.is_finite() && value >= 0.0;
+    if !(valid(options.margin) && valid(options.bevel) && size.is_finite())
+        || options.thickness <= 0.0
+        || text_depth <= 0.0
+        || !(options.thickness.is_finite() && text_depth.is_finite())
+    {
+        return Err(FontMeshError::InvalidParameter(
+            "plaque dimensions must be finite, thickness and text depth positive".to_string(),
+        ));
+    }
+    if options.bevel >= options.thickness {
+        return Err(FontMeshError::InvalidParameter(
+            "bevel must be thinner than the plaque".to_string(),
+        ));
+    }
+    if matches!(options.text, PlaqueText::Engraved(depth) if depth >= options.thickness) {
+        return Err(FontMeshError::InvalidParameter(
+            "engraving must be shallower than the plaque".to_string(),
+        ));
+    }
+
+    // Work in font units, like print_solid
+    let units_per_em = face.units_per_em() as f32;
+    let mut letters = text_outline_units(face, text, options.subdivisions)?;
+    let [[x_min, y_min], [x_max, y_max]] = letters.bounds().ok_or(FontMeshError::EmptyOutline)?;
+    let (ink_min, ink_max) = (Vec2::new(x_min, y_min), Vec2::new(x_max, y_max));
+    let ink = ink_max - ink_min;
+    let margin = options.margin * units_per_em;
+    let bevel = options.bevel * units_per_em;
+
+    // 1. The face (inside the bevel) and the text placed on it
+    let half_face = match options.size {
+        Some(size) => size * units_per_em * 0.5 - Vec2::splat(bevel),
+        None => ink * 0.5 + Vec2::splat(margin),
+    };
+    if half_face.min_element() <= 0.0 {
+        return Err(FontMeshError::InvalidParameter(
+            "plaque is too small for its bevel".to_string(),
+        ));
+    }
+    let ink_x = match options.align {
+        Align::Left => -half_face.x + margin,
+        Align::Center => -ink.x * 0.5,
+        Align::Right => half_face.x - margin - ink.x,
+    };
+    let offset = Vec2::new(ink_x, -ink.y * 0.5) - ink_min;
+    let placed = (ink_min + offset, ink_max + offset);
+    if placed.0.cmplt(-half_face).any() || placed.1.cmpgt(half_face).any() {
+        return Err(FontMeshError::InvalidParameter(
+            "text does not fit on the plaque".to_string(),
+        ));
+    }
+    letters.translate(offset);
+    let Footprint { cap, walls, loops } = union_footprint(&letters)?;
+
+    let face_rect = clockwise_rect(-half_face, half_face);
+    let mut face_outline = Outline2D::new();
+    face_outline.add_contour(face_rect.clone());
+    face_outline.contours.extend(loops);
+    let face_top = without_t_junctions(crate::triangulate::triangulate_nonzero(&face_outline)?);
+
+    // 2. Assemble
+    let thickness = options.thickness;
+    let rim = thickness - options.bevel;
+    let mut mesh = Mesh3D::new();
+    push_cap(&mut mesh, &face_top, thickness, Vec3::Z);
+    match options.text {
+        PlaqueText::Raised(height) => {
+            push_cap(&mut mesh, &cap, thickness + height, Vec3::Z);
+            for &(a, b) in &walls {
+                push_wall(&mut mesh, a, b, thickness, thickness + height);
+            }
+        }
+        PlaqueText::Engraved(depth) => {
+            // The pocket's walls face into the letters
+            push_cap(&mut mesh, &cap, thickness - depth, Vec3::Z);
+            for &(a, b) in &walls {
+                push_wall(&mut mesh, b, a, thickness - depth, thickness);
+            }
+        }
+    }
+
+    let inner: Vec<Vec2> = face_rect.points.iter().map(|cp| cp.point).collect();
+    let half_size = half_face + Vec2::splat(bevel);
+    let outer: Vec<Vec2> = clockwise_rect(-half_size, half_size)
+        .points
+        .iter()
+        .map(|cp| cp.point)
+        .collect();
+    // The rectangles are clockwise, so the outside of each side is on its left
+    for i in 0..4 {
+        let j = (i + 1) % 4;
+        if bevel > 0.0 {
+            let d = inner[j] - inner[i];
+            let normal = (Vec3::new(-d.y, d.x, 0.0).normalize() + Vec3::Z).normalize();
+            let base = mesh.vertices.len() as u32;
+            mesh.vertices.extend_from_slice(&[
+                outer[i].extend(rim),
+                outer[j].extend(rim),
+                inner[j].extend(thickness),
+                inner[i].extend(thickness),
+            ]);
+            mesh.normals.extend_from_slice(&[normal; 4]);
+            mesh.indices
+                .extend_from_slice(&[base, base + 2, base + 1, base, base + 3, base + 2]);
+        }
+        push_wall(&mut mesh, outer[j], outer[i], 0.0, rim);
+    }
+    let back = Mesh2D {
+        vertices: outer,
+        indices: vec![0, 1, 2, 0, 2, 3],
+        boundary_mask: Vec::new(),
+        contour_ids: Vec::new(),
+    };
+    push_cap(&mut mesh, &back, 0.0, Vec3::NEG_Z);
+
+    for vertex in &mut mesh.vertices {
+        vertex.x /= units_per_em;
+        vertex.y /= units_per_em;
+    }
+
+    match mesh.solid_defect() {
+        None => Ok(mesh),
+        Some(defect) => Err(FontMeshError::ExtrusionFailed(format!(
+            "plaque did not form a solid: {:?}",
+            defect
+        ))),
+    }
+}
+
+/// A clockwise rectangle contour, as TrueType winds outer contours
+fn clockwise_rect(min: Vec2, max: Vec2) -> Contour {
+    let mut rect = Contour::new(true);
+    for corner in [min, Vec2::new(min.x, max.y), max, Vec2::new(max.x, min.y)] {
+        rect.push_on_curve(corner);
+    }
+    rect
+}
+
+/// Every visible glyph's linearized outline at its pen position, in font units
+///
+/// lyon's tolerances are absolute and misplace edges at em scale, so
+/// footprints are built in font units.
+///
+/// # Errors
+/// [`FontMeshError::GlyphNotFound`] for characters missing from the font and
+/// [`FontMeshError::EmptyOutline`] if nothing in `text` is visible.
+fn text_outline_units(face: &Face, text: &str, subdivisions: u8) -> Result<Outline2D> {
+    let mut letters = Outline2D::new();
+    for (c, x) in pen_positions(face, text) {
+        let glyph_id = match face.glyph_index(c) {
+            Some(glyph_id) => glyph_id,
+            None if is_zero_width(c) => continue,
+            None => return Err(FontMeshError::GlyphNotFound(c)),
+        };
+        let outline = match crate::glyph::extract_outline(face, glyph_id) {
+            Ok(outline) => outline,
+            Err(FontMeshError::EmptyOutline) => continue,
+            Err(e) => return Err(e),
+        };
+        let mut outline = crate::glyph::linearize_for_face(face, outline, subdivisions)?;
+        outline.translate(Vec2::new(x, 0.0));
+        letters.contours.extend(outline.contours);
+    }
+    if letters.is_empty() {
+        return Err(FontMeshError::EmptyOutline);
+    }
+    letters.scale(face.units_per_em() as f32);
+    Ok(letters)
+}
+
+/// The filled union of some letters, ready to be extruded
+pub(crate) struct Footprint {
+    /// Triangulation of the union (non-zero winding)
+    pub(crate) cap: Mesh2D,
+    /// Boundary edges, directed with the filled side on the left
+    pub(crate) walls: Vec<(Vec2, Vec2)>,
+    /// The boundary edges chained into closed loops
+    pub(crate) loops: Vec<Contour>,
+}
+
+/// Fuse overlapping letters into their [`Footprint`]
+///
+/// Expects font units: pinches are split a tenth of a unit apart.
+pub(crate) fn union_footprint(letters: &Outline2D) -> Result<Footprint> {
+    let cap = crate::triangulate::triangulate_nonzero(letters)?;
+    let mut cap = without_t_junctions(cap);
+    split_pinches(&mut cap);
+    let walls = boundary_edges(&cap);
+    let loops = chain_loops(&walls);
+    Ok(Footprint { cap, walls, loops })
+}
+
+/// Drop zero-area triangles, then split any triangle edge that passes
+/// through another vertex
+///
+/// lyon leaves slivers between collinear points (e.g. along the baseline)
+/// and fans that span them; the fans' edges then pass through vertices.
+/// Splitting them pairs every edge with exactly one neighbor.
+fn without_t_junctions(mesh: Mesh2D) -> Mesh2D {
+    let indices: Vec<u32> = mesh
+        .indices
+        .chunks_exact(3)
+        .filter(|tri| {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+            (b - a).perp_dot(c - a) != 0.0
+        })
+        .flatten()
+        .copied()
+        .collect();
+    let mut lifted = Mesh3D {
+        normals: vec![Vec3::Z; mesh.vertices.len()],
+        vertices: mesh.vertices.iter().map(|v| v.extend(0.0)).collect(),
+        indices,
+        uvs: Vec::new(),
+        colors: Vec::new(),
+        tangents: Vec::new(),
+    };
+    lifted.fix_t_junctions(1e-4);
+    Mesh2D {
+        vertices: mesh.vertices,
+        indices: lifted.indices,
+        boundary_mask: mesh.boundary_mask,
+        contour_ids: mesh.contour_ids,
+    }
+}
+
+/// Give each fan of triangles around a pinch vertex its own, slightly moved vertex
+///
+/// Where the filled area touches itself at a single point (a counter
+/// meeting the outside, or two letters touching corner to corner) the
+/// surface isn't manifold. Each fan of triangles around such a point gets a
+/// copy of the vertex nudged a tenth of a font unit into the fan,
+/// separating the sheets.
+fn split_pinches(mesh: &mut Mesh2D) {
+    const NUDGE: f32 = 0.1;
+
+    // Weld coincident vertices so fans are found by position
+    let (welded, mut vertices) = weld(&mesh.vertices, Vec2::to_array);
+    let mut indices: Vec<u32> = mesh.indices.iter().map(|&i| welded[i as usize]).collect();
+
+    let mut incident: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+    for (t, tri) in indices.chunks_exact(3).enumerate() {
+        for &v in tri {
+            incident[v as usize].push(t);
+        }
+    }
+
+    for (v, triangles) in incident.iter().enumerate() {
+        // Group the triangles around `v` into fans joined by shared edges
+        let mut fan_of: Vec<usize> = (0..triangles.len()).collect();
+        let find = |fan_of: &mut Vec<usize>, mut i: usize| {
+            while fan_of[i] != i {
+                fan_of[i] = fan_of[fan_of[i]];
+                i = fan_of[i];
+            }
+            i
+        };
+        let mut by_neighbor: FxHashMap<u32, usize> = FxHashMap::default();
+        for (k, &t) in triangles.iter().enumerate() {
+            for &w in &indices[t * 3..t * 3 + 3] {
+                if w as usize == v {
+                    continue;
+                }
+                if let Some(&other) = by_neighbor.get(&w) {
+                    let (a, b) = (find(&mut fan_of, k), find(&mut fan_of, other));
+                    fan_of[a] = b;
+                } else {
+                    by_neighbor.insert(w, k);
+                }
+            }
+        }
+        let mut fans: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
+        for (k, &t) in triangles.iter().enumerate() {
+            fans.entry(find(&mut fan_of, k)).or_default().push(t);
+        }
+        if fans.len() < 2 {
+            continue;
+        }
+
+        let center = vertices[v];
+        let mut fans: Vec<Vec<usize>> = fans.into_values().collect();
+        fans.sort_unstable();
+        for fan in fans {
+            let direction: Vec2 = fan
+                .iter()
+                .map(|&t| {
+                    let sum: Vec2 = indices[t * 3..t * 3 + 3]
+                        .iter()
+                        .map(|&i| vertices[i as usize])
+                        .sum();
+                    (sum / 3.0 - center).normalize_or_zero()
+                })
+                .sum();
+            let moved = vertices.len() as u32;
+            vertices.push(center + direction.normalize_or_zero() * NUDGE);
+            for &t in &fan {
+                for index in &mut indices[t * 3..t * 3 + 3] {
+                    if *index as usize == v {
+                        *index = moved;
+                    }
+                }
+            }
+        }
+    }
+
+    mesh.vertices = vertices;
+    mesh.indices = indices;
+    mesh.boundary_mask.clear();
+    mesh.contour_ids.clear();
+}
+
+/// Edges used by a single triangle, directed with the filled side on the left
+fn boundary_edges(mesh: &Mesh2D) -> Vec<(Vec2, Vec2)> {
+    let (welded, positions) = weld(&mesh.vertices, Vec2::to_array);
+
+    // Use count and (first) direction of every undirected edge
+    let mut edges: FxHashMap<(u32, u32), (u32, (u32, u32))> = FxHashMap::default();
+    for tri in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| welded[i as usize]);
+        let area = (positions[b as usize] - positions[a as usize])
+            .perp_dot(positions[c as usize] - positions[a as usize]);
+        if area == 0.0 {
+            continue;
+        }
+        let [a, b, c] = if area > 0.0 { [a, b, c] } else { [a, c, b] };
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            edges
+                .entry((from.min(to), from.max(to)))
+                .or_insert((0, (from, to)))
+                .0 += 1;
+        }
+    }
+
+    let mut boundary: Vec<_> = edges
+        .into_values()
+        .filter(|&(count, _)| count == 1)
+        .map(|(_, (from, to))| (positions[from as usize], positions[to as usize]))
+        .collect();
+    // Hash map order isn't stable; keep the output deterministic
+    boundary.sort_by(|a, b| {
+        [a.0.x, a.0.y, a.1.x, a.1.y]
+            .partial_cmp(&[b.0.x, b.0.y, b.1.x, b.1.y])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    boundary
+}
+
+/// Join directed edges end to start into closed contours
+fn chain_loops(edges: &[(Vec2, Vec2)]) -> Vec<Contour> {
+    let key = |p: Vec2| p.to_array().map(f32::to_bits);
+    let mut outgoing: FxHashMap<[u32; 2], Vec<usize>> = FxHashMap::default();
+    for (i, &(from, _)) in edges.iter().enumerate() {
+        outgoing.entry(key(from)).or_default().push(i);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut loops = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut contour = Contour::new(true);
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let (from, to) = edges[current];
+            contour.push_on_curve(from);
+            let next = outgoing
+                .get(&key(to))
+                .and_then(|candidates| candidates.iter().copied().find(|&i| !used[i]));
+            match next {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        loops.push(contour);
+    }
+    loops
+}
+
+/// Append a flat 2D mesh at height `z` with the given normal
+///
+/// Each triangle is wound counter-clockwise seen along the normal, whichever
+/// way it winds in `cap`.
+fn push_cap(mesh: &mut Mesh3D, cap: &Mesh2D, z: f32, normal: Vec3) {
+    let base = mesh.vertices.len() as u32;
+    mesh.vertices
+        .extend(cap.vertices.iter().map(|v| v.extend(z)));
+    mesh.normals
+        .extend(std::iter::repeat_n(normal, cap.vertices.len()));
+    mesh.indices
+        .extend(cap.indices.chunks_exact(3).flat_map(|tri| {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| cap.vertices[i as usize]);
+            let area = (b - a).perp_dot(c - a);
+            let tri = if area * normal.z < 0.0 {
+                [tri[0], tri[2], tri[1]]
+            } else {
+                [tri[0], tri[1], tri[2]]
+            };
+            tri.map(|i| base + i)
+        }));
+}
+
+/// Append a vertical quad along `a -> b`, facing right of that direction
+fn push_wall(mesh: &mut Mesh3D, a: Vec2, b: Vec2, z_low: f32, z_high: f32) {
+    let d = b - a;
+    let normal = Vec3::new(d.y, -d.x, 0.0).normalize_or_zero();
+    let base = mesh.vertices.len() as u32;
+    mesh.vertices.extend_from_slice(&[
+        a.extend(z_low),
+        b.extend(z_low),
+        b.extend(z_high),
+        a.extend(z_high),
+    ]);
+    mesh.normals.extend_from_slice(&[normal; 4]);
+    mesh.indices
+        .extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+}
+
+/// Pen x position of every character, advancing by glyph advances and kerning
+fn pen_positions<'a>(face: &'a Face<'a>, text: &'a str) -> impl Iterator<Item = (char, f32)> + 'a {
+    let mut pen_x = 0.0;
+    let mut previous: Option<(char, Option<GlyphId>)> = None;
+
+    text.chars().map(move |c| {
+        let glyph_id = face.glyph_index(c);
+        if let Some((prev_c, prev_id)) = previous {
+            pen_x += crate::font::glyph_advance(face, prev_c).unwrap_or(0.0);
+            if let (Some(left), Some(right)) = (prev_id, glyph_id) {
+                pen_x += kerning(face, left, right);
+            }
+        }
+        previous = Some((c, glyph_id));
+        (c, pen_x)
+    })
+}
+
+/// Horizontal `kern` table adjustment between two glyphs (normalized to 1.0 em)
+fn kerning(face: &Face, left: GlyphId, right: GlyphId) -> f32 {
+    let Some(kern) = face.tables().kern else {
+        return 0.0;
+    };
+    kern.subtables
+        .into_iter()
+        .filter(|subtable| subtable.horizontal && !subtable.variable)
+        .find_map(|subtable| subtable.glyphs_kerning(left, right))
+        .map_or(0.0, |units| units as f32 / face.units_per_em() as f32)
+}
+
+/// Write a whole string as a Wavefront OBJ file with one object per glyph
+///
+/// Glyphs are placed left to right on the baseline at the same pen
+/// positions as [`layout_text`] with [`Align::Left`]. Every glyph with an outline becomes an
+/// `o glyph_<index>_<char>` object (non-alphanumeric characters are written
+/// as their code point, e.g. `glyph_3_U0021`); whitespace only advances the
+/// pen. Vertex indices are offset across objects, so the file loads as one
+/// scene.
+///
+/// # Arguments
+/// * `face` - A parsed ttf-parser Face
+/// * `text` - The string to export
+/// * `w` - Where to write the OBJ data
+/// * `subdivisions` - Number of subdivisions per curve
+/// * `depth` - The extrusion depth
+///
+/// # Errors
+/// Returns the writer's I/O errors, and meshing errors (e.g. a character
+/// missing from the font) as [`io::ErrorKind::InvalidData`].
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, layout::export_text_obj};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mut obj = Vec::new();
+/// export_text_obj(&face, "Hello", &mut obj, 20, 0.1)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn export_text_obj<W: Write>(
+    face: &Face,
+    text: &str,
+    w: &mut W,
+    subdivisions: u8,
+    depth: f32,
+) -> io::Result<()> {
+    let mut vertex_offset = 1; // OBJ indices are 1-based
+
+    writeln!(w, "# {} glyphs exported by fontmesh", text.chars().count())?;
+
+    let meshes = mesh_iter(face, text, depth, subdivisions);
+    for (index, (item, (_, pen_x))) in meshes.zip(pen_positions(face, text)).enumerate() {
+        let (c, mesh) = item.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if !mesh.is_empty() {
+            if c.is_ascii_alphanumeric() {
+                writeln!(w, "o glyph_{}_{}", index, c)?;
+            } else {
+                writeln!(w, "o glyph_{}_U{:04X}", index, c as u32)?;
+            }
+            for v in &mesh.vertices {
+                writeln!(w, "v {} {} {}", v.x + pen_x, v.y, v.z)?;
+            }
+            for n in &mesh.normals {
+                writeln!(w, "vn {} {} {}", n.x, n.y, n.z)?;
+            }
+            for tri in mesh.indices.chunks_exact(3) {
+                let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i + vertex_offset);
+                writeln!(w, "f {a}//{a} {b}//{b} {c}//{c}")?;
+            }
+            vertex_offset += mesh.vertices.len() as u32;
+        }
+    }
+
+    Ok(())
+}
+
+/// Mesh a single character, enforcing the batch limits
+fn mesh_char(face: &Face, c: char, options: &TextMeshOptions) -> Result<Mesh3D> {
+    if options.subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(options.subdivisions));
+    }
+    if !options.depth.is_finite() {
+        return Err(FontMeshError::ExtrusionFailed(
+            "depth must be a finite value".to_string(),
+        ));
+    }
+
+    let glyph_id = match face.glyph_index(c) {
+        Some(glyph_id) => glyph_id,
+        // Nothing to draw, and no advance to apply
+        None if is_zero_width(c) => return Err(FontMeshError::EmptyOutline),
+        None => return Err(FontMeshError::GlyphNotFound(c)),
+    };
+    let outline = crate::glyph::extract_outline(face, glyph_id)?;
+
+    if let Some(limit) = options.skip_if_contours_over {
+        if outline.contours.len() > limit {
+            return Err(FontMeshError::ComplexityExceeded(outline.contours.len()));
+        }
+    }
+
+    let outline = crate::glyph::linearize_for_face(face, outline, options.subdivisions)?;
+    let mesh_2d = crate::triangulate::triangulate(&outline)?;
+    crate::extrude::extrude(&mesh_2d, &outline, options.depth)
+}
+
+/// Whether a character is normally rendered without a glyph of its own
+///
+/// Covers the zero-width formatting characters that appear in emoji
+/// sequences and running text, plus the main blocks of combining marks.
+pub(crate) fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200F}' // zero-width space, (non-)joiner, direction marks
+            | '\u{2060}'..='\u{2064}' // word joiner, invisible operators
+            | '\u{FEFF}' // zero-width no-break space
+            | '\u{FE00}'..='\u{FE0F}' // variation selectors
+            | '\u{E0100}'..='\u{E01EF}' // variation selectors supplement
+            | '\u{E0020}'..='\u{E007F}' // emoji tag sequences
+            | '\u{0300}'..='\u{036F}' // combining diacritical marks
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
@@ -54,6 +54,19 @@
 //! let mesh = fontmesh::char_to_mesh_3d(&face, 'A', 5.0, 20)?;
 //! ```
 //!
+//! ## Reusing Your Own Face
+//!
+//! [`Face`] is `ttf_parser::Face` re-exported, and every function only borrows it. If your
+//! application already parsed the font (e.g. for its text engine), pass that face as is: nothing
+//! is re-parsed or copied. It must come from the same `ttf-parser` version as fontmesh uses
+//! (0.24); otherwise the types won't match.
+//!
+//! ```ignore
+//! fn mesh_label(app_face: &ttf_parser::Face, text: &str) -> fontmesh::Result<fontmesh::Mesh3D> {
+//!     fontmesh::layout::layout_text(app_face, text, 0.1, 20, fontmesh::layout::Align::Left)
+//! }
+//! ```
+//!
 //! ## Font Metrics
 //!
 //! Helper functions for common font metrics (normalized to 1.0 em):
@@ -93,6 +106,20 @@
 //! // Most users should just use char_to_mesh_2d/3d
 //! ```
 //!
+//! ## Variable Fonts
+//!
+//! Variable fonts (both `glyf`+`gvar` and CFF2) are meshed at whatever
+//! instance the `Face` is set to. Select an instance with
+//! `Face::set_variation` before generating meshes:
+//!
+//! ```ignore
+//! use fontmesh::{Face, Tag, char_to_mesh_3d};
+//!
+//! let mut face = Face::parse(font_data, 0)?;
+//! face.set_variation(Tag::from_bytes(b"wght"), 700.0);
+//! let bold = char_to_mesh_3d(&face, 'A', 5.0, 20)?;
+//! ```
+//!
 //! ## Integration with Text Shaping
 //!
 //! Works seamlessly with text shaping libraries like `rustybuzz` or `cosmic-text`:
@@ -108,31 +135,67 @@
 //! // ... then generate meshes per character as needed
 //! ```
 
+pub mod atlas;
+#[cfg(feature = "binary")]
+pub mod binary;
+#[cfg(feature = "debug-checks")]
+mod checks;
 pub mod error;
+#[cfg(feature = "export")]
+pub mod export;
 pub mod extrude;
 pub mod font;
 pub mod glyph;
+pub mod hinting;
+pub mod layout;
 pub mod linearize;
+#[cfg(all(feature = "mmap", unix))]
+pub mod mmap;
 pub mod triangulate;
 pub mod types;
 
 // Re-export main types
 pub use error::{FontMeshError, Result};
-pub use types::{Mesh2D, Mesh3D, Outline2D};
+pub use types::{Axis, ContourDepth, Mesh2D, Mesh3D, Outline2D, PathBuilder, Quality, SolidDefect};
 
 // Re-export ttf-parser types for direct usage
-pub use ttf_parser::{Face, GlyphId};
+pub use ttf_parser::{Face, GlyphId, Tag, Transform};
 
 // Re-export core pure functions (stateless API)
-pub use glyph::{char_to_mesh_2d, char_to_mesh_3d, Glyph};
+pub use glyph::{
+    char_to_mesh_2d, char_to_mesh_3d, glyph_to_mesh_2d_reuse, glyph_to_mesh_3d_reuse,
+    notdef_mesh_2d, notdef_mesh_3d, Glyph,
+};
+
+// Re-export atlas packing
+pub use atlas::{atlas_mesh_2d, Rect};
+
+// Re-export text-level helpers
+pub use layout::{mesh_iter, print_solid, text_mesh_stats, text_width};
 
 // Re-export font utilities
-pub use font::{ascender, descender, glyph_advance, line_gap, parse_font};
+pub use font::{
+    ascender, cap_height, descender, family_name, glyph_advance, is_meshable, italic_angle,
+    line_gap, palette, parse_font, subfamily_name, unsupported_chars, x_height,
+};
 
 // Re-export pipeline functions for advanced usage
-pub use extrude::{compute_smooth_normals, extrude};
-pub use linearize::linearize_outline;
-pub use triangulate::triangulate;
+pub use extrude::{
+    compute_smooth_normals, compute_tangents, extrude, extrude_beveled, extrude_into, extrude_loft,
+    extrude_with, orient_triangles, sweep_tube, BevelOptions, BevelStyle, CapNormals, ExtrudeDepth,
+    ExtrudeMode, ExtrudeOptions, UvMode,
+};
+pub use linearize::{
+    linearize_contour, linearize_contour_with, linearize_outline, linearize_outline_matched,
+    linearize_outline_tolerance, linearize_outline_with, LinearizeMode, LinearizeOptions,
+};
+pub use triangulate::{
+    triangulate, triangulate_into, triangulate_with_holes, triangulate_with_points,
+    TriangulateScratch,
+};
+
+// Re-export lyon's stroke styles for `Outline2D::stroke_mesh_2d`
+pub use lyon_tessellation::{LineCap, LineJoin};
 
 #[cfg(test)]
 mod tests {
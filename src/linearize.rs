@@ -3,12 +3,159 @@
 //! This implementation uses adaptive subdivision based on curve angle,
 //! matching the approach used by ttf2mesh for optimal performance.
 
-use crate::error::Result;
-use crate::types::{Contour, Outline2D, Point2D};
+use crate::error::{FontMeshError, Result};
+use crate::types::{Contour, ContourPoint, Outline2D, Point2D};
+use glam::Vec2;
 use std::f32::consts::PI;
 
 const EPSILON: f32 = 1e-5;
 const AREA_THRESHOLD: f32 = 1e-5;
+/// Upper bound on the points generated for a single curve segment
+///
+/// Guards against runaway vertex generation from extreme coordinates or
+/// malformed fonts, whatever the subdivision settings.
+const MAX_POINTS_PER_CURVE: usize = 256;
+/// Cosine of the smallest turn [`LinearizeOptions::preserve_corners`] keeps (45 degrees)
+const CORNER_COS: f32 = std::f32::consts::FRAC_1_SQRT_2;
+/// Area, in font units², of the wobble rounding to whole units leaves in a
+/// straight edge: half a unit off the line over a few units of edge
+const QUANTIZATION_AREA: f32 = 4.0;
+
+/// How curves are turned into line segments
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LinearizeMode {
+    /// Subdivide each curve according to how far it bends
+    #[default]
+    Adaptive,
+    /// Replace each curve with its chord, a single line between its ends
+    ///
+    /// The cheapest tier, for thumbnails and tiny text where curves are
+    /// sub-pixel anyway: vertices land only on the outline's on-curve
+    /// points (including the midpoints implied between consecutive
+    /// off-curve points). `subdivisions` and `min_points_per_curve` are
+    /// ignored, and 0 subdivisions is accepted.
+    Chord,
+    /// Subdivide each curve until no segment strays from it by more than
+    /// [`LinearizeOptions::max_deviation`]
+    ///
+    /// Unlike the angle-based [`LinearizeMode::Adaptive`], this bounds the
+    /// visible error directly, so the same tolerance suits a glyph whatever
+    /// its size: large curves get more points, small ones fewer. The
+    /// tolerance is in outline units (em for glyph outlines); divide the
+    /// error you can accept on screen by the rendered em size. Curves are
+    /// split evenly in their parameter, using the bound on the gap between
+    /// a curve and its chords given by the curve's second derivative.
+    /// `subdivisions` is ignored, and 0 subdivisions is accepted.
+    Tolerance,
+}
+
+/// Options controlling curve linearization
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearizeOptions {
+    /// Number of subdivisions per full turn of curve tangent
+    ///
+    /// A curve whose tangent turns by an angle θ gets
+    /// `round(θ / 360° × subdivisions)` intermediate points. The turn is
+    /// measured between the end tangents and reads at most 90°, so a
+    /// single curve never gets more than `subdivisions / 4` points: 64 at
+    /// the maximum of 255, and nearly straight curves get none. For
+    /// smoother curves than that (e.g. decorative fonts rendered very
+    /// large), raise [`min_points_per_curve`](Self::min_points_per_curve),
+    /// which allows up to 255 points on every curve.
+    pub subdivisions: u8,
+    /// Minimum number of intermediate points for every curve
+    ///
+    /// Adaptive subdivision skips nearly straight curves entirely and gives
+    /// gently bending ones very few points, which can look faceted at large
+    /// sizes. Every curve gets at least this many points regardless. The
+    /// default of 0 keeps the purely adaptive behavior. Points that end up
+    /// collinear within tolerance are still removed afterwards.
+    pub min_points_per_curve: u8,
+    /// Area below which a point and its neighbors count as collinear
+    ///
+    /// After flattening, every point whose triangle with the previous kept
+    /// point and the next point has an area at or below this value is
+    /// removed. The default of `1e-5` suits em-normalized outlines; lower it
+    /// for tiny glyphs that would otherwise be over-simplified, or set it to
+    /// 0 to keep every point.
+    pub collinear_epsilon: f32,
+    /// Never remove a point where the contour turns by more than 45 degrees
+    ///
+    /// Sharp corners of very small features can fall under
+    /// `collinear_epsilon` and get cut off; with this set they are kept no
+    /// matter how small their triangle is. Off by default.
+    pub preserve_corners: bool,
+    /// Drop solid contours enclosing less than this area
+    ///
+    /// A level-of-detail setting for distant or low-resolution text: small
+    /// dots and serif fragments that would be sub-pixel are removed along
+    /// with everything nested inside them. Areas are in outline units
+    /// (em² for glyph outlines). The default of 0 keeps every contour.
+    pub min_contour_area: f32,
+    /// Also drop holes enclosing less than `min_contour_area`
+    ///
+    /// Off by default, since filling in a hole changes the glyph's shape
+    /// rather than just removing detail.
+    pub drop_small_holes: bool,
+    /// How curves are flattened; see [`LinearizeMode`]
+    pub mode: LinearizeMode,
+    /// Largest distance between a curve and its line segments, in outline
+    /// units, for [`LinearizeMode::Tolerance`]
+    ///
+    /// Must be positive and finite in that mode; ignored otherwise.
+    pub max_deviation: f32,
+}
+
+impl LinearizeOptions {
+    /// Create options with the given subdivisions and no other overrides
+    pub fn new(subdivisions: u8) -> Self {
+        Self {
+            subdivisions,
+            min_points_per_curve: 0,
+            collinear_epsilon: EPSILON,
+            preserve_corners: false,
+            min_contour_area: 0.0,
+            drop_small_holes: false,
+            mode: LinearizeMode::Adaptive,
+            max_deviation: 0.0,
+        }
+    }
+
+    /// Create options that flatten curves to within `max_deviation`
+    ///
+    /// See [`LinearizeMode::Tolerance`].
+    pub fn tolerance(max_deviation: f32) -> Self {
+        Self {
+            mode: LinearizeMode::Tolerance,
+            max_deviation,
+            ..Self::new(0)
+        }
+    }
+
+    /// Create options calibrated for a font's `units_per_em`
+    ///
+    /// Outlines are normalized to the em, but their points were rounded to
+    /// whole font units first, so fonts with few units per em carry more
+    /// rounding noise relative to their size. `collinear_epsilon` is raised
+    /// to absorb that noise, keeping point density comparable between e.g. a
+    /// 256 and a 2048 unit font with the same shapes. For 1000 units per em
+    /// and up this is the same as [`LinearizeOptions::new`].
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::LinearizeOptions;
+    ///
+    /// assert_eq!(LinearizeOptions::for_units_per_em(20, 2048), LinearizeOptions::new(20));
+    /// assert!(LinearizeOptions::for_units_per_em(20, 256).collinear_epsilon > 1e-5);
+    /// ```
+    pub fn for_units_per_em(subdivisions: u8, units_per_em: u16) -> Self {
+        let unit = 1.0 / units_per_em.max(1) as f32;
+        Self {
+            collinear_epsilon: EPSILON.max(QUANTIZATION_AREA * unit * unit),
+            ..Self::new(subdivisions)
+        }
+    }
+}
 
 /// Linearize an outline by converting curves to line segments
 ///
@@ -17,18 +164,268 @@ const AREA_THRESHOLD: f32 = 1e-5;
 /// * `subdivisions` - Number of subdivisions per curve
 #[inline]
 pub fn linearize_outline(outline: Outline2D, subdivisions: u8) -> Result<Outline2D> {
+    linearize_outline_with(outline, &LinearizeOptions::new(subdivisions))
+}
+
+/// Linearize an outline so no segment strays from its curve by more than
+/// `max_deviation`
+///
+/// The resolution-independent alternative to [`linearize_outline`]; see
+/// [`LinearizeMode::Tolerance`].
+///
+/// # Arguments
+/// * `outline` - The outline to linearize
+/// * `max_deviation` - Largest distance between a curve and its segments,
+///   in outline units (em for glyph outlines)
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidParameter`] if `max_deviation` is not
+/// positive and finite.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph, linearize_outline_tolerance};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let outline = Glyph::new(&face, 'O')?.outline()?;
+///
+/// let fine = linearize_outline_tolerance(outline.clone(), 0.0005)?;
+/// let coarse = linearize_outline_tolerance(outline, 0.005)?;
+/// assert!(fine.contours[0].points.len() > coarse.contours[0].points.len());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+#[inline]
+pub fn linearize_outline_tolerance(outline: Outline2D, max_deviation: f32) -> Result<Outline2D> {
+    linearize_outline_with(outline, &LinearizeOptions::tolerance(max_deviation))
+}
+
+/// Linearize an outline with explicit options
+///
+/// # Arguments
+/// * `outline` - The outline to linearize
+/// * `options` - Subdivision settings
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph};
+/// use fontmesh::linearize::{linearize_outline_with, LinearizeOptions};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let outline = Glyph::new(&face, 'O')?.outline()?;
+///
+/// let options = LinearizeOptions {
+///     min_points_per_curve: 4,
+///     ..LinearizeOptions::new(20)
+/// };
+/// let smooth = linearize_outline_with(outline, &options)?;
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn linearize_outline_with(outline: Outline2D, options: &LinearizeOptions) -> Result<Outline2D> {
+    if options.mode == LinearizeMode::Tolerance
+        && !(options.max_deviation.is_finite() && options.max_deviation > 0.0)
+    {
+        return Err(FontMeshError::InvalidParameter(
+            "max_deviation must be a positive finite value".to_string(),
+        ));
+    }
     let mut result = Outline2D::new();
 
     outline
         .contours
         .into_iter()
-        .map(|contour| linearize_contour(&contour, subdivisions))
-        .filter(|linearized| !linearized.is_empty())
+        .map(|contour| linearize_contour_with(&contour, options))
+        // Fewer than 3 points enclose nothing (DejaVu's 'u' has a 2-point contour)
+        .filter(|linearized| linearized.points.len() >= 3)
         .for_each(|linearized| result.add_contour(linearized));
 
+    if options.min_contour_area > 0.0 {
+        drop_small_contours(&mut result, options);
+    }
+
+    #[cfg(feature = "debug-checks")]
+    crate::checks::linearized(&result);
+
     Ok(result)
 }
 
+/// Remove contours below `options.min_contour_area`, and whatever they enclose
+fn drop_small_contours(outline: &mut Outline2D, options: &LinearizeOptions) {
+    let depths = outline.nesting_depths();
+    let small: Vec<usize> = outline
+        .contours
+        .iter()
+        .zip(&depths)
+        .enumerate()
+        .filter(|&(_, (contour, &depth))| {
+            (depth % 2 == 0 || options.drop_small_holes)
+                && contour.signed_area().abs() < options.min_contour_area
+        })
+        .map(|(i, _)| i)
+        .collect();
+    if small.is_empty() {
+        return;
+    }
+
+    // Contours nested in a dropped one would flip between filled and empty
+    let keep: Vec<bool> = outline
+        .contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            !small.contains(&i)
+                && !contour.points.first().is_some_and(|cp| {
+                    small
+                        .iter()
+                        .any(|&j| outline.contours[j].contains(cp.point))
+                })
+        })
+        .collect();
+    let mut keep = keep.into_iter();
+    outline.contours.retain(|_| keep.next().unwrap_or(true));
+}
+
+/// Linearize two outlines so corresponding contours have equal point counts
+///
+/// Each pair of contours (matched by index) is linearized with the same
+/// subdivisions, then the contour with fewer points gets extra points
+/// inserted along its edges, in proportion to edge length, until the counts
+/// match. Inserted points lie on the existing edges, so neither shape
+/// changes. The results can be passed straight to
+/// [`crate::extrude::extrude_loft`] or interpolated point by point to morph
+/// one glyph into another.
+///
+/// Point `i` of one contour is paired with point `i` of the other as-is; no
+/// attempt is made to align the contours' start points or winding.
+///
+/// # Arguments
+/// * `a` - The first outline
+/// * `b` - The second outline
+/// * `subdivisions` - Number of subdivisions per curve, shared by both
+///
+/// # Errors
+/// Returns [`FontMeshError::LinearizationFailed`] if the outlines have a
+/// different number of contours, or if a contour collapses entirely in one
+/// outline but not in the other.
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph};
+/// use fontmesh::linearize::linearize_outline_matched;
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let o = Glyph::new(&face, 'O')?.outline()?;
+/// let d = Glyph::new(&face, 'D')?.outline()?;
+///
+/// let (o, d) = linearize_outline_matched(o, d, 20)?;
+/// for (a, b) in o.contours.iter().zip(&d.contours) {
+///     assert_eq!(a.points.len(), b.points.len());
+/// }
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn linearize_outline_matched(
+    a: Outline2D,
+    b: Outline2D,
+    subdivisions: u8,
+) -> Result<(Outline2D, Outline2D)> {
+    if a.contours.len() != b.contours.len() {
+        return Err(FontMeshError::LinearizationFailed(format!(
+            "outlines have {} and {} contours",
+            a.contours.len(),
+            b.contours.len()
+        )));
+    }
+
+    let options = LinearizeOptions::new(subdivisions);
+    let mut result_a = Outline2D::new();
+    let mut result_b = Outline2D::new();
+
+    for (i, (contour_a, contour_b)) in a.contours.iter().zip(&b.contours).enumerate() {
+        let mut linear_a = linearize_contour_with(contour_a, &options);
+        let mut linear_b = linearize_contour_with(contour_b, &options);
+
+        match (linear_a.is_empty(), linear_b.is_empty()) {
+            (true, true) => continue,
+            (false, false) => {}
+            _ => {
+                return Err(FontMeshError::LinearizationFailed(format!(
+                    "contour {} is degenerate in only one outline",
+                    i
+                )))
+            }
+        }
+
+        let count = linear_a.points.len().max(linear_b.points.len());
+        densify_contour(&mut linear_a, count);
+        densify_contour(&mut linear_b, count);
+        result_a.add_contour(linear_a);
+        result_b.add_contour(linear_b);
+    }
+
+    Ok((result_a, result_b))
+}
+
+/// Insert points along a linear contour's edges until it has `target` points
+///
+/// Extra points are shared out by edge length (largest remainder first) and
+/// spaced evenly along each edge.
+fn densify_contour(contour: &mut Contour, target: usize) {
+    let n = contour.points.len();
+    let edge_count = if contour.closed {
+        n
+    } else {
+        n.saturating_sub(1)
+    };
+    if target <= n || edge_count == 0 {
+        return;
+    }
+    let extra = target - n;
+
+    let lengths: Vec<f32> = (0..edge_count)
+        .map(|i| {
+            contour.points[i]
+                .point
+                .distance(contour.points[(i + 1) % n].point)
+        })
+        .collect();
+    let total: f32 = lengths.iter().sum();
+
+    let mut counts = vec![0usize; edge_count];
+    if total > 0.0 {
+        let shares: Vec<f32> = lengths.iter().map(|l| extra as f32 * l / total).collect();
+        for (count, share) in counts.iter_mut().zip(&shares) {
+            *count = share.floor() as usize;
+        }
+        let mut by_remainder: Vec<usize> = (0..edge_count).collect();
+        by_remainder.sort_by(|&x, &y| {
+            (shares[y] - shares[y].floor()).total_cmp(&(shares[x] - shares[x].floor()))
+        });
+        let assigned: usize = counts.iter().sum();
+        for &edge in by_remainder.iter().cycle().take(extra - assigned) {
+            counts[edge] += 1;
+        }
+    } else {
+        counts[0] = extra;
+    }
+
+    let mut points = Vec::with_capacity(target);
+    for (i, &count) in counts.iter().enumerate() {
+        points.push(contour.points[i]);
+        let p0 = contour.points[i].point;
+        let p1 = contour.points[(i + 1) % n].point;
+        let steps = count + 1;
+        for k in 1..steps {
+            let t = k as f32 / steps as f32;
+            points.push(ContourPoint::on_curve(p0.lerp(p1, t)));
+        }
+    }
+    // An open contour's last point ends no edge
+    points.extend_from_slice(&contour.points[edge_count..]);
+    contour.points = points;
+}
+
 /// State machine for processing TrueType contour points
 #[derive(Debug, Clone, Copy)]
 enum LinearizeState {
@@ -41,13 +438,93 @@ enum LinearizeState {
         last_point: Point2D,
         control_point: Point2D,
     },
+    /// Have on-curve + first cubic control point, expecting the second
+    CubicControl {
+        last_point: Point2D,
+        control_point: Point2D,
+    },
+    /// Have on-curve + both cubic control points, expecting end point
+    CubicControls {
+        last_point: Point2D,
+        control1: Point2D,
+        control2: Point2D,
+    },
 }
 
 /// Linearize a single contour using adaptive subdivision
+///
+/// Produces the same points as the contour gets from [`linearize_outline`],
+/// for pipelines that handle contours one at a time, e.g. streamed from a
+/// custom path source. Unlike the outline functions, a contour that
+/// collapses entirely is returned empty rather than dropped.
+///
+/// # Arguments
+/// * `contour` - The contour to linearize
+/// * `subdivisions` - Number of subdivisions per curve
+///
+/// # Errors
+/// Returns [`FontMeshError::InvalidQuality`] if `subdivisions` is 0.
+///
+/// Example
+/// ```
+/// use fontmesh::linearize_contour;
+/// use fontmesh::types::Contour;
+/// use glam::Vec2;
+///
+/// let mut arch = Contour::new(true);
+/// arch.push_on_curve(Vec2::new(0.0, 0.0));
+/// arch.push_off_curve(Vec2::new(0.5, 1.0));
+/// arch.push_on_curve(Vec2::new(1.0, 0.0));
+///
+/// let linear = linearize_contour(&arch, 20)?;
+/// assert!(linear.points.len() > 3);
+/// assert!(linear.points.iter().all(|p| p.on_curve));
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
 #[inline]
-fn linearize_contour(contour: &Contour, subdivisions: u8) -> Contour {
+pub fn linearize_contour(contour: &Contour, subdivisions: u8) -> Result<Contour> {
+    if subdivisions == 0 {
+        return Err(FontMeshError::InvalidQuality(subdivisions));
+    }
+    Ok(linearize_contour_with(
+        contour,
+        &LinearizeOptions::new(subdivisions),
+    ))
+}
+
+/// Linearize a single contour with explicit options
+///
+/// If culling near-collinear points would drop a contour that still encloses
+/// some area (e.g. a hairline rectangle thinner than the cull threshold), the
+/// unculled points are kept instead so the geometry isn't lost.
+///
+/// # Arguments
+/// * `contour` - The contour to linearize
+/// * `options` - Subdivision settings
+#[inline]
+pub fn linearize_contour_with(contour: &Contour, options: &LinearizeOptions) -> Contour {
+    let mut result = flatten_contour(contour, options);
+
+    // Remove collinear points to reduce vertex count
+    remove_collinear_points(&mut result, options);
+
+    if result.is_empty() && contour.points.len() >= 3 {
+        let raw = flatten_contour(contour, options);
+        if raw.points.len() >= 3 && raw.signed_area() != 0.0 {
+            return raw;
+        }
+    }
+
+    result
+}
+
+/// Convert a contour's curves to line segments, without any culling
+#[inline]
+fn flatten_contour(contour: &Contour, options: &LinearizeOptions) -> Contour {
     let n = contour.points.len();
-    if n < 2 {
+    // Nothing to flatten: a single point, or straight edges only (e.g. 'I',
+    // 'L', box drawing), which the state machine would copy point by point
+    if n < 2 || contour.points.iter().all(|cp| cp.on_curve) {
         // Return a new contour with just the points (avoid cloning entire structure)
         let mut result = Contour::new(contour.closed);
         result.points = contour.points.clone();
@@ -55,7 +532,7 @@ fn linearize_contour(contour: &Contour, subdivisions: u8) -> Contour {
     }
 
     // Pre-allocate with estimate: most points stay + some subdivisions
-    let estimated_size = n + (n / 3) * subdivisions as usize;
+    let estimated_size = n + (n / 3) * options.subdivisions as usize;
     let mut result = Contour::new(contour.closed);
     result.points.reserve(estimated_size);
 
@@ -83,6 +560,12 @@ fn linearize_contour(contour: &Contour, subdivisions: u8) -> Contour {
                     LinearizeState::OnCurve {
                         last_point: cp.point,
                     }
+                } else if cp.cubic {
+                    // First control point of a cubic curve
+                    LinearizeState::CubicControl {
+                        last_point,
+                        control_point: cp.point,
+                    }
                 } else {
                     // Off-curve point - store as control point
                     LinearizeState::OffCurve {
@@ -98,13 +581,7 @@ fn linearize_contour(contour: &Contour, subdivisions: u8) -> Contour {
                 // Have on-curve + off-curve, expecting end point
                 if cp.on_curve {
                     // Standard curve: on-off-on
-                    linearize_qbezier(
-                        last_point,
-                        control_point,
-                        cp.point,
-                        subdivisions,
-                        &mut result,
-                    );
+                    linearize_qbezier(last_point, control_point, cp.point, options, &mut result);
                     result.push_on_curve(cp.point);
                     LinearizeState::OnCurve {
                         last_point: cp.point,
@@ -113,7 +590,7 @@ fn linearize_contour(contour: &Contour, subdivisions: u8) -> Contour {
                     // Two consecutive off-curve points: on-off-off
                     // Insert implicit midpoint
                     let mid = (control_point + cp.point) * 0.5;
-                    linearize_qbezier(last_point, control_point, mid, subdivisions, &mut result);
+                    linearize_qbezier(last_point, control_point, mid, options, &mut result);
                     result.push_on_curve(mid);
                     LinearizeState::OffCurve {
                         last_point: mid,
@@ -121,36 +598,103 @@ fn linearize_contour(contour: &Contour, subdivisions: u8) -> Contour {
                     }
                 }
             }
+            LinearizeState::CubicControl {
+                last_point,
+                control_point,
+            } => {
+                if cp.on_curve {
+                    // A lone cubic control point: the best reading is a quadratic
+                    linearize_qbezier(last_point, control_point, cp.point, options, &mut result);
+                    result.push_on_curve(cp.point);
+                    LinearizeState::OnCurve {
+                        last_point: cp.point,
+                    }
+                } else {
+                    LinearizeState::CubicControls {
+                        last_point,
+                        control1: control_point,
+                        control2: cp.point,
+                    }
+                }
+            }
+            LinearizeState::CubicControls {
+                last_point,
+                control1,
+                control2,
+            } => {
+                if cp.on_curve {
+                    // Standard cubic: on-off-off-on
+                    linearize_cbezier(
+                        last_point,
+                        control1,
+                        control2,
+                        cp.point,
+                        options,
+                        &mut result,
+                    );
+                    result.push_on_curve(cp.point);
+                    LinearizeState::OnCurve {
+                        last_point: cp.point,
+                    }
+                } else {
+                    // More than two control points in a row: end the curve at
+                    // the second one and start over from it
+                    linearize_qbezier(last_point, control1, control2, options, &mut result);
+                    result.push_on_curve(control2);
+                    if cp.cubic {
+                        LinearizeState::CubicControl {
+                            last_point: control2,
+                            control_point: cp.point,
+                        }
+                    } else {
+                        LinearizeState::OffCurve {
+                            last_point: control2,
+                            control_point: cp.point,
+                        }
+                    }
+                }
+            }
         };
     }
 
-    // Handle closing curve if we ended with off-curve point
-    if let LinearizeState::OffCurve {
-        last_point,
-        control_point,
-    } = state
-    {
-        if contour.closed {
-            linearize_qbezier(
+    // Handle closing curve if we ended with off-curve points
+    if contour.closed {
+        match state {
+            LinearizeState::OffCurve {
+                last_point,
+                control_point,
+            }
+            | LinearizeState::CubicControl {
                 last_point,
                 control_point,
-                first_point,
-                subdivisions,
-                &mut result,
-            );
+            } => {
+                linearize_qbezier(last_point, control_point, first_point, options, &mut result);
+            }
+            LinearizeState::CubicControls {
+                last_point,
+                control1,
+                control2,
+            } => {
+                linearize_cbezier(
+                    last_point,
+                    control1,
+                    control2,
+                    first_point,
+                    options,
+                    &mut result,
+                );
+            }
+            LinearizeState::Initial | LinearizeState::OnCurve { .. } => {}
         }
     }
 
-    // Remove collinear points to reduce vertex count
-    remove_collinear_points(&mut result);
-
     result
 }
 
 /// Remove near-collinear points from a contour (matches ttf_fix_linear_bags)
 /// Optimized: uses in-place two-pointer algorithm to avoid allocations
 #[inline]
-fn remove_collinear_points(contour: &mut Contour) {
+fn remove_collinear_points(contour: &mut Contour, options: &LinearizeOptions) {
     let n = contour.points.len();
     if n < 3 {
         return;
@@ -167,7 +711,13 @@ fn remove_collinear_points(contour: &mut Contour) {
         let p2 = contour.points[read_idx + 1].point;
 
         // Keep point if it forms a non-degenerate triangle
-        if triangle_area(p0, p1, p2) > EPSILON {
+        let area = triangle_area(p0, p1, p2);
+        let keep = area > options.collinear_epsilon
+            || (options.preserve_corners && is_corner(p0, p1, p2))
+            // Dropping it would move the outline by more than the tolerance
+            || (options.mode == LinearizeMode::Tolerance
+                && 2.0 * area > options.max_deviation * p0.distance(p2));
+        if keep {
             if write_idx != read_idx {
                 contour.points[write_idx] = contour.points[read_idx];
             }
@@ -202,47 +752,39 @@ fn remove_collinear_points(contour: &mut Contour) {
     }
 }
 
+/// Whether the path `p0 -> p1 -> p2` turns by more than 45 degrees at `p1`
+#[inline]
+fn is_corner(p0: Point2D, p1: Point2D, p2: Point2D) -> bool {
+    let incoming = (p1 - p0).normalize_or_zero();
+    let outgoing = (p2 - p1).normalize_or_zero();
+    incoming != Point2D::ZERO && outgoing != Point2D::ZERO && incoming.dot(outgoing) < CORNER_COS
+}
+
 /// Linearize a quadratic Bezier curve using adaptive subdivision
 ///
 /// This matches the ttf2mesh approach: calculate the angle between tangents
 /// at t=0 and t=1, then determine the number of subdivisions based on that angle.
+/// The count is raised to `options.min_points_per_curve` if lower and capped
+/// at [`MAX_POINTS_PER_CURVE`].
 #[inline(always)]
 fn linearize_qbezier(
     p0: Point2D,
     p1: Point2D,
     p2: Point2D,
-    subdivisions: u8,
+    options: &LinearizeOptions,
     result: &mut Contour,
 ) {
-    // Check if the curve is nearly linear using triangle area (Heron's formula)
-    let area = triangle_area(p0, p1, p2);
-    if area < AREA_THRESHOLD {
-        return; // Skip near-linear curves
-    }
-
-    // Calculate tangent vectors at t=0 and t=1 (inlined for performance)
-    // At t=0: 2(P1-P0)
-    let t0 = (p1 - p0) * 2.0;
-    // At t=1: 2(P2-P1)
-    let t1 = (p2 - p1) * 2.0;
-
-    let t0_len = t0.length();
-    let t1_len = t1.length();
-
-    if t0_len < EPSILON || t1_len < EPSILON {
-        return;
+    let num_points = match options.mode {
+        LinearizeMode::Chord => return,
+        LinearizeMode::Adaptive => adaptive_point_count(p0, p1, p2, options.subdivisions),
+        // The chord error of n even steps is at most |B''| / (8 n²), with
+        // B'' = 2 (p0 - 2 p1 + p2)
+        LinearizeMode::Tolerance => {
+            tolerance_point_count((p0 - 2.0 * p1 + p2).length() / 4.0, options.max_deviation)
+        }
     }
-
-    // Calculate angle between tangents using cross product
-    let cross = t0.x * t1.y - t0.y * t1.x;
-    let inv_len_product = 1.0 / (t0_len * t1_len);
-    let mut angle = (cross.abs() * inv_len_product).min(1.0);
-
-    // Convert to angle
-    angle = angle.asin();
-
-    // Calculate number of subdivisions based on angle
-    let num_points = (angle / (PI * 2.0) * subdivisions as f32).round() as usize;
+    .max(options.min_points_per_curve as usize)
+    .min(MAX_POINTS_PER_CURVE);
 
     if num_points == 0 {
         return;
@@ -279,6 +821,158 @@ fn linearize_qbezier(
     });
 }
 
+/// Add intermediate points along a cubic Bézier curve
+///
+/// The curve is split in half with De Casteljau's algorithm and each half
+/// gets the point count adaptive subdivision would give a quadratic turning
+/// by the same angle, so an S-bend is not mistaken for a straight line and
+/// a cubic arc gets about as many points as the quadratics approximating
+/// it would. The end points are not added. Like [`linearize_qbezier`], the
+/// count is raised to `options.min_points_per_curve` and capped at
+/// [`MAX_POINTS_PER_CURVE`].
+fn linearize_cbezier(
+    p0: Point2D,
+    p1: Point2D,
+    p2: Point2D,
+    p3: Point2D,
+    options: &LinearizeOptions,
+    result: &mut Contour,
+) {
+    let num_points = match options.mode {
+        LinearizeMode::Chord => return,
+        // As for quadratics, with |B''| at most 6 times the larger second
+        // difference of the control points
+        LinearizeMode::Tolerance => {
+            let second_difference = (p0 - 2.0 * p1 + p2)
+                .length()
+                .max((p1 - 2.0 * p2 + p3).length());
+            tolerance_point_count(0.75 * second_difference, options.max_deviation)
+        }
+        // Control points (nearly) on the chord
+        LinearizeMode::Adaptive
+            if triangle_area(p0, p1, p3) + triangle_area(p0, p2, p3) < AREA_THRESHOLD =>
+        {
+            0
+        }
+        LinearizeMode::Adaptive => {
+            let [first, second] = split_cbezier(p0, p1, p2, p3);
+            let counts = [first, second].map(|[a, b, c, d]| {
+                let (t0, t1) = cubic_end_tangents(a, b, c, d);
+                turn_point_count(t0, t1, options.subdivisions)
+            });
+            match counts {
+                [0, 0] => 0,
+                // The split point itself, plus each half's own points
+                [a, b] => a + b + 1,
+            }
+        }
+    }
+    .max(options.min_points_per_curve as usize)
+    .min(MAX_POINTS_PER_CURVE);
+
+    let step = 1.0 / (num_points + 1) as f32;
+    (1..=num_points).for_each(|i| {
+        result.push_on_curve(cbezier(p0, p1, p2, p3, i as f32 * step));
+    });
+}
+
+/// Split a cubic Bézier curve at t = 0.5 (De Casteljau)
+#[inline]
+fn split_cbezier(p0: Point2D, p1: Point2D, p2: Point2D, p3: Point2D) -> [[Point2D; 4]; 2] {
+    let p01 = p0.lerp(p1, 0.5);
+    let p12 = p1.lerp(p2, 0.5);
+    let p23 = p2.lerp(p3, 0.5);
+    let p012 = p01.lerp(p12, 0.5);
+    let p123 = p12.lerp(p23, 0.5);
+    let mid = p012.lerp(p123, 0.5);
+    [[p0, p01, p012, mid], [mid, p123, p23, p3]]
+}
+
+/// Tangent directions of a cubic Bézier at its start and end
+///
+/// A control point on its end point gives a zero derivative there, so the
+/// direction comes from the next control point along.
+#[inline]
+fn cubic_end_tangents(p0: Point2D, p1: Point2D, p2: Point2D, p3: Point2D) -> (Vec2, Vec2) {
+    let start = [p1, p2, p3]
+        .into_iter()
+        .map(|p| p - p0)
+        .find(|d| d.length_squared() >= EPSILON * EPSILON)
+        .unwrap_or(Vec2::ZERO);
+    let end = [p2, p1, p0]
+        .into_iter()
+        .map(|p| p3 - p)
+        .find(|d| d.length_squared() >= EPSILON * EPSILON)
+        .unwrap_or(Vec2::ZERO);
+    (start, end)
+}
+
+/// Evaluate a cubic Bézier curve at parameter t
+#[inline(always)]
+fn cbezier(p0: Point2D, p1: Point2D, p2: Point2D, p3: Point2D, t: f32) -> Point2D {
+    let u = 1.0 - t;
+    p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+}
+
+/// Number of intermediate points adaptive subdivision gives a quadratic curve
+#[inline(always)]
+fn adaptive_point_count(p0: Point2D, p1: Point2D, p2: Point2D, subdivisions: u8) -> usize {
+    // Check if the curve is nearly linear using triangle area (Heron's formula)
+    let area = triangle_area(p0, p1, p2);
+    if area < AREA_THRESHOLD {
+        return 0; // Skip near-linear curves
+    }
+
+    // Calculate tangent vectors at t=0 and t=1 (inlined for performance)
+    // At t=0: 2(P1-P0)
+    let t0 = (p1 - p0) * 2.0;
+    // At t=1: 2(P2-P1)
+    let t1 = (p2 - p1) * 2.0;
+
+    turn_point_count(t0, t1, subdivisions)
+}
+
+/// Number of intermediate points keeping a curve's chords within `max_deviation`
+///
+/// `error` is the chord error of a single step (the whole curve); `n` even
+/// steps cut it by `n²`.
+#[inline(always)]
+fn tolerance_point_count(error: f32, max_deviation: f32) -> usize {
+    let steps = (error / max_deviation).sqrt().ceil();
+    if steps.is_finite() {
+        (steps as usize).saturating_sub(1).min(MAX_POINTS_PER_CURVE)
+    } else {
+        MAX_POINTS_PER_CURVE
+    }
+}
+
+/// Number of intermediate points for a curve whose tangent turns from `t0` to `t1`
+#[inline(always)]
+fn turn_point_count(t0: Vec2, t1: Vec2, subdivisions: u8) -> usize {
+    let t0_len = t0.length();
+    let t1_len = t1.length();
+
+    if t0_len < EPSILON || t1_len < EPSILON {
+        return 0;
+    }
+
+    // Calculate angle between tangents using cross product
+    let cross = t0.x * t1.y - t0.y * t1.x;
+    let inv_len_product = 1.0 / (t0_len * t1_len);
+    let mut angle = (cross.abs() * inv_len_product).min(1.0);
+
+    // Convert to angle
+    angle = angle.asin();
+
+    // Calculate number of subdivisions based on angle
+    let count = (angle / (PI * 2.0) * subdivisions as f32).round();
+    if count.is_finite() {
+        (count as usize).min(MAX_POINTS_PER_CURVE)
+    } else {
+        0
+    }
+}
+
 /// Evaluate a quadratic Bezier curve at parameter t
 #[inline(always)]
 fn qbezier(p0: Point2D, p1: Point2D, p2: Point2D, t: f32) -> Point2D {
@@ -335,4 +1029,270 @@ mod tests {
         let result = qbezier(p0, p1, p2, 0.5);
         assert!(result.y > 0.0);
     }
+
+    #[test]
+    fn test_min_points_per_curve() {
+        // A gentle arc: adaptive subdivision alone gives it no points
+        let mut contour = Contour::new(true);
+        contour.push_on_curve(Vec2::new(0.0, 0.0));
+        contour.push_off_curve(Vec2::new(0.5, 0.01));
+        contour.push_on_curve(Vec2::new(1.0, 0.0));
+        contour.push_on_curve(Vec2::new(1.0, -1.0));
+        contour.push_on_curve(Vec2::new(0.0, -1.0));
+        let mut outline = Outline2D::new();
+        outline.add_contour(contour);
+
+        let adaptive = linearize_outline(outline.clone(), 20).unwrap();
+        assert_eq!(adaptive.contours[0].points.len(), 4);
+
+        let options = LinearizeOptions {
+            min_points_per_curve: 5,
+            ..LinearizeOptions::new(20)
+        };
+        let forced = linearize_outline_with(outline, &options).unwrap();
+        assert_eq!(forced.contours[0].points.len(), 4 + 5);
+    }
+
+    #[test]
+    fn test_linearize_contour_matches_outline() {
+        let mut contour = Contour::new(true);
+        contour.push_on_curve(Vec2::new(0.0, 0.0));
+        contour.push_off_curve(Vec2::new(1.0, 0.0));
+        contour.push_on_curve(Vec2::new(1.0, 1.0));
+        contour.push_off_curve(Vec2::new(0.0, 1.0));
+        let mut outline = Outline2D::new();
+        outline.add_contour(contour.clone());
+
+        let single = linearize_contour(&contour, 20).unwrap();
+        let whole = linearize_outline(outline, 20).unwrap();
+        let points = |c: &Contour| c.points.iter().map(|p| p.point).collect::<Vec<_>>();
+        assert_eq!(points(&single), points(&whole.contours[0]));
+        assert!(single.closed);
+
+        assert!(matches!(
+            linearize_contour(&contour, 0),
+            Err(FontMeshError::InvalidQuality(0))
+        ));
+    }
+
+    #[test]
+    fn test_min_contour_area() {
+        let square = |min, max| crate::types::square(min, max, false);
+        // A large ring (outer + hole) with a small island inside the hole,
+        // and a small ring off to the side
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 1.0));
+        outline.add_contour(square(0.1, 0.9));
+        outline.add_contour(square(0.4, 0.6));
+        outline.add_contour(square(2.0, 2.2));
+        outline.add_contour(square(2.05, 2.15));
+
+        let sizes = |options: &LinearizeOptions| -> Vec<f32> {
+            let result = linearize_outline_with(outline.clone(), options).unwrap();
+            result
+                .contours
+                .iter()
+                .map(|c| c.signed_area().abs())
+                .collect()
+        };
+
+        let mut options = LinearizeOptions::new(20);
+        assert_eq!(sizes(&options).len(), 5);
+
+        // Small solids go, taking the hole inside the small ring with them
+        options.min_contour_area = 0.05;
+        let kept = sizes(&options);
+        assert_eq!(kept.len(), 2);
+        assert!(kept.iter().all(|&a| a > 0.5));
+
+        // A large hole stays even when dropping small holes
+        options.drop_small_holes = true;
+        assert_eq!(sizes(&options).len(), 2);
+        options.min_contour_area = 0.7;
+        assert_eq!(sizes(&options).len(), 1);
+    }
+
+    #[test]
+    fn test_subdivision_range() {
+        // A quarter circle turns the tangent by 90 degrees
+        let (p0, p1, p2) = (
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert_eq!(adaptive_point_count(p0, p1, p2, 20), 5);
+        assert_eq!(adaptive_point_count(p0, p1, p2, u8::MAX), 64);
+
+        let mut contour = Contour::new(true);
+        contour.push_on_curve(p0);
+        contour.push_off_curve(p1);
+        contour.push_on_curve(p2);
+        let options = LinearizeOptions {
+            min_points_per_curve: u8::MAX,
+            collinear_epsilon: 0.0,
+            ..LinearizeOptions::new(u8::MAX)
+        };
+        let finer = linearize_contour_with(&contour, &options);
+        assert_eq!(finer.points.len(), 2 + u8::MAX as usize);
+    }
+
+    #[test]
+    fn test_point_count_is_capped() {
+        // A hairpin with huge coordinates at the maximum settings
+        let mut contour = Contour::new(true);
+        contour.push_on_curve(Vec2::new(0.0, 0.0));
+        contour.push_off_curve(Vec2::new(1.0e7, 1.0));
+        contour.push_on_curve(Vec2::new(0.0, 2.0));
+        let mut outline = Outline2D::new();
+        outline.add_contour(contour);
+
+        let options = LinearizeOptions {
+            min_points_per_curve: u8::MAX,
+            ..LinearizeOptions::new(u8::MAX)
+        };
+        let result = linearize_outline_with(outline, &options).unwrap();
+        let points = result.contours[0].points.len();
+        assert!(points > 2);
+        assert!(points <= 2 + MAX_POINTS_PER_CURVE);
+
+        let count = adaptive_point_count(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(f32::MAX, 1.0),
+            Vec2::new(0.0, f32::MAX),
+            u8::MAX,
+        );
+        assert!(count <= MAX_POINTS_PER_CURVE);
+    }
+
+    #[test]
+    fn test_collinear_epsilon_and_preserve_corners() {
+        // A unit square with a tiny bump on its top edge
+        let mut contour = Contour::new(true);
+        for (x, y) in [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.502, 1.0),
+            (0.502, 1.002),
+            (0.498, 1.002),
+            (0.498, 1.0),
+            (0.0, 1.0),
+        ] {
+            contour.push_on_curve(Vec2::new(x, y));
+        }
+        let mut outline = Outline2D::new();
+        outline.add_contour(contour);
+        let with = |options: LinearizeOptions| {
+            linearize_outline_with(outline.clone(), &options)
+                .unwrap()
+                .contours[0]
+                .points
+                .len()
+        };
+
+        // The default tolerance flattens the bump
+        assert!(with(LinearizeOptions::new(20)) < 8);
+        assert_eq!(
+            with(LinearizeOptions {
+                collinear_epsilon: 1e-7,
+                ..LinearizeOptions::new(20)
+            }),
+            8
+        );
+        assert_eq!(
+            with(LinearizeOptions {
+                preserve_corners: true,
+                ..LinearizeOptions::new(20)
+            }),
+            8
+        );
+    }
+
+    #[test]
+    fn test_linearize_outline_matched() {
+        let square = crate::types::square(0.0, 1.0, true);
+        let mut circle = Contour::new(true);
+        circle.push_on_curve(Vec2::new(0.5, 0.0));
+        circle.push_off_curve(Vec2::new(0.0, 0.0));
+        circle.push_on_curve(Vec2::new(0.0, 0.5));
+        circle.push_off_curve(Vec2::new(0.0, 1.0));
+        circle.push_on_curve(Vec2::new(0.5, 1.0));
+        circle.push_off_curve(Vec2::new(1.0, 1.0));
+        circle.push_on_curve(Vec2::new(1.0, 0.5));
+        circle.push_off_curve(Vec2::new(1.0, 0.0));
+
+        let mut a = Outline2D::new();
+        a.add_contour(square.clone());
+        let mut b = Outline2D::new();
+        b.add_contour(circle);
+
+        let (a, b) = linearize_outline_matched(a, b, 20).unwrap();
+        let (a, b) = (&a.contours[0], &b.contours[0]);
+        assert!(b.points.len() > 4);
+        assert_eq!(a.points.len(), b.points.len());
+        // The square keeps its corners and stays a square
+        for corner in &square.points {
+            assert!(a.points.iter().any(|p| p.point == corner.point));
+        }
+        assert!((a.signed_area().abs() - 1.0).abs() < 1e-5);
+
+        let mut two = Outline2D::new();
+        two.add_contour(square.clone());
+        two.add_contour(square.clone());
+        let mut one = Outline2D::new();
+        one.add_contour(square);
+        assert!(matches!(
+            linearize_outline_matched(one, two, 20),
+            Err(FontMeshError::LinearizationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_straight_contour_keeps_points() {
+        // No off-curve points: only the collinear cleanup applies
+        let mut contour = Contour::new(true);
+        for (x, y) in [(0.0, 0.0), (0.0, 1.0), (0.5, 1.0), (1.0, 1.0), (1.0, 0.0)] {
+            contour.push_on_curve(Vec2::new(x, y));
+        }
+        let mut outline = Outline2D::new();
+        outline.add_contour(contour);
+
+        let linear = linearize_outline(outline, 20).unwrap();
+        let points: Vec<Vec2> = linear.contours[0].points.iter().map(|p| p.point).collect();
+        assert_eq!(
+            points,
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, 1.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(1.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hairline_contour_survives_collinear_cull() {
+        // A '|'-like bar far thinner than the collinear-cull threshold
+        let mut contour = Contour::new(true);
+        contour.push_on_curve(Vec2::new(0.0, 0.0));
+        contour.push_on_curve(Vec2::new(0.0, 1.0));
+        contour.push_on_curve(Vec2::new(1e-6, 1.0));
+        contour.push_on_curve(Vec2::new(1e-6, 0.0));
+        let mut outline = Outline2D::new();
+        outline.add_contour(contour);
+
+        let linearized = linearize_outline(outline, 20).unwrap();
+        assert_eq!(linearized.contours.len(), 1);
+        assert_eq!(linearized.contours[0].points.len(), 4);
+        assert!(crate::triangulate::triangulate(&linearized).is_ok());
+
+        // Fully collinear contours still have nothing to keep
+        let mut line = Contour::new(true);
+        line.push_on_curve(Vec2::new(0.0, 0.0));
+        line.push_on_curve(Vec2::new(0.5, 0.0));
+        line.push_on_curve(Vec2::new(1.0, 0.0));
+        let mut outline = Outline2D::new();
+        outline.add_contour(line);
+        assert!(linearize_outline(outline, 20).unwrap().is_empty());
+    }
 }
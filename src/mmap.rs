@@ -0,0 +1,128 @@
+//! Memory-mapped font files (requires the `mmap` feature, Unix only)
+//!
+//! Large fonts and collections (CJK fonts are often tens of megabytes) don't
+//! need to be read into memory up front: only the pages holding the tables and
+//! glyphs you actually touch are loaded by the OS.
+
+use crate::error::{FontMeshError, Result};
+use std::fs::File;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use ttf_parser::Face;
+
+/// A read-only memory mapping of a font file
+///
+/// The mapping stays alive as long as this value, and any `Face` obtained from
+/// [`MmapFont::face`] borrows from it.
+pub struct MmapFont {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// The mapping is read-only and owned exclusively by this value
+unsafe impl Send for MmapFont {}
+unsafe impl Sync for MmapFont {}
+
+impl MmapFont {
+    /// Memory-map a font file
+    ///
+    /// # Safety
+    /// The file must not be truncated or modified while it is mapped. Doing
+    /// so is undefined behavior: reads of truncated pages raise `SIGBUS`, and
+    /// modified bytes can change under an already parsed `Face`.
+    ///
+    /// # Arguments
+    /// * `path` - Path to a font or font collection file
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::mmap::MmapFont;
+    ///
+    /// // Safety: the test font isn't modified while mapped
+    /// let font = unsafe { MmapFont::open("assets/test_font.ttf")? };
+    /// let face = font.face(0)?;
+    /// let mesh = fontmesh::char_to_mesh_3d(&face, 'A', 5.0, 20)?;
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub unsafe fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let map_err = |e: std::io::Error| {
+            FontMeshError::ParseError(format!("Failed to map {}: {}", path.display(), e))
+        };
+
+        let file = File::open(path).map_err(map_err)?;
+        let file_len = file.metadata().map_err(map_err)?.len();
+        let len = usize::try_from(file_len).map_err(|_| {
+            FontMeshError::ParseError(format!(
+                "Failed to map {}: {} bytes exceed the address space",
+                path.display(),
+                file_len
+            ))
+        })?;
+        if len == 0 {
+            return Err(FontMeshError::ParseError(format!(
+                "Failed to map {}: file is empty",
+                path.display()
+            )));
+        }
+
+        let ptr = libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ,
+            libc::MAP_PRIVATE,
+            file.as_raw_fd(),
+            0,
+        );
+        if ptr == libc::MAP_FAILED {
+            return Err(map_err(std::io::Error::last_os_error()));
+        }
+
+        // The mapping stays valid after the file descriptor is closed
+        Ok(Self { ptr, len })
+    }
+
+    /// Get the mapped file contents
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+
+    /// Parse a face from the mapped file
+    ///
+    /// # Arguments
+    /// * `index` - Face index within a font collection; 0 for single fonts
+    pub fn face(&self, index: u32) -> Result<Face<'_>> {
+        Face::parse(self.as_bytes(), index)
+            .map_err(|e| FontMeshError::ParseError(format!("Failed to parse font: {:?}", e)))
+    }
+}
+
+impl Drop for MmapFont {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
+
+impl std::fmt::Debug for MmapFont {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MmapFont").field("len", &self.len).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mmap_matches_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/test_font.ttf");
+        let font = unsafe { MmapFont::open(path) }.unwrap();
+
+        assert_eq!(font.as_bytes(), std::fs::read(path).unwrap());
+        assert!(font.face(0).unwrap().glyph_index('A').is_some());
+        assert!(unsafe { MmapFont::open("does/not/exist.ttf") }.is_err());
+    }
+}
@@ -1,10 +1,13 @@
 //! 2D triangulation using lyon_tessellation
 
 use crate::error::{FontMeshError, Result};
-use crate::types::{Mesh2D, Outline2D};
+use crate::types::{Contour, Mesh2D, Outline2D};
 use glam::Vec2;
+use lyon_tessellation::math::point;
+use lyon_tessellation::path::EndpointId;
 use lyon_tessellation::{
-    FillOptions, FillTessellator, FillVertex, GeometryBuilder, VertexBuffers, VertexId,
+    FillOptions, FillRule, FillTessellator, FillVertex, GeometryBuilder, LineCap, LineJoin,
+    StrokeOptions, StrokeTessellator, StrokeVertex, VertexId, VertexSource,
 };
 
 /// Triangulate a 2D outline into a triangle mesh
@@ -17,14 +20,297 @@ use lyon_tessellation::{
 ///
 /// # Returns
 /// A 2D triangle mesh
+///
+/// Contours are filled with the even-odd rule, so holes are cut whichever
+/// way they are wound; [`Mesh2D::has_filled_holes`] can confirm the result.
 #[inline]
 pub fn triangulate(outline: &Outline2D) -> Result<Mesh2D> {
+    let mut mesh = Mesh2D::new();
+    triangulate_into(outline, &mut TriangulateScratch::new(), &mut mesh)?;
+    Ok(mesh)
+}
+
+/// Reusable tessellator state for [`triangulate_into`]
+///
+/// Holds lyon's fill tessellator so its internal buffers survive between
+/// calls. Keep one per thread when meshing many glyphs.
+pub struct TriangulateScratch {
+    tessellator: FillTessellator,
+}
+
+impl TriangulateScratch {
+    /// Create an empty scratch
+    pub fn new() -> Self {
+        Self {
+            tessellator: FillTessellator::new(),
+        }
+    }
+}
+
+impl Default for TriangulateScratch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for TriangulateScratch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TriangulateScratch").finish_non_exhaustive()
+    }
+}
+
+/// Triangulate a 2D outline into an existing mesh, reusing scratch state
+///
+/// Produces exactly the same mesh as [`triangulate`], but writes it into
+/// `out` (replacing its contents and keeping its capacity) and reuses the
+/// tessellator in `scratch`. The outline is fed to the tessellator directly,
+/// without building an intermediate lyon path. Once the buffers have grown
+/// to fit the largest glyph, meshing a batch allocates almost nothing.
+///
+/// # Arguments
+/// * `outline` - The linearized outline to triangulate
+/// * `scratch` - Tessellator state reused across calls
+/// * `out` - Receives the triangulated mesh
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph, Mesh2D};
+/// use fontmesh::triangulate::{triangulate_into, TriangulateScratch};
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+///
+/// let mut scratch = TriangulateScratch::new();
+/// let mut mesh = Mesh2D::new();
+/// for c in "Hello".chars() {
+///     let outline = Glyph::new(&face, c)?.with_subdivisions(20).to_outline()?;
+///     triangulate_into(&outline, &mut scratch, &mut mesh)?;
+///     // ... upload `mesh` ...
+/// }
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn triangulate_into(
+    outline: &Outline2D,
+    scratch: &mut TriangulateScratch,
+    out: &mut Mesh2D,
+) -> Result<()> {
     if outline.is_empty() {
         return Err(FontMeshError::TriangulationFailed(
             "Empty outline".to_string(),
         ));
     }
 
+    tessellate(outline, FillRule::EvenOdd, &mut scratch.tessellator, out)?;
+
+    #[cfg(feature = "debug-checks")]
+    crate::checks::triangulated(out);
+
+    Ok(())
+}
+
+/// Triangulate with the non-zero fill rule, so overlapping contours wound
+/// the same way merge into their union
+pub(crate) fn triangulate_nonzero(outline: &Outline2D) -> Result<Mesh2D> {
+    let mut mesh = Mesh2D::new();
+    tessellate(
+        outline,
+        FillRule::NonZero,
+        &mut FillTessellator::new(),
+        &mut mesh,
+    )?;
+    Ok(mesh)
+}
+
+/// Triangulate contours whose role (solid or hole) is stated by the caller
+///
+/// Unlike [`triangulate`], no fill rule is guessed from the winding in the
+/// font: every contour in `outer` is filled and every contour in `holes` is
+/// cut out, whatever direction its points run in. The contours are
+/// re-wound accordingly and tessellated with the non-zero rule, so
+/// overlapping solids merge into their union.
+///
+/// # Arguments
+/// * `outer` - Linearized contours to fill
+/// * `holes` - Linearized contours to cut out of the filled area
+///
+/// # Errors
+/// Returns [`FontMeshError::TriangulationFailed`] if `outer` is empty or a
+/// hole doesn't lie inside any outer contour.
+///
+/// Example
+/// ```
+/// use fontmesh::triangulate_with_holes;
+/// use fontmesh::types::Contour;
+/// use glam::Vec2;
+///
+/// let square = |min: f32, max: f32| {
+///     let mut contour = Contour::new(true);
+///     for (x, y) in [(min, min), (max, min), (max, max), (min, max)] {
+///         contour.push_on_curve(Vec2::new(x, y));
+///     }
+///     contour
+/// };
+///
+/// // Same winding for both; the second is a hole because we say so
+/// let mesh = triangulate_with_holes(&[square(0.0, 3.0)], &[square(1.0, 2.0)])?;
+/// assert_eq!(mesh.triangle_count(), 8);
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn triangulate_with_holes(outer: &[Contour], holes: &[Contour]) -> Result<Mesh2D> {
+    if outer.is_empty() {
+        return Err(FontMeshError::TriangulationFailed(
+            "No outer contours".to_string(),
+        ));
+    }
+    if let Some(i) = holes.iter().position(|hole| {
+        !outer
+            .iter()
+            .any(|solid| hole.points.iter().all(|cp| solid.contains(cp.point)))
+    }) {
+        return Err(FontMeshError::TriangulationFailed(format!(
+            "Hole {} is not inside any outer contour",
+            i
+        )));
+    }
+
+    // Solids counter-clockwise, holes clockwise
+    let wound = |contour: &Contour, counter_clockwise: bool| {
+        let mut contour = contour.clone();
+        if (contour.signed_area() > 0.0) != counter_clockwise {
+            contour.points.reverse();
+        }
+        contour
+    };
+    let mut outline = Outline2D::new();
+    outer
+        .iter()
+        .for_each(|contour| outline.add_contour(wound(contour, true)));
+    holes
+        .iter()
+        .for_each(|contour| outline.add_contour(wound(contour, false)));
+
+    triangulate_nonzero(&outline)
+}
+
+/// Triangulate a 2D outline, forcing extra interior points into the mesh
+///
+/// The outline is triangulated as with [`triangulate`], then each point in
+/// `extra` is inserted as a vertex by splitting the triangles that contain it:
+/// a point inside a triangle splits it in three, a point on a shared edge
+/// splits both neighbours in two. This pins vertices at chosen positions, e.g.
+/// for per-glyph displacement mapping.
+///
+/// Points outside the filled area (including inside holes) are ignored, as are
+/// points that coincide with an existing vertex. Splitting doesn't re-balance
+/// the triangulation, so many points clustered together produce thin
+/// triangles.
+///
+/// # Arguments
+/// * `outline` - The linearized outline to triangulate
+/// * `extra` - Interior points that must appear as mesh vertices
+///
+/// # Returns
+/// A 2D triangle mesh containing every accepted point as a vertex
+///
+/// Example
+/// ```
+/// use fontmesh::{Face, glyph::Glyph, triangulate_with_points};
+/// use glam::Vec2;
+///
+/// let font_data = include_bytes!("../assets/test_font.ttf");
+/// let face = Face::parse(font_data, 0)?;
+/// let outline = Glyph::new(&face, 'I')?.with_subdivisions(20).to_outline()?;
+///
+/// let pin = Vec2::new(0.15, 0.35);
+/// let mesh = triangulate_with_points(&outline, &[pin])?;
+/// assert!(mesh.vertices.contains(&pin));
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+pub fn triangulate_with_points(outline: &Outline2D, extra: &[Vec2]) -> Result<Mesh2D> {
+    let mut mesh = triangulate(outline)?;
+    for &point in extra {
+        insert_point(&mut mesh, point);
+    }
+    Ok(mesh)
+}
+
+/// Insert `p` into the mesh by splitting every triangle that contains it
+fn insert_point(mesh: &mut Mesh2D, p: Vec2) {
+    const EPSILON: f32 = 1e-5;
+
+    // (triangle start, barycentric weights) of every triangle touching `p`
+    let mut hits = Vec::new();
+    for (t, tri) in mesh.indices.chunks_exact(3).enumerate() {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+        let area = (b - a).perp_dot(c - a);
+        if area.abs() <= f32::EPSILON {
+            continue;
+        }
+        let weights = [
+            (c - b).perp_dot(p - b) / area,
+            (a - c).perp_dot(p - c) / area,
+            (b - a).perp_dot(p - a) / area,
+        ];
+        if weights.iter().all(|&w| w >= -EPSILON) {
+            if weights.iter().any(|&w| w >= 1.0 - EPSILON) {
+                // Already a vertex of the mesh
+                return;
+            }
+            hits.push((t * 3, weights));
+        }
+    }
+
+    if hits.is_empty() {
+        return;
+    }
+
+    let index = mesh.vertices.len() as u32;
+    if mesh.boundary_mask.len() == mesh.vertices.len() {
+        mesh.boundary_mask.push(false);
+    }
+    if mesh.contour_ids.len() == mesh.vertices.len() {
+        // Inside the fill of whichever contour the first hit triangle came from
+        let id = mesh.contour_ids[mesh.indices[hits[0].0] as usize];
+        mesh.contour_ids.push(id);
+    }
+    mesh.vertices.push(p);
+
+    for (start, weights) in hits {
+        let [a, b, c] = [
+            mesh.indices[start],
+            mesh.indices[start + 1],
+            mesh.indices[start + 2],
+        ];
+        // Each split keeps the winding of the original triangle
+        match weights.iter().position(|&w| w < EPSILON) {
+            // On the edge opposite to a, b or c respectively
+            Some(0) => {
+                mesh.indices[start + 2] = index;
+                mesh.indices.extend_from_slice(&[a, index, c]);
+            }
+            Some(1) => {
+                mesh.indices[start] = index;
+                mesh.indices.extend_from_slice(&[a, b, index]);
+            }
+            Some(_) => {
+                mesh.indices[start + 1] = index;
+                mesh.indices.extend_from_slice(&[index, b, c]);
+            }
+            None => {
+                mesh.indices[start + 2] = index;
+                mesh.indices.extend_from_slice(&[b, c, index, c, a, index]);
+            }
+        }
+    }
+}
+
+/// Tessellate an outline with lyon using the given fill rule
+fn tessellate(
+    outline: &Outline2D,
+    fill_rule: FillRule,
+    tessellator: &mut FillTessellator,
+    out: &mut Mesh2D,
+) -> Result<()> {
     // Pre-allocate buffers based on outline size
     // Estimate: roughly 4x the number of outline points for vertices
     // and ~3x vertices for indices (each triangle = 3 indices)
@@ -32,16 +318,43 @@ pub fn triangulate(outline: &Outline2D) -> Result<Mesh2D> {
     let estimated_vertices = point_count * 4;
     let estimated_indices = estimated_vertices * 3;
 
-    let mut geometry: VertexBuffers<[f32; 2], u32> =
-        VertexBuffers::with_capacity(estimated_vertices, estimated_indices);
-    let mut tessellator = FillTessellator::new();
+    out.vertices.clear();
+    out.indices.clear();
+    out.boundary_mask.clear();
+    out.contour_ids.clear();
+    out.vertices.reserve(estimated_vertices);
+    out.indices.reserve(estimated_indices);
+    out.boundary_mask.reserve(estimated_vertices);
+    out.contour_ids.reserve(estimated_vertices);
 
-    // Configure fill options (even-odd rule for font glyphs)
-    let options = FillOptions::default().with_fill_rule(lyon_tessellation::FillRule::EvenOdd);
+    // lyon numbers path endpoints in the order they are fed, so the first
+    // endpoint id of every contour maps vertex sources back to contours
+    let mut contour_starts = Vec::with_capacity(outline.contours.len());
+    let mut next_id = 0;
+    for (i, contour) in outline.contours.iter().enumerate() {
+        if !contour.is_empty() {
+            contour_starts.push((next_id, i as u32));
+            next_id += contour.points.len() as u32;
+        }
+    }
 
-    // Build the path from our outline
-    let mut builder = lyon_tessellation::path::Path::builder();
+    // lyon merges vertices closer than half its tolerance, and the default
+    // is absolute: at em scale it would collapse strokes thinner than 0.05 em
+    let extent = outline
+        .bounds()
+        .map_or(0.0, |[min, max]| (max[0] - min[0]).max(max[1] - min[1]));
+    let tolerance = (extent * 1e-4).clamp(f32::EPSILON, FillOptions::DEFAULT_TOLERANCE);
+    let options = FillOptions::default()
+        .with_fill_rule(fill_rule)
+        .with_tolerance(tolerance);
+    let mut output = MeshBuilder {
+        mesh: out,
+        contour_starts: &contour_starts,
+    };
+    let mut builder = tessellator.builder(&options, &mut output);
+    builder.reserve(point_count, 0);
 
+    // Feed the outline to the tessellator
     outline
         .contours
         .iter()
@@ -49,59 +362,135 @@ pub fn triangulate(outline: &Outline2D) -> Result<Mesh2D> {
         .for_each(|contour| {
             // Start the contour
             let first = contour.points[0].point;
-            builder.begin(lyon_tessellation::math::Point::new(first.x, first.y));
+            builder.begin(point(first.x, first.y));
 
             // Add lines to the rest of the points
             contour.points[1..].iter().for_each(|cp| {
-                builder.line_to(lyon_tessellation::math::Point::new(cp.point.x, cp.point.y));
+                builder.line_to(point(cp.point.x, cp.point.y));
             });
 
             // Close the contour if needed
-            if contour.closed {
-                builder.close();
-            } else {
-                builder.end(false);
-            }
+            builder.end(contour.closed);
         });
 
-    let path = builder.build();
-
     // Tessellate the path
-    tessellator
-        .tessellate_path(&path, &options, &mut SimpleBuffersBuilder(&mut geometry))
-        .map_err(|e| {
-            FontMeshError::TriangulationFailed(format!("Lyon tessellation failed: {:?}", e))
-        })?;
+    builder.build().map_err(|e| {
+        FontMeshError::TriangulationFailed(format!("Lyon tessellation failed: {:?}", e))
+    })?;
 
-    // Convert to our Mesh2D format (pre-allocate for efficiency)
-    let vertices: Vec<Vec2> = geometry.vertices.into_iter().map(Vec2::from).collect();
-    Ok(Mesh2D {
-        vertices,
-        indices: geometry.indices,
-    })
+    Ok(())
 }
 
-/// Simple geometry builder for lyon tessellation
-struct SimpleBuffersBuilder<'a>(&'a mut VertexBuffers<[f32; 2], u32>);
+/// Stroke every contour of an outline with lyon's stroke tessellator
+///
+/// See [`Outline2D::stroke_mesh_2d`].
+pub(crate) fn stroke(
+    outline: &Outline2D,
+    width: f32,
+    line_join: LineJoin,
+    line_cap: LineCap,
+) -> Result<Mesh2D> {
+    if !width.is_finite() || width <= 0.0 {
+        return Err(FontMeshError::InvalidParameter(
+            "stroke width must be a positive finite value".to_string(),
+        ));
+    }
 
-impl<'a> GeometryBuilder for SimpleBuffersBuilder<'a> {
+    // lyon's default tolerance is absolute and far too coarse at em scale
+    let options = StrokeOptions::default()
+        .with_line_width(width)
+        .with_line_join(line_join)
+        .with_line_cap(line_cap)
+        .with_tolerance(width * 0.05);
+    let mut mesh = Mesh2D::new();
+    let mut tessellator = StrokeTessellator::new();
+    let mut output = MeshBuilder {
+        mesh: &mut mesh,
+        contour_starts: &[],
+    };
+    let mut builder = tessellator.builder(&options, &mut output);
+
+    outline
+        .contours
+        .iter()
+        .filter(|contour| !contour.is_empty())
+        .for_each(|contour| {
+            let first = contour.points[0].point;
+            builder.begin(point(first.x, first.y));
+            contour.points[1..].iter().for_each(|cp| {
+                builder.line_to(point(cp.point.x, cp.point.y));
+            });
+            builder.end(contour.closed);
+        });
+
+    builder.build().map_err(|e| {
+        FontMeshError::TriangulationFailed(format!("Lyon stroke tessellation failed: {:?}", e))
+    })?;
+
+    // Stroke vertices sit beside the outline, not on it
+    mesh.boundary_mask.clear();
+    mesh.contour_ids.clear();
+    Ok(mesh)
+}
+
+/// Geometry builder writing lyon's output straight into a [`Mesh2D`]
+struct MeshBuilder<'a> {
+    mesh: &'a mut Mesh2D,
+    /// First endpoint id and outline index of every contour fed to lyon
+    contour_starts: &'a [(u32, u32)],
+}
+
+impl<'a> MeshBuilder<'a> {
+    /// The contour an endpoint id belongs to
+    fn contour_of(&self, endpoint: EndpointId) -> u32 {
+        let next = self
+            .contour_starts
+            .partition_point(|&(start, _)| start <= endpoint.0);
+        self.contour_starts[next.saturating_sub(1)].1
+    }
+}
+
+impl<'a> GeometryBuilder for MeshBuilder<'a> {
     #[inline]
     fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
-        self.0.indices.push(a.0);
-        self.0.indices.push(b.0);
-        self.0.indices.push(c.0);
+        self.mesh.indices.push(a.0);
+        self.mesh.indices.push(b.0);
+        self.mesh.indices.push(c.0);
     }
 }
 
-impl<'a> lyon_tessellation::FillGeometryBuilder for SimpleBuffersBuilder<'a> {
+impl<'a> lyon_tessellation::FillGeometryBuilder for MeshBuilder<'a> {
     fn add_fill_vertex(
         &mut self,
         vertex: FillVertex,
     ) -> std::result::Result<VertexId, lyon_tessellation::GeometryBuilderError> {
-        let index = self.0.vertices.len() as u32;
-        self.0
-            .vertices
-            .push([vertex.position().x, vertex.position().y]);
+        let index = self.mesh.vertices.len() as u32;
+        let position = vertex.position();
+        self.mesh.vertices.push(Vec2::new(position.x, position.y));
+        self.mesh
+            .boundary_mask
+            .push(vertex.sources().any(|source| source.is_endpoint()));
+        // Every fill vertex has at least one source; where contours cross,
+        // the first one wins
+        let contour = match vertex.sources().next() {
+            Some(VertexSource::Endpoint { id }) | Some(VertexSource::Edge { from: id, .. }) => {
+                self.contour_of(id)
+            }
+            None => 0,
+        };
+        self.mesh.contour_ids.push(contour);
+        Ok(VertexId(index))
+    }
+}
+
+impl<'a> lyon_tessellation::StrokeGeometryBuilder for MeshBuilder<'a> {
+    fn add_stroke_vertex(
+        &mut self,
+        vertex: StrokeVertex,
+    ) -> std::result::Result<VertexId, lyon_tessellation::GeometryBuilderError> {
+        let index = self.mesh.vertices.len() as u32;
+        let position = vertex.position();
+        self.mesh.vertices.push(Vec2::new(position.x, position.y));
         Ok(VertexId(index))
     }
 }
@@ -109,7 +498,7 @@ impl<'a> lyon_tessellation::FillGeometryBuilder for SimpleBuffersBuilder<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::Contour;
+    use crate::types::{square, Contour};
     use glam::Vec2;
 
     #[test]
@@ -131,4 +520,212 @@ mod tests {
         assert!(mesh.vertices.len() >= 4);
         assert!(mesh.triangle_count() >= 2);
     }
+
+    #[test]
+    fn test_triangulate_into_reuses_buffers() {
+        let square = |min, max| square(min, max, false);
+        let mut solid = Outline2D::new();
+        solid.add_contour(square(0.0, 3.0));
+        let mut ring = solid.clone();
+        ring.add_contour(square(1.0, 2.0));
+
+        let mut scratch = TriangulateScratch::new();
+        let mut mesh = Mesh2D::new();
+        for outline in [&ring, &solid, &ring] {
+            triangulate_into(outline, &mut scratch, &mut mesh).unwrap();
+            let expected = triangulate(outline).unwrap();
+            assert_eq!(mesh.vertices, expected.vertices);
+            assert_eq!(mesh.indices, expected.indices);
+        }
+
+        assert!(triangulate_into(&Outline2D::new(), &mut scratch, &mut mesh).is_err());
+    }
+
+    #[test]
+    fn test_has_filled_holes() {
+        let square = |min, max| square(min, max, true);
+
+        let mut solid = Outline2D::new();
+        solid.add_contour(square(0.0, 3.0));
+        let mut ring = solid.clone();
+        ring.add_contour(square(1.0, 2.0));
+
+        // Same winding for outer and inner contour; the hole must still be cut
+        let mesh = triangulate(&ring).unwrap();
+        assert!(!mesh.has_filled_holes(&ring));
+
+        let blob = triangulate(&solid).unwrap();
+        assert!(blob.has_filled_holes(&ring));
+    }
+
+    #[test]
+    fn test_border_mesh_2d() {
+        let square = |min, max| square(min, max, true);
+
+        let mut solid = Outline2D::new();
+        solid.add_contour(square(0.0, 1.0));
+        let border = solid.border_mesh_2d(0.1).unwrap();
+        assert!((border.area() - (1.2 * 1.2 - 1.0)).abs() < 1e-4);
+
+        // The counter gets its own inner ring
+        let mut ring = solid.clone();
+        ring.add_contour(square(0.4, 0.6));
+        let border = ring.border_mesh_2d(0.05).unwrap();
+        let expected = (1.1 * 1.1 - 1.0) + (0.2 * 0.2 - 0.1 * 0.1);
+        assert!(
+            (border.area() - expected).abs() < 1e-4,
+            "{} vs {}",
+            border.area(),
+            expected
+        );
+
+        // A counter narrower than the border is filled completely
+        let mut small = solid.clone();
+        small.add_contour(square(0.45, 0.55));
+        let border = small.border_mesh_2d(0.1).unwrap();
+        let expected = (1.2 * 1.2 - 1.0) + 0.1 * 0.1;
+        assert!(
+            (border.area() - expected).abs() < 1e-4,
+            "{} vs {}",
+            border.area(),
+            expected
+        );
+
+        assert!(matches!(
+            solid.border_mesh_2d(0.0),
+            Err(FontMeshError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_boundary_mask() {
+        // Two overlapping squares: their edges cross at (1, 2) and (2, 1)
+        let mut outline = Outline2D::new();
+        for offset in [0.0, 1.0] {
+            outline.add_contour(square(offset, 2.0 + offset, false));
+        }
+
+        let mesh = triangulate(&outline).unwrap();
+        assert_eq!(mesh.vertices.len(), 10);
+        assert_eq!(mesh.boundary_mask.len(), mesh.vertices.len());
+        for (v, &on_outline) in mesh.vertices.iter().zip(&mesh.boundary_mask) {
+            let crossing = *v == Vec2::new(1.0, 2.0) || *v == Vec2::new(2.0, 1.0);
+            assert_eq!(on_outline, !crossing, "{v}");
+        }
+
+        let pinned = triangulate_with_points(&outline, &[Vec2::new(0.5, 0.5)]).unwrap();
+        assert_eq!(pinned.boundary_mask.len(), pinned.vertices.len());
+        assert!(!pinned.boundary_mask.last().unwrap());
+    }
+
+    #[test]
+    fn test_contour_ids() {
+        // A square ring after an empty contour, which keeps its index
+        let mut outline = Outline2D::new();
+        outline.add_contour(Contour::new(true));
+        outline.add_contour(square(0.0, 3.0, false));
+        outline.add_contour(square(1.0, 2.0, true));
+
+        let mesh = triangulate(&outline).unwrap();
+        assert_eq!(mesh.contour_ids.len(), mesh.vertices.len());
+        for (v, &id) in mesh.vertices.iter().zip(&mesh.contour_ids) {
+            let outer = v.x == 0.0 || v.x == 3.0 || v.y == 0.0 || v.y == 3.0;
+            assert_eq!(id, if outer { 1 } else { 2 }, "{v}");
+        }
+
+        let pinned = triangulate_with_points(&outline, &[Vec2::new(0.5, 1.5)]).unwrap();
+        assert_eq!(pinned.contour_ids.len(), pinned.vertices.len());
+
+        // Appended contours stay distinct
+        let mut both = mesh.clone();
+        both.append(&mesh);
+        assert_eq!(both.contour_ids.iter().max(), Some(&5));
+    }
+
+    #[test]
+    fn test_stroke() {
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 1.0, false));
+
+        let miter = outline
+            .stroke_mesh_2d(0.1, LineJoin::Miter, LineCap::Butt)
+            .unwrap();
+        let area = miter.area();
+        assert!((area - 1.1 * 1.1 + 0.9 * 0.9).abs() < 1e-4, "{}", area);
+
+        // Round joins stay within half the width of the square's boundary
+        let round = outline
+            .stroke_mesh_2d(0.1, LineJoin::Round, LineCap::Round)
+            .unwrap();
+        assert!(round.triangle_count() > miter.triangle_count());
+        for v in &round.vertices {
+            let outside = ((*v - Vec2::splat(0.5)).abs() - Vec2::splat(0.5))
+                .max(Vec2::ZERO)
+                .length();
+            assert!(outside <= 0.05 + 1e-4, "{:?}", v);
+        }
+        assert!(round.boundary_mask.is_empty());
+
+        assert!(matches!(
+            outline.stroke_mesh_2d(0.0, LineJoin::Round, LineCap::Round),
+            Err(FontMeshError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_triangulate_with_holes() {
+        // Holes are cut whichever way the contours are wound
+        for (outer_cw, hole_cw) in [(false, false), (false, true), (true, false), (true, true)] {
+            let mesh =
+                triangulate_with_holes(&[square(0.0, 3.0, outer_cw)], &[square(1.0, 2.0, hole_cw)])
+                    .unwrap();
+            assert!((mesh.area() - 8.0).abs() < 1e-4);
+        }
+
+        // Overlapping solids merge
+        let mesh = triangulate_with_holes(&[square(0.0, 2.0, false), square(1.0, 3.0, true)], &[])
+            .unwrap();
+        assert!((mesh.area() - 7.0).abs() < 1e-4);
+
+        assert!(triangulate_with_holes(&[], &[]).is_err());
+        assert!(matches!(
+            triangulate_with_holes(&[square(0.0, 1.0, false)], &[square(2.0, 3.0, false)]),
+            Err(FontMeshError::TriangulationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_triangulate_with_points() {
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 2.0, false));
+
+        let plain = triangulate(&outline).unwrap();
+        let extra = [
+            Vec2::new(0.5, 1.5),
+            Vec2::new(1.0, 1.0), // on the diagonal
+            Vec2::new(3.0, 1.0), // outside, ignored
+            Vec2::new(2.0, 2.0), // existing vertex, ignored
+        ];
+        let mesh = triangulate_with_points(&outline, &extra).unwrap();
+
+        assert_eq!(mesh.vertices.len(), plain.vertices.len() + 2);
+        assert!(mesh.vertices.contains(&extra[0]));
+        assert!(mesh.vertices.contains(&extra[1]));
+
+        // Area and orientation are preserved by every split
+        let signed_area = |m: &Mesh2D| -> Vec<f32> {
+            m.indices
+                .chunks_exact(3)
+                .map(|t| {
+                    let [a, b, c] = [t[0], t[1], t[2]].map(|i| m.vertices[i as usize]);
+                    (b - a).perp_dot(c - a) * 0.5
+                })
+                .collect()
+        };
+        let before = signed_area(&plain);
+        let after = signed_area(&mesh);
+        let sign = before[0].signum();
+        assert!(after.iter().all(|a| a.signum() == sign && a.abs() > 0.0));
+        assert!((after.iter().sum::<f32>() - before.iter().sum::<f32>()).abs() < 1e-5);
+    }
 }
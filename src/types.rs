@@ -1,6 +1,8 @@
 //! Core type definitions for fontmesh
 
-use glam::Vec2;
+use crate::error::FontMeshError;
+use glam::{IVec3, Vec2, Vec3, Vec4};
+use rustc_hash::{FxHashMap, FxHashSet};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -11,28 +13,59 @@ pub type Point2D = Vec2;
 pub struct ContourPoint {
     pub point: Point2D,
     pub on_curve: bool,
+    /// Whether this off-curve point is a control point of a cubic Bézier
+    ///
+    /// Cubic curves (CFF outlines, [`PathBuilder::cubic_to`]) store their
+    /// two control points as consecutive cubic off-curve points. Plain
+    /// off-curve points are TrueType quadratic control points, where two in
+    /// a row imply an on-curve point halfway between them.
+    pub cubic: bool,
 }
 
 impl ContourPoint {
     pub fn new(point: Point2D, on_curve: bool) -> Self {
-        Self { point, on_curve }
-    }
-
-    pub fn on_curve(point: Point2D) -> Self {
         Self {
             point,
-            on_curve: true,
+            on_curve,
+            cubic: false,
         }
     }
 
+    pub fn on_curve(point: Point2D) -> Self {
+        Self::new(point, true)
+    }
+
     pub fn off_curve(point: Point2D) -> Self {
+        Self::new(point, false)
+    }
+
+    /// A control point of a cubic Bézier
+    pub fn cubic_control(point: Point2D) -> Self {
         Self {
             point,
             on_curve: false,
+            cubic: true,
         }
     }
 }
 
+/// A contour segment with its original control points
+///
+/// Unlike [`Contour`], which stores points with on/off-curve flags, segments
+/// keep the curve structure explicit, e.g. for GPU curve rendering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    /// A straight line from the first to the second point
+    Line(Point2D, Point2D),
+    /// A quadratic Bézier: start, control, end
+    Quad(Point2D, Point2D, Point2D),
+    /// A cubic Bézier: start, two controls, end
+    Cubic(Point2D, Point2D, Point2D, Point2D),
+}
+
+/// The segments of one closed contour, each starting where the previous ends
+pub type ContourCurves = Vec<Segment>;
+
 /// A single contour (closed or open path)
 #[derive(Debug, Clone)]
 pub struct Contour {
@@ -60,9 +93,216 @@ impl Contour {
         self.points.push(ContourPoint::off_curve(point));
     }
 
+    /// Add the two control points of a cubic Bézier
+    ///
+    /// Follow with the curve's on-curve end point, or end the contour to
+    /// curve back to its first point.
+    pub fn push_cubic_controls(&mut self, c1: Point2D, c2: Point2D) {
+        self.points.push(ContourPoint::cubic_control(c1));
+        self.points.push(ContourPoint::cubic_control(c2));
+    }
+
     pub fn is_empty(&self) -> bool {
         self.points.is_empty()
     }
+
+    /// Signed area of the polygon formed by the contour points (shoelace formula)
+    ///
+    /// Positive for counter-clockwise contours, negative for clockwise ones
+    /// (in the y-up font coordinate system). Off-curve points are treated as
+    /// polygon vertices, so use a linearized contour for an accurate area.
+    pub fn signed_area(&self) -> f32 {
+        let n = self.points.len();
+        if n < 3 {
+            return 0.0;
+        }
+        let sum: f32 = (0..n)
+            .map(|i| {
+                let p0 = self.points[i].point;
+                let p1 = self.points[(i + 1) % n].point;
+                p0.x * p1.y - p1.x * p0.y
+            })
+            .sum();
+        sum * 0.5
+    }
+
+    /// How far each point of a linearized contour moves per unit of offset
+    ///
+    /// Points move along the bisector of their two edges' normals, away from
+    /// the filled side, stretched so that each edge moves by exactly the
+    /// offset distance. Very sharp corners are limited to 4x. Zero-length
+    /// edges are passed over, so repeated points move together.
+    pub(crate) fn miter_offsets(&self, filled_left: bool) -> Vec<Vec2> {
+        const MAX_MITER: f32 = 4.0;
+
+        let n = self.points.len();
+        // Unit normal of each edge, pointing away from the filled side
+        let normals: Vec<Vec2> = (0..n)
+            .map(|i| {
+                let dir =
+                    (self.points[(i + 1) % n].point - self.points[i].point).normalize_or_zero();
+                if filled_left {
+                    Vec2::new(dir.y, -dir.x)
+                } else {
+                    Vec2::new(-dir.y, dir.x)
+                }
+            })
+            .collect();
+        let nonzero = |normal: &Vec2| *normal != Vec2::ZERO;
+
+        (0..n)
+            .map(|i| {
+                let prev = (1..=n).map(|k| normals[(i + n - k) % n]).find(nonzero);
+                let next = (0..n).map(|k| normals[(i + k) % n]).find(nonzero);
+                let (Some(prev), Some(next)) = (prev, next) else {
+                    return Vec2::ZERO;
+                };
+                let miter = (prev + next).normalize_or(next);
+                miter / miter.dot(next).max(1.0 / MAX_MITER)
+            })
+            .collect()
+    }
+
+    /// Check whether a point lies inside the polygon formed by the contour points
+    ///
+    /// Uses the even-odd ray casting test; points exactly on an edge may be
+    /// reported either way.
+    pub fn contains(&self, point: Point2D) -> bool {
+        let n = self.points.len();
+        if n < 3 {
+            return false;
+        }
+        let mut inside = false;
+        let mut j = n - 1;
+        for i in 0..n {
+            let pi = self.points[i].point;
+            let pj = self.points[j].point;
+            if (pi.y > point.y) != (pj.y > point.y)
+                && point.x < (pj.x - pi.x) * (point.y - pi.y) / (pj.y - pi.y) + pi.x
+            {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+
+    /// Find the points where the contour crosses or touches itself
+    ///
+    /// Every pair of non-adjacent edges is tested, so this is O(n²) in the
+    /// number of points - fine for diagnosing a glyph. Self-intersecting
+    /// contours triangulate unpredictably and usually show up as missing or
+    /// inverted regions. Like [`Contour::signed_area`], off-curve points are
+    /// treated as polygon vertices, so use a linearized contour. Zero-length
+    /// edges are ignored, and overlapping parallel edges aren't reported.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::types::Contour;
+    /// use glam::Vec2;
+    ///
+    /// // A bow tie crossing itself at (1, 1)
+    /// let mut contour = Contour::new(true);
+    /// for (x, y) in [(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)] {
+    ///     contour.push_on_curve(Vec2::new(x, y));
+    /// }
+    /// assert_eq!(contour.self_intersections(), vec![Vec2::new(1.0, 1.0)]);
+    /// ```
+    #[must_use]
+    pub fn self_intersections(&self) -> Vec<Vec2> {
+        let n = self.points.len();
+        let edge_count = if self.closed { n } else { n.saturating_sub(1) };
+        let edges: Vec<(Vec2, Vec2)> = (0..edge_count)
+            .map(|i| (self.points[i].point, self.points[(i + 1) % n].point))
+            .filter(|(a, b)| a != b)
+            .collect();
+
+        let mut hits = Vec::new();
+        let m = edges.len();
+        for i in 0..m {
+            for j in i + 2..m {
+                // The last edge of a closed contour is adjacent to the first
+                if self.closed && i == 0 && j == m - 1 {
+                    continue;
+                }
+                if let Some(hit) = segment_intersection(edges[i], edges[j]) {
+                    hits.push(hit);
+                }
+            }
+        }
+        hits
+    }
+}
+
+/// A closed square contour from `(min, min)` to `(max, max)`, for tests
+///
+/// Both windings start at `(min, min)`.
+#[cfg(test)]
+pub(crate) fn square(min: f32, max: f32, clockwise: bool) -> Contour {
+    let corners = if clockwise {
+        [(min, min), (min, max), (max, max), (max, min)]
+    } else {
+        [(min, min), (max, min), (max, max), (min, max)]
+    };
+    let mut contour = Contour::new(true);
+    for (x, y) in corners {
+        contour.push_on_curve(Vec2::new(x, y));
+    }
+    contour
+}
+
+/// Intersection point of two segments, or `None` if they're disjoint or parallel
+pub(crate) fn segment_intersection((a0, a1): (Vec2, Vec2), (b0, b1): (Vec2, Vec2)) -> Option<Vec2> {
+    let da = a1 - a0;
+    let db = b1 - b0;
+    let denom = da.perp_dot(db);
+    if denom == 0.0 {
+        return None;
+    }
+    let offset = b0 - a0;
+    let t = offset.perp_dot(db) / denom;
+    let u = offset.perp_dot(da) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then(|| a0 + da * t)
+}
+
+/// Weld points at the same exact position, returning each point's id and
+/// the distinct points in first-seen order
+///
+/// Coordinates are compared by bits after `+ 0.0`, which folds -0.0 into 0.0.
+pub(crate) fn weld<V: Copy, const N: usize>(
+    points: &[V],
+    coords: impl Fn(&V) -> [f32; N],
+) -> (Vec<u32>, Vec<V>) {
+    let mut ids: FxHashMap<[u32; N], u32> = FxHashMap::default();
+    let mut distinct = Vec::new();
+    let welded = points
+        .iter()
+        .map(|&p| {
+            let key = coords(&p).map(|c| (c + 0.0).to_bits());
+            *ids.entry(key).or_insert_with(|| {
+                distinct.push(p);
+                distinct.len() as u32 - 1
+            })
+        })
+        .collect();
+    (welded, distinct)
+}
+
+/// Where a contour sits in its outline's nesting, see [`Outline2D::classify_nesting`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContourDepth {
+    /// Number of other contours enclosing this one
+    pub depth: usize,
+    /// Index of the innermost enclosing contour, if any
+    pub parent: Option<usize>,
+}
+
+impl ContourDepth {
+    /// Whether the contour cuts a hole (odd depth) rather than adds fill
+    #[inline]
+    pub fn is_hole(&self) -> bool {
+        self.depth % 2 == 1
+    }
 }
 
 /// A collection of contours representing a glyph outline
@@ -85,6 +325,405 @@ impl Outline2D {
     pub fn is_empty(&self) -> bool {
         self.contours.is_empty()
     }
+
+    /// Even-odd nesting depth of every contour
+    ///
+    /// Depth 0 is an outermost contour, 1 a hole inside it, 2 a solid inside
+    /// that hole, and so on. Containment is tested with each contour's first point.
+    pub(crate) fn nesting_depths(&self) -> Vec<usize> {
+        self.contours
+            .iter()
+            .enumerate()
+            .map(|(i, contour)| match contour.points.first() {
+                Some(cp) => self
+                    .contours
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, other)| j != i && other.contains(cp.point))
+                    .count(),
+                None => 0,
+            })
+            .collect()
+    }
+
+    /// Nesting depth and enclosing contour of every contour
+    ///
+    /// Depth 0 is an outermost contour, 1 a hole inside it, 2 a solid inside
+    /// that hole, and so on, following the even-odd rule the triangulator
+    /// uses. The parent is the innermost contour enclosing this one.
+    /// Containment is tested with each contour's first point, so use a
+    /// linearized outline; overlapping contours are not resolved.
+    ///
+    /// Useful when splitting an outline into its filled pieces, e.g. to mesh
+    /// the ring of '®' and its letter separately.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    ///
+    /// let outline = Glyph::new(&face, '®')?.linearize()?;
+    /// let mut depths: Vec<usize> = outline.classify_nesting().iter().map(|d| d.depth).collect();
+    /// depths.sort_unstable();
+    /// assert_eq!(depths, vec![0, 1, 2, 3]);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn classify_nesting(&self) -> Vec<ContourDepth> {
+        let depths = self.nesting_depths();
+        depths
+            .iter()
+            .enumerate()
+            .map(|(i, &depth)| {
+                let parent = match (depth, self.contours[i].points.first()) {
+                    (0, _) | (_, None) => None,
+                    (_, Some(cp)) => (0..self.contours.len()).find(|&j| {
+                        j != i && depths[j] + 1 == depth && self.contours[j].contains(cp.point)
+                    }),
+                };
+                ContourDepth { depth, parent }
+            })
+            .collect()
+    }
+
+    /// Group contours into filled components: each outer contour (even
+    /// nesting depth) followed by the holes directly inside it
+    ///
+    /// Groups are ordered by their outer contour's position in the outline.
+    pub(crate) fn contour_groups(&self) -> Vec<Vec<usize>> {
+        let nesting = self.classify_nesting();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of = vec![usize::MAX; self.contours.len()];
+
+        for (i, n) in nesting.iter().enumerate() {
+            if !n.is_hole() {
+                group_of[i] = groups.len();
+                groups.push(vec![i]);
+            }
+        }
+        for (i, n) in nesting.iter().enumerate() {
+            // A hole joins the group of the outer contour one level up
+            if let (true, Some(parent)) = (n.is_hole(), n.parent) {
+                groups[group_of[parent]].push(i);
+            }
+        }
+
+        groups
+    }
+
+    /// Uniformly scale every point of the outline about the origin
+    pub(crate) fn scale(&mut self, factor: f32) {
+        for cp in self.contours.iter_mut().flat_map(|c| c.points.iter_mut()) {
+            cp.point *= factor;
+        }
+    }
+
+    /// Move every point of the outline by `offset`
+    pub(crate) fn translate(&mut self, offset: Vec2) {
+        for cp in self.contours.iter_mut().flat_map(|c| c.points.iter_mut()) {
+            cp.point += offset;
+        }
+    }
+
+    /// Shear the outline horizontally: each point moves by `y * factor` in x
+    ///
+    /// y coordinates, and so the baseline and vertical extent, are unchanged.
+    pub(crate) fn shear_x(&mut self, factor: f32) {
+        for cp in self.contours.iter_mut().flat_map(|c| c.points.iter_mut()) {
+            cp.point.x += cp.point.y * factor;
+        }
+    }
+
+    /// Offset every contour of a linearized outline by `distance`
+    ///
+    /// Positive distances grow the filled area (outer contours move out,
+    /// counters shrink), negative distances thin it. Corners are mitered, with
+    /// very sharp ones limited to 4x the distance. Contours that collapse past
+    /// themselves, e.g. a counter smaller than the offset, are dropped.
+    ///
+    /// # Arguments
+    /// * `distance` - Offset distance in outline units
+    pub fn offset(&self, distance: f32) -> Outline2D {
+        let mut result = Outline2D::new();
+        for (contour, filled_left) in self
+            .contours
+            .iter()
+            .zip(crate::extrude::contour_fill_sides(self))
+        {
+            let points: Vec<Point2D> = contour.points.iter().map(|cp| cp.point).collect();
+            let n = points.len();
+            if n < 3 {
+                continue;
+            }
+
+            let mut offset = Contour::new(contour.closed);
+            for (&point, miter) in points.iter().zip(contour.miter_offsets(filled_left)) {
+                offset.push_on_curve(point + miter * distance);
+            }
+
+            // A contour that collapsed past itself has its edges reversed
+            // (a square counter shrunk too far comes out rotated by 180°,
+            // with the same winding)
+            let alignment: f32 = (0..n)
+                .map(|i| {
+                    let before = points[(i + 1) % n] - points[i];
+                    let after = offset.points[(i + 1) % n].point - offset.points[i].point;
+                    before.dot(after)
+                })
+                .sum();
+            let area = offset.signed_area();
+            if alignment > 0.0
+                && area.signum() == contour.signed_area().signum()
+                && area.abs() > f32::EPSILON
+            {
+                result.add_contour(offset);
+            }
+        }
+        result
+    }
+
+    /// Triangulate a ring of the given width around the outside of the outline
+    ///
+    /// Covers the area between the outline and its [`offset`](Self::offset)
+    /// by `width`, including inside counters (which a wide border may fill
+    /// completely). Append it to the glyph's fill mesh with
+    /// [`Mesh2D::append`] to draw outlined text.
+    ///
+    /// # Arguments
+    /// * `width` - The border width, positive and finite
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidParameter`] if `width` isn't positive
+    /// and finite.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let outline = Glyph::new(&face, 'O')?.with_subdivisions(20).to_outline()?;
+    ///
+    /// let mut mesh = outline.triangulate()?;
+    /// let fill_triangles = mesh.triangle_count();
+    /// mesh.append(&outline.border_mesh_2d(0.02)?);
+    /// assert!(mesh.triangle_count() > fill_triangles);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn border_mesh_2d(&self, width: f32) -> crate::error::Result<Mesh2D> {
+        if !width.is_finite() || width <= 0.0 {
+            return Err(FontMeshError::InvalidParameter(
+                "border width must be a positive finite value".to_string(),
+            ));
+        }
+
+        // Even-odd fill of the original plus offset contours is exactly the ring
+        let mut ring = self.clone();
+        ring.contours.extend(self.offset(width).contours);
+        crate::triangulate::triangulate(&ring)
+    }
+
+    /// Render every contour as a thick line instead of filling the outline
+    ///
+    /// Each contour is stroked with lyon's stroke tessellator, centered on
+    /// the contour, for outlined or "marker pen" text. Closed contours (all
+    /// glyph contours) use `line_join` at every corner; `line_cap` only
+    /// applies to the ends of open contours. Linearize the outline first.
+    ///
+    /// # Arguments
+    /// * `width` - The line width, positive and finite
+    /// * `line_join` - How segments meet, e.g. [`LineJoin::Round`](crate::LineJoin::Round)
+    /// * `line_cap` - How open contours end, e.g. [`LineCap::Round`](crate::LineCap::Round)
+    ///
+    /// # Errors
+    /// Returns [`FontMeshError::InvalidParameter`] if `width` isn't positive
+    /// and finite.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, LineCap, LineJoin, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let outline = Glyph::new(&face, 'A')?.with_subdivisions(20).to_outline()?;
+    ///
+    /// let mesh = outline.stroke_mesh_2d(0.03, LineJoin::Round, LineCap::Round)?;
+    /// assert!(mesh.triangle_count() > 0);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn stroke_mesh_2d(
+        &self,
+        width: f32,
+        line_join: crate::LineJoin,
+        line_cap: crate::LineCap,
+    ) -> crate::error::Result<Mesh2D> {
+        crate::triangulate::stroke(self, width, line_join, line_cap)
+    }
+
+    /// Compute the bounds of all contour points as `[[x_min, y_min], [x_max, y_max]]`
+    ///
+    /// Off-curve control points are included, so for an unlinearized outline
+    /// this may be slightly larger than the tight curve bounds.
+    /// Returns `None` if the outline has no points.
+    pub fn bounds(&self) -> Option<[[f32; 2]; 2]> {
+        let mut points = self.contours.iter().flat_map(|c| &c.points);
+        let first = points.next()?.point;
+        let (min, max) = points.fold((first, first), |(min, max), cp| {
+            (min.min(cp.point), max.max(cp.point))
+        });
+        Some([min.to_array(), max.to_array()])
+    }
+
+    /// Decompose the filled area into y-monotone polygons
+    ///
+    /// Every horizontal line crosses each returned polygon in at most one
+    /// interval, so the pieces can be filled by a simple scanline or split
+    /// into triangle strips without a general triangulator, e.g. for GPU
+    /// pipelines that don't use lyon. Polygons are counter-clockwise, and
+    /// together they cover exactly the even-odd fill of the outline.
+    ///
+    /// The decomposition sweeps upwards through the horizontal slabs between
+    /// vertex heights, joining each slab's trapezoids into the piece below
+    /// them wherever no vertex interrupts the span. Pieces are therefore
+    /// split at interior extrema (the tops and bottoms of counters, the
+    /// crotches of letters like 'A' or 'M') but are not guaranteed to be the
+    /// fewest possible. Expects a linearized outline without self
+    /// intersections; off-curve points are treated as polygon vertices.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, glyph::Glyph};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let outline = Glyph::new(&face, 'O')?.with_subdivisions(20).to_outline()?;
+    ///
+    /// // The ring splits at the top and bottom of its counter
+    /// let pieces = outline.to_monotone_polygons();
+    /// assert!(pieces.len() >= 4);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn to_monotone_polygons(&self) -> Vec<Vec<Vec2>> {
+        // Non-horizontal edges, stored bottom to top
+        let mut edges = Vec::new();
+        for contour in &self.contours {
+            let points = &contour.points;
+            for (i, start) in points.iter().enumerate() {
+                let (a, b) = (start.point, points[(i + 1) % points.len()].point);
+                if a.y < b.y {
+                    edges.push((a, b));
+                } else if a.y > b.y {
+                    edges.push((b, a));
+                }
+            }
+        }
+
+        let mut heights: Vec<f32> = edges.iter().flat_map(|(a, b)| [a.y, b.y]).collect();
+        heights.sort_by(f32::total_cmp);
+        heights.dedup();
+
+        // Exact at the endpoints, so a vertex evaluates identically from
+        // the slabs above and below it
+        let x_at = |(a, b): (Vec2, Vec2), y: f32| {
+            if y == a.y {
+                a.x
+            } else if y == b.y {
+                b.x
+            } else {
+                a.x + (b.x - a.x) * (y - a.y) / (b.y - a.y)
+            }
+        };
+
+        let mut finished = Vec::new();
+        let mut open: Vec<MonotonePiece> = Vec::new();
+
+        for slab in heights.windows(2) {
+            let (y0, y1) = (slab[0], slab[1]);
+            let mid = (y0 + y1) * 0.5;
+            let mut crossing: Vec<usize> = (0..edges.len())
+                .filter(|&i| edges[i].0.y <= y0 && edges[i].1.y >= y1)
+                .collect();
+            crossing.sort_by(|&i, &j| x_at(edges[i], mid).total_cmp(&x_at(edges[j], mid)));
+
+            let mut next_open = Vec::new();
+            // Even-odd: crossings pair up into filled spans
+            for pair in crossing.chunks_exact(2) {
+                let (left, right) = (pair[0], pair[1]);
+                let bottom = (x_at(edges[left], y0), x_at(edges[right], y0));
+                let top = (x_at(edges[left], y1), x_at(edges[right], y1));
+
+                let below = (bottom.0 < bottom.1)
+                    .then(|| open.iter().position(|piece| piece.top == bottom))
+                    .flatten();
+                let mut piece = match below {
+                    Some(index) => open.swap_remove(index),
+                    None => MonotonePiece {
+                        left: vec![Vec2::new(bottom.0, y0)],
+                        right: vec![Vec2::new(bottom.1, y0)],
+                        left_edge: usize::MAX,
+                        right_edge: usize::MAX,
+                        top: bottom,
+                    },
+                };
+                piece.extend(left, right, top, y1);
+                next_open.push(piece);
+            }
+
+            finished.append(&mut open);
+            open = next_open;
+        }
+        finished.append(&mut open);
+
+        finished
+            .into_iter()
+            .filter_map(|piece| {
+                // Counter-clockwise: up the right side, down the left
+                let mut polygon = piece.right;
+                polygon.extend(piece.left.into_iter().rev());
+                polygon.dedup();
+                if polygon.len() > 1 && polygon.first() == polygon.last() {
+                    polygon.pop();
+                }
+                (polygon.len() >= 3).then_some(polygon)
+            })
+            .collect()
+    }
+}
+
+/// A y-monotone polygon being built by [`Outline2D::to_monotone_polygons`]
+struct MonotonePiece {
+    /// Left chain, bottom to top
+    left: Vec<Vec2>,
+    /// Right chain, bottom to top
+    right: Vec<Vec2>,
+    left_edge: usize,
+    right_edge: usize,
+    /// Span `(left x, right x)` at the current top
+    top: (f32, f32),
+}
+
+impl MonotonePiece {
+    /// Grow the piece by one slab bounded by the given edges
+    fn extend(&mut self, left_edge: usize, right_edge: usize, top: (f32, f32), y: f32) {
+        // An edge continuing from the slab below just moves its end point
+        let left_top = Vec2::new(top.0, y);
+        if self.left_edge == left_edge {
+            *self.left.last_mut().unwrap() = left_top;
+        } else {
+            self.left.push(left_top);
+        }
+        let right_top = Vec2::new(top.1, y);
+        if self.right_edge == right_edge {
+            *self.right.last_mut().unwrap() = right_top;
+        } else {
+            self.right.push(right_top);
+        }
+        self.left_edge = left_edge;
+        self.right_edge = right_edge;
+        self.top = top;
+    }
 }
 
 impl Default for Outline2D {
@@ -134,6 +773,136 @@ impl Outline2D {
         let mesh_2d = self.triangulate()?;
         crate::extrude::extrude(&mesh_2d, self, depth)
     }
+
+    /// Sweep a circular tube along every contour, e.g. for neon-sign text
+    ///
+    /// See [`crate::extrude::sweep_tube`].
+    ///
+    /// # Arguments
+    /// * `radius` - The tube radius
+    /// * `radial_segments` - Number of vertices around the tube (at least 3)
+    #[inline]
+    pub fn sweep_tube(&self, radius: f32, radial_segments: u8) -> Mesh3D {
+        crate::extrude::sweep_tube(self, radius, radial_segments)
+    }
+}
+
+/// Builds an [`Outline2D`] from path commands, for procedural shapes
+///
+/// Uses the move/line/quad/curve/close vocabulary of ttf-parser's
+/// `OutlineBuilder`, so logos and icons can go through the same
+/// linearize → triangulate → extrude pipeline as glyphs. Coordinates are
+/// taken as given; glyph outlines are in em units (1.0 = one em).
+///
+/// Every `move_to` starts a new contour; drawing without one starts a
+/// contour at the origin. `close` marks the current contour closed; a
+/// contour left unclosed when the next `move_to` or `build` comes is kept as
+/// an open contour, which fills the same but gets no side wall along its
+/// missing last edge when extruded.
+///
+/// Example
+/// ```
+/// use fontmesh::types::PathBuilder;
+/// use fontmesh::linearize_outline;
+///
+/// // A unit square with a rounded top
+/// let outline = PathBuilder::new()
+///     .move_to(0.0, 0.0)
+///     .line_to(1.0, 0.0)
+///     .line_to(1.0, 1.0)
+///     .quad_to(0.5, 1.5, 0.0, 1.0)
+///     .close()
+///     .build();
+///
+/// let mesh = linearize_outline(outline, 20)?.to_mesh_3d(0.2)?;
+/// assert!(mesh.is_solid());
+/// # Ok::<(), fontmesh::FontMeshError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathBuilder {
+    outline: Outline2D,
+    current: Option<Contour>,
+}
+
+impl PathBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new contour at `(x, y)`
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.finish(false);
+        let mut contour = Contour::new(false);
+        contour.push_on_curve(Vec2::new(x, y));
+        self.current = Some(contour);
+        self
+    }
+
+    /// Add a straight line to `(x, y)`
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.contour().push_on_curve(Vec2::new(x, y));
+        self
+    }
+
+    /// Add a quadratic Bézier with control point `(x1, y1)` ending at `(x, y)`
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn quad_to(mut self, x1: f32, y1: f32, x: f32, y: f32) -> Self {
+        let contour = self.contour();
+        contour.push_off_curve(Vec2::new(x1, y1));
+        contour.push_on_curve(Vec2::new(x, y));
+        self
+    }
+
+    /// Add a cubic Bézier with control points `(x1, y1)` and `(x2, y2)`
+    /// ending at `(x, y)`
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn cubic_to(mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> Self {
+        let contour = self.contour();
+        contour.push_cubic_controls(Vec2::new(x1, y1), Vec2::new(x2, y2));
+        contour.push_on_curve(Vec2::new(x, y));
+        self
+    }
+
+    /// Close the current contour back to its first point
+    #[must_use = "builder methods are intended to be chained"]
+    pub fn close(mut self) -> Self {
+        self.finish(true);
+        self
+    }
+
+    /// Finish the path and return its contours
+    #[must_use]
+    pub fn build(mut self) -> Outline2D {
+        self.finish(false);
+        self.outline
+    }
+
+    /// The current contour, starting one at the origin if there is none
+    fn contour(&mut self) -> &mut Contour {
+        self.current.get_or_insert_with(|| {
+            let mut contour = Contour::new(false);
+            contour.push_on_curve(Vec2::ZERO);
+            contour
+        })
+    }
+
+    fn finish(&mut self, closed: bool) {
+        let Some(mut contour) = self.current.take() else {
+            return;
+        };
+        contour.closed = closed;
+        // An explicit line back to the start would be a zero-length edge
+        if closed && contour.points.len() > 1 {
+            let (first, last) = (contour.points[0], contour.points[contour.points.len() - 1]);
+            if last.on_curve && last.point == first.point {
+                contour.points.pop();
+            }
+        }
+        self.outline.add_contour(contour);
+    }
 }
 
 /// A 2D triangle mesh
@@ -142,6 +911,31 @@ impl Outline2D {
 pub struct Mesh2D {
     pub vertices: Vec<Point2D>,
     pub indices: Vec<u32>,
+    /// Whether each vertex is a point of the outline, or empty if unknown
+    ///
+    /// Filled in by [`crate::triangulate`]: `true` for vertices at outline
+    /// points, `false` for vertices added elsewhere, such as where
+    /// overlapping contours cross or by [`crate::triangulate_with_points`].
+    /// Lets rim effects and selective extrusion find the boundary without
+    /// recomputing it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub boundary_mask: Vec<bool>,
+    /// Index of the contour each vertex came from, or empty if unknown
+    ///
+    /// Filled in by [`crate::triangulate`] with the vertex's position in
+    /// [`Outline2D::contours`]. Where overlapping contours cross, the vertex
+    /// is tagged with one of them; points added by
+    /// [`crate::triangulate_with_points`] take the contour of the triangle
+    /// they split. Lets per-contour effects (coloring each stroke, animating
+    /// counters separately) work on the merged mesh.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub contour_ids: Vec<u32>,
 }
 
 impl Mesh2D {
@@ -150,9 +944,23 @@ impl Mesh2D {
         Self {
             vertices: Vec::new(),
             indices: Vec::new(),
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
         }
     }
 
+    /// Get the number of vertices in the mesh
+    #[must_use]
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Get the number of indices in the mesh, three per triangle
+    #[must_use]
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
     /// Get the number of triangles in the mesh
     #[must_use]
     pub fn triangle_count(&self) -> usize {
@@ -165,6 +973,125 @@ impl Mesh2D {
         self.vertices.is_empty()
     }
 
+    /// Get the axis-aligned bounding box as `(min, max)`
+    ///
+    /// An empty mesh returns a zero-sized box at the origin.
+    #[must_use]
+    pub fn aabb(&self) -> (Point2D, Point2D) {
+        match self.vertices.first() {
+            Some(&first) => self
+                .vertices
+                .iter()
+                .fold((first, first), |(min, max), &v| (min.min(v), max.max(v))),
+            None => (Vec2::ZERO, Vec2::ZERO),
+        }
+    }
+
+    /// Get the total area covered by the triangles
+    ///
+    /// Each triangle counts positively whichever way it winds, so for a
+    /// triangulated outline this is its filled area.
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        self.indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| self.vertices[i as usize]);
+                (b - a).perp_dot(c - a).abs() * 0.5
+            })
+            .sum()
+    }
+
+    /// Create a translated copy of this mesh, e.g. for a drop shadow
+    ///
+    /// Indices are unchanged, so the copy can be drawn on its own or appended
+    /// to another mesh after offsetting its indices.
+    ///
+    /// # Arguments
+    /// * `delta` - The offset applied to every vertex
+    #[must_use]
+    pub fn shadow(&self, delta: Vec2) -> Mesh2D {
+        Mesh2D {
+            vertices: self.vertices.iter().map(|&v| v + delta).collect(),
+            indices: self.indices.clone(),
+            boundary_mask: self.boundary_mask.clone(),
+            contour_ids: self.contour_ids.clone(),
+        }
+    }
+
+    /// Append another mesh's triangles to this one
+    ///
+    /// The other mesh's indices are shifted past this mesh's vertices. The
+    /// boundary mask and contour ids are kept only if both meshes have them;
+    /// the other mesh's contour ids are shifted past this mesh's so contours
+    /// stay distinct.
+    pub fn append(&mut self, other: &Mesh2D) {
+        if self.boundary_mask.len() == self.vertices.len()
+            && other.boundary_mask.len() == other.vertices.len()
+        {
+            self.boundary_mask.extend_from_slice(&other.boundary_mask);
+        } else {
+            self.boundary_mask.clear();
+        }
+        if self.contour_ids.len() == self.vertices.len()
+            && other.contour_ids.len() == other.vertices.len()
+        {
+            let base = self.contour_ids.iter().max().map_or(0, |&id| id + 1);
+            self.contour_ids
+                .extend(other.contour_ids.iter().map(|&id| base + id));
+        } else {
+            self.contour_ids.clear();
+        }
+        let base = self.vertices.len() as u32;
+        self.vertices.extend_from_slice(&other.vertices);
+        self.indices.extend(other.indices.iter().map(|&i| base + i));
+    }
+
+    /// Mirror the mesh by negating one coordinate, keeping its winding
+    ///
+    /// A reflection reverses the winding of every triangle, so each
+    /// triangle's last two indices are swapped to keep facing the same way.
+    /// [`Axis::X`] flips the mesh left to right, [`Axis::Y`] upside down;
+    /// [`Axis::Z`] leaves a 2D mesh unchanged.
+    ///
+    /// # Arguments
+    /// * `axis` - The coordinate to negate
+    pub fn mirror(&mut self, axis: Axis) {
+        let flip = match axis {
+            Axis::X => Vec2::new(-1.0, 1.0),
+            Axis::Y => Vec2::new(1.0, -1.0),
+            Axis::Z => return,
+        };
+        self.vertices.iter_mut().for_each(|v| *v *= flip);
+        flip_winding(&mut self.indices);
+    }
+
+    /// Check whether any hole of `outline` is covered by a triangle of this mesh
+    ///
+    /// Holes are the contours at odd nesting depth. For each hole a probe point
+    /// just inside it (next to its longest edge) is tested against every
+    /// triangle. A `true` result means the hole was filled as solid, the classic
+    /// "the O is a blob" failure caused by inconsistent contour winding.
+    ///
+    /// # Arguments
+    /// * `outline` - The outline this mesh was triangulated from
+    pub fn has_filled_holes(&self, outline: &Outline2D) -> bool {
+        let depths = outline.nesting_depths();
+
+        outline
+            .contours
+            .iter()
+            .zip(depths)
+            .filter(|(_, depth)| depth % 2 == 1)
+            .filter_map(|(contour, _)| hole_probe(contour))
+            .any(|probe| {
+                self.indices.chunks_exact(3).any(|tri| {
+                    let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| self.vertices[i as usize]);
+                    point_in_triangle(probe, a, b, c)
+                })
+            })
+    }
+
     /// Extrude this 2D mesh into a 3D mesh (fluent API)
     ///
     /// # Arguments
@@ -190,6 +1117,50 @@ impl Mesh2D {
     pub fn extrude(&self, outline: &Outline2D, depth: f32) -> crate::error::Result<Mesh3D> {
         crate::extrude::extrude(self, outline, depth)
     }
+
+    /// Lift this 2D mesh onto the plane at `z`, facing +Z
+    ///
+    /// Produces a flat [`Mesh3D`] with a `+Z` normal on every vertex, so 2D
+    /// text can share the vertex format and shaders of extruded text
+    /// without paying for side walls or a back face. Vertices and indices
+    /// are carried over one to one; triangles are wound counter-clockwise
+    /// when seen from +Z, matching the normals.
+    ///
+    /// # Arguments
+    /// * `z` - Height of the plane
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_2d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh_2d = char_to_mesh_2d(&face, 'A', 20)?;
+    ///
+    /// let flat = mesh_2d.to_mesh_3d_flat(0.0);
+    /// assert_eq!(flat.vertices.len(), mesh_2d.vertices.len());
+    /// assert_eq!(flat.triangle_count(), mesh_2d.triangle_count());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn to_mesh_3d_flat(&self, z: f32) -> Mesh3D {
+        let mut indices = self.indices.clone();
+        for tri in indices.chunks_exact_mut(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| self.vertices[i as usize]);
+            if (b - a).perp_dot(c - a) < 0.0 {
+                tri.swap(1, 2);
+            }
+        }
+
+        Mesh3D {
+            vertices: self.vertices.iter().map(|v| v.extend(z)).collect(),
+            normals: vec![Vec3::Z; self.vertices.len()],
+            indices,
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        }
+    }
 }
 
 impl Default for Mesh2D {
@@ -198,13 +1169,76 @@ impl Default for Mesh2D {
     }
 }
 
-/// A 3D triangle mesh with normals
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Mesh3D {
-    pub vertices: Vec<glam::Vec3>,
-    pub normals: Vec<glam::Vec3>,
+/// A point just inside a contour, next to the midpoint of its longest edge
+fn hole_probe(contour: &Contour) -> Option<Point2D> {
+    let n = contour.points.len();
+    if n < 3 {
+        return None;
+    }
+    let (p0, p1) = (0..n)
+        .map(|i| (contour.points[i].point, contour.points[(i + 1) % n].point))
+        .max_by(|a, b| {
+            (a.1 - a.0)
+                .length_squared()
+                .total_cmp(&(b.1 - b.0).length_squared())
+        })?;
+
+    let edge = p1 - p0;
+    let length = edge.length();
+    if length <= f32::EPSILON {
+        return None;
+    }
+    // The contour's interior is on the left for CCW contours, right for CW
+    let left = Vec2::new(-edge.y, edge.x) / length;
+    let inward = if contour.signed_area() > 0.0 {
+        left
+    } else {
+        -left
+    };
+    Some((p0 + p1) * 0.5 + inward * (length * 1e-2).min(1e-3))
+}
+
+/// Check whether `p` lies strictly inside triangle `abc` (either winding)
+#[inline]
+fn point_in_triangle(p: Point2D, a: Point2D, b: Point2D, c: Point2D) -> bool {
+    let d1 = (b - a).perp_dot(p - a);
+    let d2 = (c - b).perp_dot(p - b);
+    let d3 = (a - c).perp_dot(p - c);
+    (d1 > 0.0 && d2 > 0.0 && d3 > 0.0) || (d1 < 0.0 && d2 < 0.0 && d3 < 0.0)
+}
+
+/// A 3D triangle mesh with normals
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mesh3D {
+    pub vertices: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
     pub indices: Vec<u32>,
+    /// Texture coordinates, one per vertex, or empty if none were generated
+    ///
+    /// See [`crate::extrude::ExtrudeOptions`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub uvs: Vec<Vec2>,
+    /// Linear RGBA vertex colors, one per vertex, or empty if none were assigned
+    ///
+    /// See [`crate::layout::layout_text_colored`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub colors: Vec<[f32; 4]>,
+    /// Tangents for normal mapping, one per vertex, or empty if none were computed
+    ///
+    /// `xyz` is the unit tangent and `w` (±1) the handedness: the bitangent
+    /// is `normal.cross(xyz) * w`. See [`crate::extrude::compute_tangents`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tangents: Vec<Vec4>,
 }
 
 impl Mesh3D {
@@ -214,9 +1248,24 @@ impl Mesh3D {
             vertices: Vec::new(),
             normals: Vec::new(),
             indices: Vec::new(),
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
         }
     }
 
+    /// Get the number of vertices in the mesh
+    #[must_use]
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Get the number of indices in the mesh, three per triangle
+    #[must_use]
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
     /// Get the number of triangles in the mesh
     #[must_use]
     pub fn triangle_count(&self) -> usize {
@@ -228,6 +1277,684 @@ impl Mesh3D {
     pub fn is_empty(&self) -> bool {
         self.vertices.is_empty()
     }
+
+    /// Get the axis-aligned bounding box as `(min, max)`
+    ///
+    /// Useful for frustum culling and for placing meshes. An empty mesh
+    /// returns a zero-sized box at the origin.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'A', 5.0, 20)?;
+    /// let (min, max) = mesh.aabb();
+    /// assert_eq!(max.z - min.z, 5.0);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn aabb(&self) -> (Vec3, Vec3) {
+        match self.vertices.first() {
+            Some(&first) => self
+                .vertices
+                .iter()
+                .fold((first, first), |(min, max), &v| (min.min(v), max.max(v))),
+            None => (Vec3::ZERO, Vec3::ZERO),
+        }
+    }
+
+    /// Uniformly scale the mesh so its y-extent equals `target_height`
+    ///
+    /// A common last step when fitting laid-out text into a UI region. x is
+    /// scaled with y, preserving the aspect ratio, and the mesh is scaled
+    /// about the origin, so text laid out from the pen origin stays anchored
+    /// there. With `scale_depth` z is scaled too, keeping the proportions of
+    /// the whole solid; without it the extrusion depth stays as it was.
+    /// Normals are corrected for the non-uniform case. Does nothing if the
+    /// mesh has no height or `target_height` isn't positive and finite.
+    ///
+    /// # Arguments
+    /// * `target_height` - The y-extent after scaling
+    /// * `scale_depth` - Whether z is scaled along with x and y
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mut mesh = char_to_mesh_3d(&face, 'H', 0.1, 20)?;
+    /// mesh.scale_to_height(32.0, false);
+    /// let (min, max) = mesh.aabb();
+    /// assert!((max.y - min.y - 32.0).abs() < 1e-3);
+    /// assert!((max.z - min.z - 0.1).abs() < 1e-6);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn scale_to_height(&mut self, target_height: f32, scale_depth: bool) {
+        let (min, max) = self.aabb();
+        let height = max.y - min.y;
+        if height <= 0.0
+            || !height.is_finite()
+            || target_height <= 0.0
+            || !target_height.is_finite()
+        {
+            return;
+        }
+
+        let factor = target_height / height;
+        let scale = Vec3::new(factor, factor, if scale_depth { factor } else { 1.0 });
+        for v in &mut self.vertices {
+            *v *= scale;
+        }
+        if !scale_depth {
+            // Normals transform by the inverse scale
+            let inverse = scale.recip();
+            for n in &mut self.normals {
+                *n = (*n * inverse).normalize_or_zero();
+            }
+            // Tangents lie in the surface and transform with it
+            for t in &mut self.tangents {
+                *t = (t.truncate() * scale).normalize_or_zero().extend(t.w);
+            }
+        }
+    }
+
+    /// Create a translated copy of this mesh, e.g. for drop-shadow geometry
+    ///
+    /// Normals, indices, UVs, colors and tangents are unchanged, so the copy
+    /// can be drawn on its own or appended to another mesh after offsetting
+    /// its indices.
+    ///
+    /// # Arguments
+    /// * `delta` - The offset applied to every vertex
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    /// use glam::Vec3;
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'A', 0.1, 20)?;
+    /// let shadow = mesh.offset_copy(Vec3::new(0.02, -0.02, -0.05));
+    /// assert_eq!(shadow.triangle_count(), mesh.triangle_count());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn offset_copy(&self, delta: Vec3) -> Mesh3D {
+        Mesh3D {
+            vertices: self.vertices.iter().map(|&v| v + delta).collect(),
+            normals: self.normals.clone(),
+            indices: self.indices.clone(),
+            uvs: self.uvs.clone(),
+            colors: self.colors.clone(),
+            tangents: self.tangents.clone(),
+        }
+    }
+
+    /// Mirror the mesh by negating one coordinate of every vertex and normal
+    ///
+    /// A reflection turns the mesh inside out: negating coordinates alone
+    /// reverses the winding of every triangle, leaving the mirrored copy
+    /// back-facing. This also swaps each triangle's last two indices, so the
+    /// result stays front-facing with outward normals. UVs are kept, which
+    /// mirrors any texture along with the geometry.
+    ///
+    /// # Arguments
+    /// * `axis` - The coordinate to negate; [`Axis::X`] mirrors left to
+    ///   right across the plane `x = 0`
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d, types::Axis};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mut mesh = char_to_mesh_3d(&face, 'R', 0.1, 20)?;
+    ///
+    /// mesh.mirror(Axis::X);
+    /// assert!(mesh.is_solid());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn mirror(&mut self, axis: Axis) {
+        let flip = match axis {
+            Axis::X => Vec3::new(-1.0, 1.0, 1.0),
+            Axis::Y => Vec3::new(1.0, -1.0, 1.0),
+            Axis::Z => Vec3::new(1.0, 1.0, -1.0),
+        };
+        self.vertices.iter_mut().for_each(|v| *v *= flip);
+        self.normals.iter_mut().for_each(|n| *n *= flip);
+        // A reflection also reverses the tangent frame's handedness
+        self.tangents
+            .iter_mut()
+            .for_each(|t| *t = (t.truncate() * flip).extend(-t.w));
+        flip_winding(&mut self.indices);
+    }
+
+    /// Turn the mesh inside out: negate every normal and reverse the winding
+    ///
+    /// For meshes whose normals point inward, or to render text from the
+    /// inside, e.g. as an enclosing shell. Both are changed together so the
+    /// winding keeps agreeing with the normals; flipping only one would
+    /// leave them out of sync. Applying it twice restores the mesh.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d, SolidDefect};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mut mesh = char_to_mesh_3d(&face, 'O', 0.1, 20)?;
+    ///
+    /// mesh.flip_normals();
+    /// assert_eq!(mesh.solid_defect(), Some(SolidDefect::NonPositiveVolume));
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn flip_normals(&mut self) {
+        self.normals.iter_mut().for_each(|n| *n = -*n);
+        self.tangents.iter_mut().for_each(|t| t.w = -t.w);
+        flip_winding(&mut self.indices);
+    }
+
+    /// Displace the front cap along +Z by a height function of each vertex's xy
+    ///
+    /// For relief and procedurally textured letter faces: every vertex on
+    /// the front plane (the mesh's largest z) moves by `height(xy)`, then the
+    /// normals of the front cap are recomputed from its displaced triangles.
+    /// The top edges of the side walls move with the cap, so the mesh stays
+    /// closed; straight walls remain flat and keep their normals. Back cap
+    /// and the rest of the walls are untouched. The cap is only as finely
+    /// displaced as it is triangulated, so dense detail needs a dense cap.
+    /// Tangents no longer match the cap and are cleared; recompute them with
+    /// [`crate::extrude::compute_tangents`].
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mut mesh = char_to_mesh_3d(&face, 'O', 0.1, 20)?;
+    ///
+    /// // A gentle wave across the letter face
+    /// mesh.displace_front(|p| 0.02 * (p.x * 10.0).sin());
+    /// assert!(mesh.is_solid());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    pub fn displace_front(&mut self, height: impl Fn(Vec2) -> f32) {
+        let Some(front_z) = self.vertices.iter().map(|v| v.z).reduce(f32::max) else {
+            return;
+        };
+        let tolerance = 1e-6 * front_z.abs().max(1.0);
+        let on_front: Vec<bool> = self
+            .vertices
+            .iter()
+            .map(|v| (v.z - front_z).abs() <= tolerance)
+            .collect();
+
+        for (v, _) in self.vertices.iter_mut().zip(&on_front).filter(|(_, &f)| f) {
+            v.z += height(v.truncate());
+        }
+
+        // Cap triangles lie entirely on the front plane; wall triangles
+        // always reach back
+        let mut cap_normals = vec![Vec3::ZERO; self.vertices.len()];
+        for tri in self.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i as usize);
+            if !(on_front[a] && on_front[b] && on_front[c]) {
+                continue;
+            }
+            let [pa, pb, pc] = [a, b, c].map(|i| self.vertices[i]);
+            // Unnormalized, so larger triangles weigh more
+            let face_normal = (pb - pa).cross(pc - pa);
+            for i in [a, b, c] {
+                cap_normals[i] += face_normal;
+            }
+        }
+        for (normal, cap) in self.normals.iter_mut().zip(cap_normals) {
+            if let Some(cap) = cap.try_normalize() {
+                *normal = cap;
+            }
+        }
+        self.tangents.clear();
+    }
+
+    /// Make triangle winding and normals consistent and facing outward
+    ///
+    /// A robustness net for meshes from any source, e.g. built from outlines of
+    /// unknown winding. First every triangle is reoriented to agree with its
+    /// stored normals (see [`crate::orient_triangles`]). Then the signed volume
+    /// of the mesh is measured around its centroid: a negative volume means the
+    /// whole mesh is inside out, so every triangle is flipped and every normal
+    /// negated.
+    ///
+    /// The volume test is used instead of comparing each triangle against the
+    /// centroid directly, which would wrongly flip the inner walls of holes and
+    /// concave glyphs. It assumes the normals are consistent with each other
+    /// and the mesh is closed; open meshes with no volume are only reoriented.
+    pub fn ensure_outward_normals(&mut self) {
+        crate::extrude::orient_triangles(self);
+
+        if self.vertices.is_empty() {
+            return;
+        }
+        let centroid =
+            self.vertices.iter().fold(Vec3::ZERO, |sum, &v| sum + v) / self.vertices.len() as f32;
+
+        let volume: f32 = self
+            .indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let [a, b, c] =
+                    [tri[0], tri[1], tri[2]].map(|i| self.vertices[i as usize] - centroid);
+                a.dot(b.cross(c))
+            })
+            .sum();
+
+        if volume < 0.0 {
+            for tri in self.indices.chunks_exact_mut(3) {
+                tri.swap(1, 2);
+            }
+            for normal in &mut self.normals {
+                *normal = -*normal;
+            }
+            for tangent in &mut self.tangents {
+                tangent.w = -tangent.w;
+            }
+        }
+    }
+
+    /// Split triangle edges that pass through other vertices (T-junctions)
+    ///
+    /// A vertex lying on the inside of another triangle's edge, instead of at
+    /// one of its corners, leaves hairline cracks when rendered because the
+    /// two sides of the seam are rasterized differently. Each such edge is
+    /// split at the vertex, dividing its triangle in two with the same
+    /// winding, until no vertex used by the mesh lies on the interior of any
+    /// edge. Vertex attributes are untouched; only `indices` grows.
+    ///
+    /// # Arguments
+    /// * `epsilon` - How close to an edge (and how far from its endpoints) a
+    ///   vertex must be to count as lying on it
+    pub fn fix_t_junctions(&mut self, epsilon: f32) {
+        let mut referenced = self.indices.clone();
+        referenced.sort_unstable();
+        referenced.dedup();
+        let Some(&first) = referenced.first() else {
+            return;
+        };
+        let epsilon = epsilon.max(0.0);
+
+        // Bucket vertices in a uniform grid so each edge only tests nearby ones
+        let (min, max) = referenced.iter().fold(
+            (self.vertices[first as usize], self.vertices[first as usize]),
+            |(min, max), &i| {
+                let v = self.vertices[i as usize];
+                (min.min(v), max.max(v))
+            },
+        );
+        let cell_size = ((max - min).max_element() / (referenced.len() as f32).sqrt())
+            .max(epsilon)
+            .max(f32::MIN_POSITIVE);
+        let cell_of = |p: Vec3| ((p - min) / cell_size).floor().as_ivec3();
+        let mut grid: FxHashMap<IVec3, Vec<u32>> = FxHashMap::default();
+        for &i in &referenced {
+            grid.entry(cell_of(self.vertices[i as usize]))
+                .or_default()
+                .push(i);
+        }
+
+        let vertices = &self.vertices;
+        let on_edge = |a: u32, b: u32| -> Option<u32> {
+            let (pa, pb) = (vertices[a as usize], vertices[b as usize]);
+            let edge = pb - pa;
+            let length_sq = edge.length_squared();
+            if length_sq <= (2.0 * epsilon) * (2.0 * epsilon) {
+                return None;
+            }
+            let length = length_sq.sqrt();
+            let splits = |&v: &u32| {
+                if v == a || v == b {
+                    return false;
+                }
+                let p = vertices[v as usize];
+                let t = (p - pa).dot(edge) / length_sq;
+                t * length > epsilon
+                    && (1.0 - t) * length > epsilon
+                    && (pa + edge * t).distance_squared(p) <= epsilon * epsilon
+            };
+
+            let lo = cell_of(pa.min(pb) - Vec3::splat(epsilon));
+            let hi = cell_of(pa.max(pb) + Vec3::splat(epsilon));
+            let span = (hi - lo + IVec3::ONE).as_vec3();
+            if span.x * span.y * span.z > referenced.len() as f32 {
+                // Long edge: scanning every vertex is cheaper than every cell
+                return referenced.iter().copied().find(|v| splits(v));
+            }
+            for x in lo.x..=hi.x {
+                for y in lo.y..=hi.y {
+                    for z in lo.z..=hi.z {
+                        let found = grid
+                            .get(&IVec3::new(x, y, z))
+                            .and_then(|cell| cell.iter().copied().find(|v| splits(v)));
+                        if found.is_some() {
+                            return found;
+                        }
+                    }
+                }
+            }
+            None
+        };
+
+        let mut pending: Vec<[u32; 3]> = self
+            .indices
+            .chunks_exact(3)
+            .rev()
+            .map(|tri| [tri[0], tri[1], tri[2]])
+            .collect();
+        let mut indices = Vec::with_capacity(self.indices.len());
+
+        while let Some(tri) = pending.pop() {
+            let split =
+                (0..3).find_map(|edge| on_edge(tri[edge], tri[(edge + 1) % 3]).map(|v| (edge, v)));
+            match split {
+                Some((edge, v)) => {
+                    let [a, b, c] = [tri[edge], tri[(edge + 1) % 3], tri[(edge + 2) % 3]];
+                    pending.push([v, b, c]);
+                    pending.push([a, v, c]);
+                }
+                None => indices.extend_from_slice(&tri),
+            }
+        }
+
+        self.indices = indices;
+    }
+
+    /// Expand the index buffer into flat per-triangle vertex and normal arrays
+    ///
+    /// Triangle soup for STL-like formats and renderers without index
+    /// buffers: corner `k` of triangle `t` is element `3 * t + k` of both
+    /// arrays, so each has `triangle_count() * 3` entries. UVs, colors and
+    /// tangents are not expanded. [`Mesh3D::from_unindexed`] welds the
+    /// result back.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'A', 0.1, 20)?;
+    ///
+    /// let (vertices, normals) = mesh.to_unindexed();
+    /// assert_eq!(vertices.len(), mesh.triangle_count() * 3);
+    /// assert_eq!(normals.len(), vertices.len());
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn to_unindexed(&self) -> (Vec<Vec3>, Vec<Vec3>) {
+        let corners = &self.indices[..self.triangle_count() * 3];
+        let vertices = corners.iter().map(|&i| self.vertices[i as usize]).collect();
+        let normals = corners.iter().map(|&i| self.normals[i as usize]).collect();
+        (vertices, normals)
+    }
+
+    /// Build an indexed mesh from triangle soup, welding identical corners
+    ///
+    /// Every three entries form a triangle, as produced by
+    /// [`Mesh3D::to_unindexed`]; a trailing partial triangle is ignored.
+    /// Corners are merged only where both position and normal are exactly
+    /// equal, so hard edges stay hard. Vertices keep the order of their
+    /// first appearance.
+    ///
+    /// # Panics
+    /// If `vertices` and `normals` differ in length.
+    pub fn from_unindexed(vertices: &[Vec3], normals: &[Vec3]) -> Mesh3D {
+        assert_eq!(vertices.len(), normals.len(), "every corner needs a normal");
+        let corners = vertices.len() / 3 * 3;
+        let soup: Vec<(Vec3, Vec3)> = vertices[..corners]
+            .iter()
+            .copied()
+            .zip(normals.iter().copied())
+            .collect();
+        let (indices, distinct) = weld(&soup, |(v, n)| [v.x, v.y, v.z, n.x, n.y, n.z]);
+
+        let mut mesh = Mesh3D::new();
+        (mesh.vertices, mesh.normals) = distinct.into_iter().unzip();
+        mesh.indices = indices;
+        mesh
+    }
+
+    /// Build a line-list index buffer of the mesh's edges for wireframe rendering
+    ///
+    /// Every triangle contributes its three edges; an edge shared by several
+    /// triangles is emitted only once. The result indexes the existing
+    /// `vertices`, two indices per line. Edges are deduplicated by vertex
+    /// index, so coincident but separate vertices (e.g. where side walls meet
+    /// the caps) still produce overlapping lines.
+    #[must_use]
+    pub fn wireframe_indices(&self) -> Vec<u32> {
+        let mut seen = FxHashSet::default();
+        let mut lines = Vec::with_capacity(self.indices.len());
+
+        for tri in self.indices.chunks_exact(3) {
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                if seen.insert((a.min(b), a.max(b))) {
+                    lines.push(a);
+                    lines.push(b);
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Pack every normal into two bytes with octahedral encoding
+    ///
+    /// The unit sphere is projected onto an octahedron that is unfolded into
+    /// a square, and the square quantized to signed 8-bit coordinates. Normals
+    /// take 2 bytes instead of 12, with an angular error under one degree.
+    /// Decode with [`Mesh3D::unpack_normal_oct`] or the equivalent shader
+    /// code; upload as `snorm8x2`. A zero normal encodes as `[0, 0]`.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, Mesh3D, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'O', 0.2, 20)?;
+    /// let packed = mesh.packed_normals_oct();
+    /// let n = Mesh3D::unpack_normal_oct(packed[0]);
+    /// assert!(n.dot(mesh.normals[0]) > 0.999);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn packed_normals_oct(&self) -> Vec<[i8; 2]> {
+        self.normals.iter().map(|&n| pack_normal_oct(n)).collect()
+    }
+
+    /// Decode a normal packed by [`Mesh3D::packed_normals_oct`]
+    ///
+    /// The result is unit length.
+    #[must_use]
+    pub fn unpack_normal_oct(packed: [i8; 2]) -> Vec3 {
+        let [x, y] = packed.map(|c| (c as f32 / 127.0).max(-1.0));
+        let z = 1.0 - x.abs() - y.abs();
+        let (x, y) = if z < 0.0 {
+            (
+                (1.0 - y.abs()) * sign_non_zero(x),
+                (1.0 - x.abs()) * sign_non_zero(y),
+            )
+        } else {
+            (x, y)
+        };
+        Vec3::new(x, y, z).normalize()
+    }
+
+    /// Check whether the mesh encloses a solid, e.g. before a CSG boolean
+    ///
+    /// See [`Mesh3D::solid_defect`] for what is checked.
+    #[must_use]
+    pub fn is_solid(&self) -> bool {
+        self.solid_defect().is_none()
+    }
+
+    /// Find the first reason the mesh isn't a valid solid, if any
+    ///
+    /// A solid is closed (every edge is shared by exactly two triangles),
+    /// manifold (no edge is shared by more), consistently wound (the two
+    /// triangles at each edge traverse it in opposite directions) and
+    /// encloses a positive volume, i.e. its triangles wind counter-clockwise
+    /// seen from outside. These are the conditions boolean (CSG) libraries
+    /// require of their inputs.
+    ///
+    /// Vertices are matched by exact position, so separate vertices at the
+    /// same point (where side walls meet the caps) count as one. Triangles
+    /// with two corners at the same point are ignored.
+    ///
+    /// Example
+    /// ```
+    /// use fontmesh::{Face, char_to_mesh_3d};
+    ///
+    /// let font_data = include_bytes!("../assets/test_font.ttf");
+    /// let face = Face::parse(font_data, 0)?;
+    /// let mesh = char_to_mesh_3d(&face, 'O', 0.2, 20)?;
+    /// assert_eq!(mesh.solid_defect(), None);
+    /// # Ok::<(), fontmesh::FontMeshError>(())
+    /// ```
+    #[must_use]
+    pub fn solid_defect(&self) -> Option<SolidDefect> {
+        if self.indices.len() < 3 {
+            return Some(SolidDefect::Empty);
+        }
+
+        let (welded, _) = weld(&self.vertices, Vec3::to_array);
+
+        // Uses of each undirected edge: (forward, backward) by welded id order
+        let mut edges: FxHashMap<(u32, u32), (u32, u32)> = FxHashMap::default();
+        let mut volume = 0.0f64;
+        for tri in self.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| welded[i as usize]);
+            if a == b || b == c || c == a {
+                continue;
+            }
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                let uses = edges.entry((from.min(to), from.max(to))).or_default();
+                if from < to {
+                    uses.0 += 1;
+                } else {
+                    uses.1 += 1;
+                }
+            }
+            let [p, q, r] = [tri[0], tri[1], tri[2]].map(|i| self.vertices[i as usize].as_dvec3());
+            volume += p.dot(q.cross(r));
+        }
+
+        if edges.values().any(|&(f, b)| f + b == 1) {
+            return Some(SolidDefect::Open);
+        }
+        if edges.values().any(|&(f, b)| f + b > 2) {
+            return Some(SolidDefect::NonManifold);
+        }
+        if edges.values().any(|&(f, b)| f != b) {
+            return Some(SolidDefect::InconsistentWinding);
+        }
+        if volume <= 0.0 {
+            return Some(SolidDefect::NonPositiveVolume);
+        }
+        None
+    }
+}
+
+/// Octahedral encoding of one normal, see [`Mesh3D::packed_normals_oct`]
+fn pack_normal_oct(normal: Vec3) -> [i8; 2] {
+    let l1 = normal.x.abs() + normal.y.abs() + normal.z.abs();
+    if l1 == 0.0 || !l1.is_finite() {
+        return [0, 0];
+    }
+    let n = normal / l1;
+    // Fold the lower hemisphere over the diagonals of the square
+    let (x, y) = if n.z < 0.0 {
+        (
+            (1.0 - n.y.abs()) * sign_non_zero(n.x),
+            (1.0 - n.x.abs()) * sign_non_zero(n.y),
+        )
+    } else {
+        (n.x, n.y)
+    };
+    [x, y].map(|c| (c.clamp(-1.0, 1.0) * 127.0).round() as i8)
+}
+
+/// Sign of `v` that treats zero as positive
+#[inline]
+fn sign_non_zero(v: f32) -> f32 {
+    if v < 0.0 {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+/// A coordinate axis, e.g. the one [`Mesh3D::mirror`] negates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+/// A named tessellation quality, as an alternative to raw subdivision counts
+///
+/// Each level maps to a number of subdivisions per curve; see
+/// [`Quality::subdivisions`]. [`Quality::Normal`] matches the default used
+/// when no quality is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Quality {
+    /// 10 subdivisions per curve, for small or distant text
+    Low,
+    /// 20 subdivisions per curve
+    #[default]
+    Normal,
+    /// 50 subdivisions per curve
+    High,
+    /// 100 subdivisions per curve, for large close-ups
+    Ultra,
+}
+
+impl Quality {
+    /// Number of subdivisions per curve for this quality
+    #[inline]
+    pub fn subdivisions(&self) -> u8 {
+        match self {
+            Quality::Low => 10,
+            Quality::Normal => 20,
+            Quality::High => 50,
+            Quality::Ultra => 100,
+        }
+    }
+}
+
+/// Reverse the winding of every triangle
+fn flip_winding(indices: &mut [u32]) {
+    indices.chunks_exact_mut(3).for_each(|tri| tri.swap(1, 2));
+}
+
+/// Why a [`Mesh3D`] fails [`Mesh3D::is_solid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolidDefect {
+    /// The mesh has no triangles
+    Empty,
+    /// An edge belongs to a single triangle, leaving a hole in the surface
+    Open,
+    /// An edge is shared by more than two triangles
+    NonManifold,
+    /// Two triangles sharing an edge are wound in opposite directions
+    InconsistentWinding,
+    /// The enclosed volume is zero or negative: the mesh is flat or inside out
+    NonPositiveVolume,
 }
 
 impl Default for Mesh3D {
@@ -235,3 +1962,384 @@ impl Default for Mesh3D {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_builder() {
+        let outline = PathBuilder::new()
+            .move_to(0.0, 0.0)
+            .line_to(2.0, 0.0)
+            .cubic_to(2.5, 0.5, 2.5, 1.5, 2.0, 2.0)
+            .line_to(0.0, 2.0)
+            .line_to(0.0, 0.0)
+            .close()
+            .move_to(0.5, 0.5)
+            .quad_to(1.0, 1.0, 1.5, 0.5)
+            .build();
+
+        assert_eq!(outline.contours.len(), 2);
+        let [square, arch] = [&outline.contours[0], &outline.contours[1]];
+        assert!(square.closed);
+        // The explicit line back to the start is dropped
+        assert_eq!(square.points.len(), 6);
+        assert_eq!(square.points.iter().filter(|cp| !cp.on_curve).count(), 2);
+        assert!(!arch.closed);
+        assert_eq!(arch.points.len(), 3);
+
+        let implicit = PathBuilder::new()
+            .line_to(1.0, 0.0)
+            .line_to(1.0, 1.0)
+            .close()
+            .build();
+        assert_eq!(implicit.contours[0].points[0].point, Vec2::ZERO);
+        assert!(PathBuilder::new().build().is_empty());
+    }
+
+    #[test]
+    fn test_to_mesh_3d_flat() {
+        // One triangle each way round
+        let mesh = Mesh2D {
+            vertices: vec![Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE],
+            indices: vec![0, 1, 2, 1, 2, 3],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+        let flat = mesh.to_mesh_3d_flat(2.0);
+
+        assert!(flat.vertices.iter().all(|v| v.z == 2.0));
+        assert!(flat.normals.iter().all(|&n| n == Vec3::Z));
+        for tri in flat.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| flat.vertices[i as usize]);
+            assert!((b - a).cross(c - a).z > 0.0);
+        }
+        assert_eq!(flat.indices[..3], [0, 1, 2]);
+        assert_eq!((flat.vertex_count(), flat.index_count()), (4, 6));
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.index_count(), 3 * mesh.triangle_count());
+    }
+
+    #[test]
+    fn test_mirror() {
+        let triangle = Mesh3D {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            normals: vec![Vec3::new(0.6, 0.0, 0.8); 3],
+            indices: vec![0, 1, 2],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+        let facing = |m: &Mesh3D| {
+            let [a, b, c] = [0, 1, 2].map(|i| m.vertices[m.indices[i] as usize]);
+            (b - a).cross(c - a).dot(m.normals[0])
+        };
+
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let mut mirrored = triangle.clone();
+            mirrored.mirror(axis);
+            assert!(facing(&mirrored) > 0.0, "{:?}", axis);
+        }
+        let mut mirrored = triangle.clone();
+        mirrored.mirror(Axis::X);
+        assert_eq!(mirrored.vertices[1], Vec3::NEG_X);
+        assert_eq!(mirrored.normals[0], Vec3::new(-0.6, 0.0, 0.8));
+
+        let mut flat = Mesh2D {
+            vertices: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
+            indices: vec![0, 1, 2],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+        flat.mirror(Axis::Y);
+        assert_eq!(flat.vertices[2], Vec2::NEG_Y);
+        assert_eq!(flat.indices, [0, 2, 1]);
+        let [a, b, c] = [0, 1, 2].map(|i| flat.vertices[flat.indices[i] as usize]);
+        assert!((b - a).perp_dot(c - a) > 0.0);
+    }
+
+    #[test]
+    fn test_aabb() {
+        assert_eq!(Mesh2D::new().aabb(), (Vec2::ZERO, Vec2::ZERO));
+        assert_eq!(Mesh3D::new().aabb(), (Vec3::ZERO, Vec3::ZERO));
+
+        let mesh = Mesh2D {
+            vertices: vec![
+                Vec2::new(0.5, -1.0),
+                Vec2::new(2.0, 3.0),
+                Vec2::new(-1.0, 0.0),
+            ],
+            indices: vec![0, 1, 2],
+            boundary_mask: Vec::new(),
+            contour_ids: Vec::new(),
+        };
+        assert_eq!(mesh.aabb(), (Vec2::new(-1.0, -1.0), Vec2::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn test_wireframe_indices_dedup_shared_edges() {
+        let mesh = Mesh3D {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::ONE, Vec3::Y],
+            normals: vec![Vec3::Z; 4],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+
+        let lines = mesh.wireframe_indices();
+        // Two triangles sharing the 0-2 diagonal: 5 unique edges
+        assert_eq!(lines.len(), 10);
+    }
+
+    #[test]
+    fn test_offset_copy() {
+        let mesh = Mesh3D {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            normals: vec![Vec3::Z; 3],
+            indices: vec![0, 1, 2],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+
+        let delta = Vec3::new(0.1, -0.2, -0.5);
+        let copy = mesh.offset_copy(delta);
+        assert_eq!(copy.vertices[1], Vec3::X + delta);
+        assert_eq!(copy.normals, mesh.normals);
+        assert_eq!(copy.indices, mesh.indices);
+
+        let (min, max) = mesh.aabb();
+        assert_eq!(copy.aabb(), (min + delta, max + delta));
+    }
+
+    #[test]
+    fn test_fix_t_junctions() {
+        // Two triangles above the x axis meet at (1, 0), which lies on the
+        // long edge of the triangle below
+        let mut mesh = Mesh3D {
+            vertices: vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(2.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(1.0, -1.0, 0.0),
+            ],
+            normals: vec![Vec3::Z; 5],
+            indices: vec![0, 2, 3, 2, 1, 3, 0, 4, 1],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+
+        mesh.fix_t_junctions(1e-5);
+
+        assert_eq!(mesh.triangle_count(), 4);
+        for tri in mesh.indices.chunks_exact(3) {
+            assert!(!(tri.contains(&0) && tri.contains(&1)));
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+            assert!((b - a).cross(c - a).z > 0.0);
+        }
+
+        // Already conforming: nothing changes
+        let before = mesh.indices.clone();
+        mesh.fix_t_junctions(1e-5);
+        assert_eq!(mesh.indices, before);
+    }
+
+    #[test]
+    fn test_packed_normals_oct_round_trip() {
+        let mut normals = vec![
+            Vec3::X,
+            Vec3::NEG_X,
+            Vec3::Y,
+            Vec3::NEG_Y,
+            Vec3::Z,
+            Vec3::NEG_Z,
+        ];
+        // A spiral over the whole sphere
+        for i in 0..500 {
+            let z = 1.0 - (i as f32 + 0.5) / 250.0;
+            let r = (1.0 - z * z).sqrt();
+            let phi = i as f32 * 2.399_963;
+            normals.push(Vec3::new(r * phi.cos(), r * phi.sin(), z));
+        }
+        let mesh = Mesh3D {
+            vertices: vec![Vec3::ZERO; normals.len()],
+            normals: normals.clone(),
+            indices: Vec::new(),
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+
+        let packed = mesh.packed_normals_oct();
+        assert_eq!(packed.len(), normals.len());
+        let max_error = 1.0f32.to_radians().cos();
+        for (&n, &p) in normals.iter().zip(&packed) {
+            let decoded = Mesh3D::unpack_normal_oct(p);
+            assert!((decoded.length() - 1.0).abs() < 1e-5);
+            assert!(decoded.dot(n) > max_error, "{n} decoded as {decoded}");
+        }
+
+        assert_eq!(pack_normal_oct(Vec3::ZERO), [0, 0]);
+        assert_eq!(Mesh3D::unpack_normal_oct([0, 0]), Vec3::Z);
+    }
+
+    #[test]
+    fn test_self_intersections() {
+        let contour = |closed: bool, points: &[(f32, f32)]| {
+            let mut contour = Contour::new(closed);
+            for &(x, y) in points {
+                contour.push_on_curve(Vec2::new(x, y));
+            }
+            contour
+        };
+
+        assert!(square(0.0, 1.0, false).self_intersections().is_empty());
+
+        // Repeated points don't make neighbouring edges count as crossing
+        let repeated = contour(
+            true,
+            &[(0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        );
+        assert!(repeated.self_intersections().is_empty());
+
+        // A vertex touching a non-adjacent edge
+        let pinched = contour(
+            true,
+            &[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (1.0, 0.0), (0.0, 2.0)],
+        );
+        assert_eq!(pinched.self_intersections(), vec![Vec2::new(1.0, 0.0); 2]);
+
+        // The closing edge only counts for closed contours
+        let zigzag = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (1.0, -1.0)];
+        assert_eq!(
+            contour(false, &zigzag).self_intersections(),
+            vec![Vec2::new(4.0 / 3.0, 0.0)]
+        );
+        assert_eq!(contour(true, &zigzag).self_intersections().len(), 1);
+    }
+
+    #[test]
+    fn test_scale_to_height() {
+        // A 1 x 2 x 1 box corner with a slanted normal
+        let mesh = Mesh3D {
+            vertices: vec![
+                Vec3::new(0.0, 0.0, -0.5),
+                Vec3::new(1.0, 0.0, 0.5),
+                Vec3::new(0.0, 2.0, 0.5),
+            ],
+            normals: vec![Vec3::new(1.0, 0.0, 1.0).normalize(); 3],
+            indices: vec![0, 1, 2],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+
+        let mut scaled = mesh.clone();
+        scaled.scale_to_height(4.0, true);
+        assert_eq!(
+            scaled.aabb(),
+            (Vec3::new(0.0, 0.0, -1.0), Vec3::new(2.0, 4.0, 1.0))
+        );
+        assert_eq!(scaled.normals, mesh.normals);
+
+        let mut flat = mesh.clone();
+        flat.scale_to_height(4.0, false);
+        assert_eq!(
+            flat.aabb(),
+            (Vec3::new(0.0, 0.0, -0.5), Vec3::new(2.0, 4.0, 0.5))
+        );
+        // The surface is shallower in x, so its normal tilts toward z
+        let expected = Vec3::new(0.5, 0.0, 1.0).normalize();
+        assert!(flat.normals[0].distance(expected) < 1e-6);
+
+        let mut unchanged = mesh.clone();
+        unchanged.scale_to_height(-1.0, true);
+        unchanged.scale_to_height(f32::NAN, true);
+        assert_eq!(unchanged.vertices, mesh.vertices);
+    }
+
+    #[test]
+    fn test_solid_defect() {
+        // A tetrahedron wound counter-clockwise from outside, with the apex
+        // duplicated so welding by position is exercised
+        let tetrahedron = Mesh3D {
+            vertices: vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(0.0, 0.0, 1.0),
+            ],
+            normals: vec![Vec3::Z; 5],
+            indices: vec![0, 2, 1, 0, 1, 3, 1, 2, 4, 2, 0, 4],
+            uvs: Vec::new(),
+            colors: Vec::new(),
+            tangents: Vec::new(),
+        };
+        assert!(tetrahedron.is_solid());
+
+        let mut open = tetrahedron.clone();
+        open.indices.truncate(9);
+        assert_eq!(open.solid_defect(), Some(SolidDefect::Open));
+
+        let mut flipped_one = tetrahedron.clone();
+        flipped_one.indices.swap(0, 1);
+        assert_eq!(
+            flipped_one.solid_defect(),
+            Some(SolidDefect::InconsistentWinding)
+        );
+
+        let mut inside_out = tetrahedron.clone();
+        for tri in inside_out.indices.chunks_exact_mut(3) {
+            tri.swap(1, 2);
+        }
+        assert_eq!(
+            inside_out.solid_defect(),
+            Some(SolidDefect::NonPositiveVolume)
+        );
+
+        let mut doubled = tetrahedron.clone();
+        doubled.indices.extend_from_slice(&[0, 1, 3, 0, 3, 1]);
+        assert_eq!(doubled.solid_defect(), Some(SolidDefect::NonManifold));
+
+        assert_eq!(Mesh3D::new().solid_defect(), Some(SolidDefect::Empty));
+    }
+
+    #[test]
+    fn test_to_monotone_polygons() {
+        let square = |min, max| square(min, max, true);
+        let mut outline = Outline2D::new();
+        outline.add_contour(square(0.0, 3.0));
+        outline.add_contour(square(1.0, 2.0));
+
+        // Below, left of, right of and above the hole
+        let pieces = outline.to_monotone_polygons();
+        assert_eq!(pieces.len(), 4);
+
+        let mut total_area = 0.0;
+        for polygon in &pieces {
+            let mut contour = Contour::new(true);
+            for &p in polygon {
+                contour.push_on_curve(p);
+            }
+            let area = contour.signed_area();
+            assert!(area > 0.0, "polygon {:?} is not counter-clockwise", polygon);
+            total_area += area;
+
+            // y changes direction exactly twice around a monotone polygon
+            let dy: Vec<f32> = (0..polygon.len())
+                .map(|i| polygon[(i + 1) % polygon.len()].y - polygon[i].y)
+                .filter(|dy| *dy != 0.0)
+                .collect();
+            let turns = (0..dy.len())
+                .filter(|&i| (dy[i] > 0.0) != (dy[(i + 1) % dy.len()] > 0.0))
+                .count();
+            assert_eq!(turns, 2);
+        }
+        assert!((total_area - 8.0).abs() < 1e-5);
+    }
+}
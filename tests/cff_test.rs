@@ -0,0 +1,220 @@
+mod common;
+
+use common::{cff2, cs_int, fvar, FontBuilder};
+use fontmesh::glyph::Glyph;
+use fontmesh::{char_to_mesh_3d, linearize_outline, Face, Tag};
+
+/// Push `default` with a single delta for the one variation region, blended
+fn blended(cs: &mut Vec<u8>, default: i16, delta: i16) {
+    cs_int(cs, default);
+    cs_int(cs, delta);
+    cs_int(cs, 1);
+    cs.push(16); // blend
+}
+
+/// A CFF2 variable font whose 'o' is a circle of radius 200 at wght=400,
+/// growing to radius 300 at wght=900
+fn variable_cff2_font() -> Vec<u8> {
+    let mut circle = Vec::new();
+    blended(&mut circle, 700, 100);
+    cs_int(&mut circle, 400);
+    circle.push(21); // rmoveto
+
+    // Quarter arcs as (dx, dy) pairs at radius 200 with deltas to radius 300
+    let arcs: [[(i16, i16); 6]; 4] = [
+        [(0, 0), (110, 56), (-90, -44), (90, 44), (-110, -56), (0, 0)],
+        [
+            (-110, -56),
+            (0, 0),
+            (-90, -44),
+            (-90, -44),
+            (0, 0),
+            (-110, -56),
+        ],
+        [(0, 0), (-110, -56), (90, 44), (-90, -44), (110, 56), (0, 0)],
+        [(110, 56), (0, 0), (90, 44), (90, 44), (0, 0), (110, 56)],
+    ];
+    for arc in arcs {
+        for (default, delta) in arc {
+            blended(&mut circle, default, delta);
+        }
+        circle.push(8); // rrcurveto
+    }
+
+    FontBuilder::new(1000, 2)
+        .map('o', 1)
+        .table(b"CFF2", cff2(&[Vec::new(), circle]))
+        .table(b"fvar", fvar(b"wght", 100.0, 400.0, 900.0))
+        .build()
+}
+
+#[test]
+fn test_cff2_variable_instance() {
+    let data = variable_cff2_font();
+    let mut face = Face::parse(&data, 0).expect("synthetic font should parse");
+    assert!(face.tables().cff2.is_some());
+
+    let regular = char_to_mesh_3d(&face, 'o', 1.0, 20).unwrap();
+    assert!(face
+        .set_variation(Tag::from_bytes(b"wght"), 900.0)
+        .is_some());
+    let bold = char_to_mesh_3d(&face, 'o', 1.0, 20).unwrap();
+
+    let (min, max) = regular.aabb();
+    assert!(
+        (max.x - min.x - 0.4).abs() < 0.01,
+        "width {}",
+        max.x - min.x
+    );
+    assert!((max.y - min.y - 0.4).abs() < 0.01);
+
+    let (min, max) = bold.aabb();
+    assert!(
+        (max.x - min.x - 0.6).abs() < 0.01,
+        "width {}",
+        max.x - min.x
+    );
+    assert!((max.y - min.y - 0.6).abs() < 0.01);
+    assert!((min.x - 0.2).abs() < 0.01 && (min.y - 0.1).abs() < 0.01);
+}
+
+/// A CFF2 variable font whose 'l' is a pixel-style stroke of two stacked
+/// squares: the variation adds a single unit to some moves, so intermediate
+/// instances land between grid lines
+fn pixel_cff2_font() -> Vec<u8> {
+    let mut glyph = Vec::new();
+    cs_int(&mut glyph, 100);
+    cs_int(&mut glyph, 100);
+    glyph.push(21); // rmoveto
+    for (dx, dy, delta) in [(300, 0, 1), (0, 300, 1), (-300, 0, -1)] {
+        blended(&mut glyph, dx, if dx == 0 { 0 } else { delta });
+        blended(&mut glyph, dy, if dy == 0 { 0 } else { delta });
+        glyph.push(5); // rlineto
+    }
+    // The upper square shares the lower one's top edge
+    cs_int(&mut glyph, 0);
+    cs_int(&mut glyph, 0);
+    glyph.push(21); // rmoveto
+    for (dx, dy, delta) in [(300, 0, 1), (0, 300, 1), (-300, 0, -1)] {
+        blended(&mut glyph, dx, if dx == 0 { 0 } else { delta });
+        blended(&mut glyph, dy, if dy == 0 { 0 } else { delta });
+        glyph.push(5); // rlineto
+    }
+
+    FontBuilder::new(1000, 2)
+        .map('l', 1)
+        .table(b"CFF2", cff2(&[Vec::new(), glyph]))
+        .table(b"fvar", fvar(b"wght", 100.0, 400.0, 900.0))
+        .build()
+}
+
+#[test]
+fn test_outline_rounded_snaps_to_font_units() {
+    let data = pixel_cff2_font();
+    let mut face = Face::parse(&data, 0).expect("synthetic font should parse");
+    // Halfway to the region peak: every delta contributes half a unit
+    face.set_variation(Tag::from_bytes(b"wght"), 650.0).unwrap();
+    let glyph = Glyph::new(&face, 'l').unwrap();
+
+    let on_grid = |v: f32| (v * 1000.0 - (v * 1000.0).round()).abs() < 1e-3;
+    let raw = glyph.outline().unwrap();
+    assert!(raw
+        .contours
+        .iter()
+        .flat_map(|c| &c.points)
+        .any(|p| !on_grid(p.point.x) || !on_grid(p.point.y)));
+
+    let rounded = glyph.outline_rounded().unwrap();
+    assert_eq!(rounded.contours.len(), 2);
+    for contour in &rounded.contours {
+        let n = contour.points.len();
+        for i in 0..n {
+            let a = contour.points[i].point;
+            let b = contour.points[(i + 1) % n].point;
+            assert!(on_grid(a.x) && on_grid(a.y), "{a}");
+            // Every edge is exactly axis-aligned
+            assert!(a.x == b.x || a.y == b.y, "{a} -> {b}");
+        }
+    }
+    // The shared edge is bit-identical in both contours
+    let top_of_lower: Vec<_> = rounded.contours[0]
+        .points
+        .iter()
+        .map(|p| p.point.y)
+        .collect();
+    let bottom_of_upper: Vec<_> = rounded.contours[1]
+        .points
+        .iter()
+        .map(|p| p.point.y)
+        .collect();
+    let shared = top_of_lower.iter().copied().fold(f32::MIN, f32::max);
+    assert_eq!(
+        shared,
+        bottom_of_upper.iter().copied().fold(f32::MAX, f32::min)
+    );
+
+    // The squares' caps meet without a gap: shared corners are one vertex
+    // and the triangles exactly cover the bounding box
+    let mesh = linearize_outline(rounded, 20)
+        .unwrap()
+        .triangulate()
+        .unwrap();
+    assert_eq!(mesh.vertices.len(), 6);
+    let area = mesh.area();
+    let (min, max) = mesh.aabb();
+    assert!((area - (max.x - min.x) * (max.y - min.y)).abs() < 1e-6);
+}
+
+#[test]
+fn test_cubic_curves_follow_the_circle() {
+    let data = variable_cff2_font();
+    let face = Face::parse(&data, 0).expect("synthetic font should parse");
+    let glyph = Glyph::new(&face, 'o').unwrap();
+
+    // Four cubic arcs of radius 200 units around (500, 400), whose control
+    // points keep them within half a unit of the true circle
+    let center = glam::Vec2::new(0.5, 0.4);
+    for subdivisions in [8, 20, 64] {
+        let outline = glyph.linearize_with(subdivisions).unwrap();
+        let points: Vec<_> = outline.contours[0].points.iter().map(|p| p.point).collect();
+        assert!(points.len() >= 8, "{} points", points.len());
+        for p in &points {
+            let radius = p.distance(center);
+            assert!(
+                (radius - 0.2).abs() < 5e-4,
+                "{p} is {radius} from the center"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bounds_beyond_i16_coordinates() {
+    // A square from x = 30000 to 35000 units: ttf-parser draws it but can't
+    // store its box in i16, so the bounds are measured from the points
+    let mut glyph = Vec::new();
+    cs_int(&mut glyph, 30000);
+    cs_int(&mut glyph, 0);
+    glyph.push(21); // rmoveto
+    for (dx, dy) in [(5000, 0), (0, 1000), (-5000, 0)] {
+        cs_int(&mut glyph, dx);
+        cs_int(&mut glyph, dy);
+        glyph.push(5); // rlineto
+    }
+    let data = FontBuilder::new(1000, 2)
+        .map('x', 1)
+        .table(b"CFF2", cff2(&[Vec::new(), glyph]))
+        .build();
+    let face = Face::parse(&data, 0).expect("synthetic font should parse");
+    assert_eq!(
+        face.glyph_bounding_box(face.glyph_index('x').unwrap()),
+        None
+    );
+
+    let glyph = Glyph::new(&face, 'x').unwrap();
+    let [min, max] = glyph.bounds().unwrap();
+    for (got, want) in [min, max].iter().flatten().zip([30.0, 0.0, 35.0, 1.0]) {
+        assert!((got - want).abs() < 1e-4, "{got} != {want}");
+    }
+    assert!(!glyph.is_whitespace());
+}
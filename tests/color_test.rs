@@ -0,0 +1,25 @@
+//! Tests for color font tables
+
+mod common;
+
+use common::{cpal, FontBuilder};
+use fontmesh::{palette, Face};
+
+#[test]
+fn test_palette() {
+    let palettes = vec![
+        vec![[255, 0, 0, 255], [0, 128, 255, 64]],
+        vec![[10, 20, 30, 40], [50, 60, 70, 80]],
+    ];
+    let data = FontBuilder::new(1000, 1)
+        .table(b"CPAL", cpal(&palettes))
+        .build();
+    let face = Face::parse(&data, 0).unwrap();
+
+    assert_eq!(palette(&face, 0).as_ref(), Some(&palettes[0]));
+    assert_eq!(palette(&face, 1).as_ref(), Some(&palettes[1]));
+    assert_eq!(palette(&face, 2), None);
+
+    let plain = FontBuilder::new(1000, 1).build();
+    assert_eq!(palette(&Face::parse(&plain, 0).unwrap(), 0), None);
+}
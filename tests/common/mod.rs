@@ -0,0 +1,318 @@
+//! Minimal in-memory font builder for tests
+//!
+//! Builds just enough of an OpenType file for ttf-parser to load it, so tests
+//! can cover font features (CFF2, unusual units per em, ...) that the bundled
+//! test fonts don't have.
+
+#![allow(dead_code)]
+
+/// A glyph outline for the `glyf` table: contours of `(x, y, on_curve)` points
+pub type GlyfContours = Vec<Vec<(i16, i16, bool)>>;
+
+/// A glyph with one clockwise square contour from `(0, 0)` to `(size, size)`
+pub fn square_glyph(size: i16) -> GlyfContours {
+    vec![vec![
+        (0, 0, true),
+        (0, size, true),
+        (size, size, true),
+        (size, 0, true),
+    ]]
+}
+
+/// Assembles font tables into an sfnt file
+pub struct FontBuilder {
+    units_per_em: u16,
+    num_glyphs: u16,
+    cmap: Vec<(char, u16)>,
+    tables: Vec<([u8; 4], Vec<u8>)>,
+}
+
+impl FontBuilder {
+    pub fn new(units_per_em: u16, num_glyphs: u16) -> Self {
+        Self {
+            units_per_em,
+            num_glyphs,
+            cmap: Vec::new(),
+            tables: Vec::new(),
+        }
+    }
+
+    /// Map a character to a glyph ID
+    pub fn map(mut self, c: char, glyph_id: u16) -> Self {
+        self.cmap.push((c, glyph_id));
+        self
+    }
+
+    /// Add a raw table
+    pub fn table(mut self, tag: &[u8; 4], data: Vec<u8>) -> Self {
+        self.tables.push((*tag, data));
+        self
+    }
+
+    /// Add `glyf` + `loca` tables with one entry per glyph
+    pub fn glyf(self, glyphs: &[GlyfContours]) -> Self {
+        let mut glyf = Vec::new();
+        let mut loca = Vec::new();
+
+        for contours in glyphs {
+            loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+            if contours.is_empty() {
+                continue;
+            }
+
+            let points: Vec<_> = contours.iter().flatten().copied().collect();
+            let x_min = points.iter().map(|p| p.0).min().unwrap();
+            let y_min = points.iter().map(|p| p.1).min().unwrap();
+            let x_max = points.iter().map(|p| p.0).max().unwrap();
+            let y_max = points.iter().map(|p| p.1).max().unwrap();
+
+            push_i16(&mut glyf, contours.len() as i16);
+            for v in [x_min, y_min, x_max, y_max] {
+                push_i16(&mut glyf, v);
+            }
+            let mut end = 0u16;
+            for contour in contours {
+                end += contour.len() as u16;
+                push_u16(&mut glyf, end - 1);
+            }
+            push_u16(&mut glyf, 0); // instructionLength
+
+            // Every coordinate is written as a full 16-bit delta
+            glyf.extend(points.iter().map(|p| p.2 as u8));
+            let (mut px, mut py) = (0i16, 0i16);
+            for p in &points {
+                push_i16(&mut glyf, p.0 - px);
+                px = p.0;
+            }
+            for p in &points {
+                push_i16(&mut glyf, p.1 - py);
+                py = p.1;
+            }
+            while glyf.len() % 4 != 0 {
+                glyf.push(0);
+            }
+        }
+        loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+
+        self.table(b"glyf", glyf).table(b"loca", loca)
+    }
+
+    /// Serialize the font
+    pub fn build(self) -> Vec<u8> {
+        let is_cff = self
+            .tables
+            .iter()
+            .any(|(tag, _)| tag == b"CFF " || tag == b"CFF2");
+
+        let mut head = Vec::new();
+        push_u32(&mut head, 0x0001_0000); // version
+        push_u32(&mut head, 0x0001_0000); // fontRevision
+        push_u32(&mut head, 0); // checksumAdjustment
+        push_u32(&mut head, 0x5F0F_3CF5); // magicNumber
+        push_u16(&mut head, 0); // flags
+        push_u16(&mut head, self.units_per_em);
+        head.extend_from_slice(&[0; 16]); // created, modified
+        for v in [0, 0, self.units_per_em as i16, self.units_per_em as i16] {
+            push_i16(&mut head, v);
+        }
+        push_u16(&mut head, 0); // macStyle
+        push_u16(&mut head, 8); // lowestRecPPEM
+        push_i16(&mut head, 2); // fontDirectionHint
+        push_i16(&mut head, 1); // indexToLocFormat (long)
+        push_i16(&mut head, 0); // glyphDataFormat
+
+        let mut hhea = Vec::new();
+        push_u32(&mut hhea, 0x0001_0000);
+        push_i16(&mut hhea, (self.units_per_em as f32 * 0.8) as i16); // ascender
+        push_i16(&mut hhea, -((self.units_per_em as f32 * 0.2) as i16)); // descender
+        hhea.extend_from_slice(&[0; 26]);
+        push_u16(&mut hhea, self.num_glyphs); // numberOfHMetrics
+
+        let mut hmtx = Vec::new();
+        for _ in 0..self.num_glyphs {
+            push_u16(&mut hmtx, self.units_per_em / 2);
+            push_i16(&mut hmtx, 0);
+        }
+
+        let mut maxp = Vec::new();
+        push_u32(&mut maxp, 0x0000_5000);
+        push_u16(&mut maxp, self.num_glyphs);
+
+        let cmap = build_cmap(&self.cmap);
+
+        let mut tables = self.tables;
+        tables.push((*b"head", head));
+        tables.push((*b"hhea", hhea));
+        tables.push((*b"hmtx", hmtx));
+        tables.push((*b"maxp", maxp));
+        tables.push((*b"cmap", cmap));
+        tables.sort_by_key(|(tag, _)| *tag);
+
+        let mut out = Vec::new();
+        push_u32(&mut out, if is_cff { 0x4F54_544F } else { 0x0001_0000 });
+        push_u16(&mut out, tables.len() as u16);
+        out.extend_from_slice(&[0; 6]); // searchRange, entrySelector, rangeShift
+
+        let mut offset = 12 + 16 * tables.len();
+        for (tag, data) in &tables {
+            out.extend_from_slice(tag);
+            push_u32(&mut out, 0); // checksum
+            push_u32(&mut out, offset as u32);
+            push_u32(&mut out, data.len() as u32);
+            offset += (data.len() + 3) & !3;
+        }
+        for (_, data) in &tables {
+            out.extend_from_slice(data);
+            while out.len() % 4 != 0 {
+                out.push(0);
+            }
+        }
+        out
+    }
+}
+
+/// Build an `fvar` table with a single axis and no named instances
+pub fn fvar(tag: &[u8; 4], min: f32, default: f32, max: f32) -> Vec<u8> {
+    let mut fvar = Vec::new();
+    push_u16(&mut fvar, 1); // majorVersion
+    push_u16(&mut fvar, 0); // minorVersion
+    push_u16(&mut fvar, 16); // axesArrayOffset
+    push_u16(&mut fvar, 2); // reserved
+    push_u16(&mut fvar, 1); // axisCount
+    push_u16(&mut fvar, 20); // axisSize
+    push_u16(&mut fvar, 0); // instanceCount
+    push_u16(&mut fvar, 8); // instanceSize
+    fvar.extend_from_slice(tag);
+    for v in [min, default, max] {
+        push_u32(&mut fvar, (v * 65536.0) as i32 as u32);
+    }
+    push_u16(&mut fvar, 0); // flags
+    push_u16(&mut fvar, 256); // axisNameID
+    fvar
+}
+
+/// Build a CFF2 table with the given charstrings and one variation region
+/// peaking at the maximum of the first axis
+pub fn cff2(char_strings: &[Vec<u8>]) -> Vec<u8> {
+    // Top DICT: every offset encoded as a 5-byte integer so its size is fixed
+    const TOP_DICT_LEN: usize = 12;
+    const HEADER_LEN: usize = 5;
+    const GLOBAL_SUBRS_LEN: usize = 4;
+    let char_strings_offset = HEADER_LEN + TOP_DICT_LEN + GLOBAL_SUBRS_LEN;
+
+    let mut index = Vec::new();
+    push_u32(&mut index, char_strings.len() as u32);
+    index.push(4); // offSize
+    let mut offset = 1u32;
+    push_u32(&mut index, offset);
+    for cs in char_strings {
+        offset += cs.len() as u32;
+        push_u32(&mut index, offset);
+    }
+    for cs in char_strings {
+        index.extend_from_slice(cs);
+    }
+    let variation_store_offset = char_strings_offset + index.len();
+
+    let mut out = vec![2, 0, HEADER_LEN as u8];
+    push_u16(&mut out, TOP_DICT_LEN as u16);
+    out.push(29);
+    push_u32(&mut out, char_strings_offset as u32);
+    out.push(17); // CharStrings
+    out.push(29);
+    push_u32(&mut out, variation_store_offset as u32);
+    out.push(24); // vstore
+    push_u32(&mut out, 0); // empty Global Subr INDEX
+    out.extend_from_slice(&index);
+
+    let mut store = Vec::new();
+    push_u16(&mut store, 1); // format
+    push_u32(&mut store, 12); // variationRegionListOffset
+    push_u16(&mut store, 1); // itemVariationDataCount
+    push_u32(&mut store, 22); // itemVariationDataOffsets[0]
+    push_u16(&mut store, 1); // axisCount
+    push_u16(&mut store, 1); // regionCount
+    for v in [0i16, 0x4000, 0x4000] {
+        push_i16(&mut store, v); // start, peak, end (F2DOT14)
+    }
+    push_u16(&mut store, 0); // itemCount
+    push_u16(&mut store, 0); // wordDeltaCount
+    push_u16(&mut store, 1); // regionIndexCount
+    push_u16(&mut store, 0); // regionIndexes[0]
+
+    push_u16(&mut out, store.len() as u16);
+    out.extend_from_slice(&store);
+    out
+}
+
+/// Encode an integer charstring operand
+pub fn cs_int(out: &mut Vec<u8>, v: i16) {
+    out.push(28);
+    push_i16(out, v);
+}
+
+fn build_cmap(mapping: &[(char, u16)]) -> Vec<u8> {
+    let mut mapping = mapping.to_vec();
+    mapping.sort();
+
+    let seg_count = mapping.len() + 1;
+    let mut sub = Vec::new();
+    push_u16(&mut sub, 4); // format
+    push_u16(&mut sub, (16 + seg_count * 8) as u16); // length
+    push_u16(&mut sub, 0); // language
+    push_u16(&mut sub, (seg_count * 2) as u16);
+    sub.extend_from_slice(&[0; 6]); // searchRange, entrySelector, rangeShift
+    for (c, _) in &mapping {
+        push_u16(&mut sub, *c as u16); // endCode
+    }
+    push_u16(&mut sub, 0xFFFF);
+    push_u16(&mut sub, 0); // reservedPad
+    for (c, _) in &mapping {
+        push_u16(&mut sub, *c as u16); // startCode
+    }
+    push_u16(&mut sub, 0xFFFF);
+    for (c, id) in &mapping {
+        push_u16(&mut sub, id.wrapping_sub(*c as u16)); // idDelta
+    }
+    push_u16(&mut sub, 1);
+    sub.extend(std::iter::repeat_n(0, seg_count * 2)); // idRangeOffset
+
+    let mut cmap = Vec::new();
+    push_u16(&mut cmap, 0); // version
+    push_u16(&mut cmap, 1); // numTables
+    push_u16(&mut cmap, 3); // platformID (Windows)
+    push_u16(&mut cmap, 1); // encodingID (Unicode BMP)
+    push_u32(&mut cmap, 12);
+    cmap.extend_from_slice(&sub);
+    cmap
+}
+
+/// A `CPAL` table; each palette is a list of RGBA colors of the same length
+pub fn cpal(palettes: &[Vec<[u8; 4]>]) -> Vec<u8> {
+    let entries = palettes.first().map_or(0, Vec::len) as u16;
+    let mut out = Vec::new();
+    push_u16(&mut out, 0); // version
+    push_u16(&mut out, entries);
+    push_u16(&mut out, palettes.len() as u16);
+    push_u16(&mut out, entries * palettes.len() as u16);
+    push_u32(&mut out, 12 + 2 * palettes.len() as u32); // colorRecordsArrayOffset
+    for i in 0..palettes.len() {
+        push_u16(&mut out, i as u16 * entries);
+    }
+    for [r, g, b, a] in palettes.iter().flatten() {
+        out.extend_from_slice(&[*b, *g, *r, *a]); // stored as BGRA
+    }
+    out
+}
+
+pub fn push_u16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_be_bytes());
+}
+
+pub fn push_i16(out: &mut Vec<u8>, v: i16) {
+    out.extend_from_slice(&v.to_be_bytes());
+}
+
+pub fn push_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_be_bytes());
+}
@@ -8,10 +8,14 @@
 //! - Normal vector validity (normalized)
 //! - Mesh topology (closed, manifold)
 
+mod common;
+
+use common::FontBuilder;
 use fontmesh::glyph::Glyph;
 use fontmesh::{char_to_mesh_2d, char_to_mesh_3d, Face};
 
 const TEST_FONT: &[u8] = include_bytes!("../assets/test_font.ttf");
+const CURSIVE_FONT: &[u8] = include_bytes!("../assets/test_font_cursive.ttf");
 
 #[test]
 fn test_2d_mesh_structure() {
@@ -376,13 +380,819 @@ fn test_error_handling() {
             }
             Err(e) => {
                 println!("Character U+{:04X} not available: {:?}", c as u32, e);
-                // Error should be GlyphNotFound or NoOutline
+                // Error should be GlyphNotFound or EmptyOutline
                 assert!(
                     format!("{:?}", e).contains("GlyphNotFound")
-                        || format!("{:?}", e).contains("NoOutline"),
-                    "Error should be GlyphNotFound or NoOutline"
+                        || format!("{:?}", e).contains("EmptyOutline"),
+                    "Error should be GlyphNotFound or EmptyOutline"
                 );
             }
         }
     }
 }
+
+#[test]
+fn test_glyph_bounds_match_outline() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    for c in "AOgj@".chars() {
+        let glyph = Glyph::new(&font, c).unwrap();
+        let [[x0, y0], [x1, y1]] = glyph.bounds().expect("glyph should have bounds");
+        let [[ox0, oy0], [ox1, oy1]] = glyph.outline().unwrap().bounds().unwrap();
+
+        // Control points may lie outside the tight bbox, never inside it
+        assert!(ox0 <= x0 + 1e-4 && oy0 <= y0 + 1e-4, "'{}' min mismatch", c);
+        assert!(ox1 >= x1 - 1e-4 && oy1 >= y1 - 1e-4, "'{}' max mismatch", c);
+    }
+
+    assert!(Glyph::new(&font, ' ').unwrap().bounds().is_none());
+}
+
+#[test]
+fn test_side_normals_point_outward() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    for c in "IOAB8g".chars() {
+        let outline = Glyph::new(&font, c).unwrap().linearize_with(20).unwrap();
+        let mesh = char_to_mesh_3d(&font, c, 1.0, 20).unwrap();
+        let cap_vertices = fontmesh::triangulate(&outline).unwrap().vertices.len() * 2;
+
+        // Side walls are emitted as quads of 4 vertices sharing one normal
+        for (corners, normal) in mesh.vertices[cap_vertices..].chunks_exact(4).zip(
+            mesh.normals[cap_vertices..]
+                .chunks_exact(4)
+                .map(|normals| normals[0]),
+        ) {
+            let midpoint = (corners[0] + corners[1]).truncate() * 0.5;
+            let probe = midpoint + normal.truncate() * 1e-3;
+            let inside = outline
+                .contours
+                .iter()
+                .filter(|contour| contour.contains(probe))
+                .count()
+                % 2
+                == 1;
+            assert!(!inside, "side normal of '{}' points into the glyph", c);
+        }
+    }
+}
+
+#[test]
+fn test_match_x_height() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let mesh = Glyph::new(&font, 'x')
+        .unwrap()
+        .with_subdivisions(20)
+        .match_x_height(1.0)
+        .to_mesh_2d()
+        .unwrap();
+    let (min, max) = mesh.aabb();
+
+    assert!(
+        (max.y - 1.0).abs() < 1e-3,
+        "x-height should be 1.0, got {}",
+        max.y
+    );
+    assert!(min.y.abs() < 1e-3);
+}
+
+#[test]
+fn test_curve_segments_are_connected() {
+    use fontmesh::types::Segment;
+
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    for c in ['O', 'S', 'l', 'g'] {
+        let contours = Glyph::new(&font, c).unwrap().curve_segments().unwrap();
+        assert!(!contours.is_empty());
+
+        for contour in &contours {
+            let ends: Vec<_> = contour
+                .iter()
+                .map(|s| match *s {
+                    Segment::Line(a, b) => (a, b),
+                    Segment::Quad(a, _, b) => (a, b),
+                    Segment::Cubic(a, _, _, b) => (a, b),
+                })
+                .collect();
+            // Each segment starts where the previous one ended, and the contour closes
+            for (prev, next) in ends.iter().zip(ends.iter().cycle().skip(1)) {
+                assert_eq!(prev.1, next.0, "'{}' has a gap", c);
+            }
+        }
+    }
+
+    let space = Glyph::new(&font, ' ').unwrap();
+    assert!(matches!(
+        space.curve_segments(),
+        Err(fontmesh::FontMeshError::EmptyOutline)
+    ));
+}
+
+#[test]
+fn test_stroke_widths() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let glyph = Glyph::new(&font, 'I').unwrap();
+    let [[x_min, y_min], [x_max, y_max]] = glyph.bounds().unwrap();
+
+    let widths = glyph.stroke_widths(100).unwrap();
+    assert!(!widths.is_empty() && widths.len() <= 100);
+
+    // 'I' is a plain bar: samples on its long sides measure the stem width
+    let stem = x_max - x_min;
+    let on_stem = widths
+        .iter()
+        .filter(|&&(_, w)| (w - stem).abs() < 1e-3)
+        .count();
+    assert!(on_stem > widths.len() / 2);
+
+    for &(p, w) in &widths {
+        assert!(w <= y_max - y_min + 1e-3);
+        assert!(p.x > x_min && p.x < x_max && p.y > y_min && p.y < y_max);
+    }
+
+    assert!(glyph.stroke_widths(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_composite_components() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let e = Glyph::new(&font, 'e').unwrap();
+    assert!(!e.is_composite());
+    assert!(e.components().is_empty());
+
+    let e_acute = Glyph::new(&font, 'é').unwrap();
+    assert!(e_acute.is_composite());
+    let components = e_acute.components();
+    assert!(components.len() >= 2);
+    assert_eq!(components[0].0, e.glyph_id());
+
+    // Translations are normalized like the outline coordinates
+    for (_, transform) in &components {
+        assert!(transform.e.abs() < 2.0 && transform.f.abs() < 2.0);
+    }
+}
+
+#[test]
+fn test_origin_at_left_bearing() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let glyph = Glyph::new(&font, 'I').unwrap();
+    let [[x_min, y_min], _] = glyph.bounds().unwrap();
+    assert!(x_min > 0.0);
+
+    let kept = glyph.with_subdivisions(20).to_mesh_3d(0.1).unwrap();
+    assert!((kept.aabb().0.x - x_min).abs() < 1e-5);
+
+    let flush = glyph
+        .with_subdivisions(20)
+        .origin_at_left_bearing(false)
+        .to_mesh_3d(0.1)
+        .unwrap();
+    let (min, max) = flush.aabb();
+    assert!(min.x.abs() < 1e-6);
+    assert!((min.y - y_min).abs() < 1e-5);
+    assert!((max.x - (kept.aabb().1.x - x_min)).abs() < 1e-5);
+}
+
+#[test]
+fn test_glyph_from_id() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let by_char = Glyph::new(&font, 'A').unwrap();
+    let by_id = Glyph::from_id(&font, by_char.glyph_id()).unwrap();
+    assert_eq!(by_char.character(), Some('A'));
+    assert_eq!(by_id.character(), None);
+    assert_eq!(by_id.advance(), by_char.advance());
+
+    let expected = by_char.with_subdivisions(20).to_mesh_2d().unwrap();
+    let mesh = by_id.with_subdivisions(20).to_mesh_2d().unwrap();
+    assert_eq!(mesh.vertices, expected.vertices);
+
+    let past_end = fontmesh::GlyphId(font.number_of_glyphs());
+    assert!(matches!(
+        Glyph::from_id(&font, past_end),
+        Err(fontmesh::FontMeshError::InvalidGlyphId(id)) if id == past_end.0
+    ));
+}
+
+#[test]
+fn test_empty_outline_vs_no_glyph_data() {
+    use fontmesh::FontMeshError;
+
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let space = Glyph::new(&font, ' ').unwrap();
+    assert_eq!(space.outline().unwrap_err(), FontMeshError::EmptyOutline);
+
+    // A font without glyf or CFF tables, like a bitmap-only font
+    let data = FontBuilder::new(1000, 2).map('a', 1).build();
+    let bitmap_only = Face::parse(&data, 0).unwrap();
+    let glyph = Glyph::new(&bitmap_only, 'a').unwrap();
+    assert_eq!(glyph.outline().unwrap_err(), FontMeshError::NoGlyphData);
+    assert_eq!(
+        char_to_mesh_2d(&bitmap_only, 'a', 20).unwrap_err(),
+        FontMeshError::NoGlyphData
+    );
+}
+
+#[test]
+fn test_contour_meshes_2d() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    for (c, expected) in [('i', 2), ('B', 1), ('%', 3), (':', 2)] {
+        let glyph = Glyph::new(&font, c).unwrap();
+        let parts = glyph.contour_meshes_2d(20).unwrap();
+        assert_eq!(parts.len(), expected, "'{}'", c);
+
+        // Together the parts cover the whole glyph, holes included
+        let whole = glyph.with_subdivisions(20).to_mesh_2d().unwrap();
+        let total: f32 = parts.iter().map(fontmesh::Mesh2D::area).sum();
+        assert!((total - whole.area()).abs() < 1e-4, "'{}'", c);
+    }
+}
+
+#[test]
+fn test_font_names() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    assert_eq!(fontmesh::family_name(&font).as_deref(), Some("DejaVu Sans"));
+    assert_eq!(fontmesh::subfamily_name(&font).as_deref(), Some("Book"));
+
+    // No name table at all
+    let data = FontBuilder::new(1000, 1).build();
+    let bare = Face::parse(&data, 0).unwrap();
+    assert_eq!(fontmesh::family_name(&bare), None);
+    assert_eq!(fontmesh::subfamily_name(&bare), None);
+}
+
+#[test]
+fn test_default_subdivisions() {
+    let font = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let mut glyph = Glyph::new(&font, 'O').unwrap();
+    assert_eq!(glyph.default_subdivisions(), 20);
+    glyph.set_default_subdivisions(5).unwrap();
+
+    let expected = glyph.with_subdivisions(5).to_mesh_2d().unwrap();
+    assert_eq!(glyph.to_mesh_2d().unwrap().vertices, expected.vertices);
+    // The builder still overrides the default
+    let fine = glyph.with_subdivisions(40).to_mesh_2d().unwrap();
+    assert!(fine.vertices.len() > expected.vertices.len());
+
+    assert_eq!(
+        glyph.set_default_subdivisions(0),
+        Err(fontmesh::FontMeshError::InvalidQuality(0))
+    );
+    assert_eq!(glyph.default_subdivisions(), 5);
+}
+
+#[test]
+fn test_outline_scaled() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let glyph = Glyph::new(&face, 'H').unwrap();
+
+    let normalized = glyph.outline().unwrap();
+    let scaled = glyph.outline_scaled(24.0).unwrap();
+    assert_eq!(normalized.contours.len(), scaled.contours.len());
+    for (a, b) in normalized.contours.iter().zip(&scaled.contours) {
+        assert_eq!(a.points.len(), b.points.len());
+        for (p, q) in a.points.iter().zip(&b.points) {
+            assert!((p.point * 24.0 - q.point).length() < 1e-4);
+            assert_eq!(p.on_curve, q.on_curve);
+        }
+    }
+
+    for ppem in [0.0, f32::NAN] {
+        assert!(matches!(
+            glyph.outline_scaled(ppem),
+            Err(fontmesh::FontMeshError::InvalidParameter(_))
+        ));
+    }
+}
+
+#[test]
+fn test_grid_fit_rejects_invalid_ppem() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let glyph = Glyph::new(&face, 'H').unwrap();
+    assert!(glyph.grid_fit(12.0).is_ok());
+    for ppem in [0.0, -12.0, f32::NAN, f32::INFINITY] {
+        assert!(matches!(
+            glyph.grid_fit(ppem),
+            Err(fontmesh::FontMeshError::InvalidParameter(_))
+        ));
+    }
+}
+
+#[test]
+fn test_skew() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    assert_eq!(fontmesh::italic_angle(&face), 0.0);
+    let glyph = Glyph::new(&face, 'H').unwrap();
+
+    let upright = glyph.with_subdivisions(20).to_outline().unwrap();
+    let slanted = glyph
+        .with_subdivisions(20)
+        .skew(-12.0)
+        .to_outline()
+        .unwrap();
+    let shear = 12.0f32.to_radians().tan();
+    for (a, b) in upright.contours.iter().zip(&slanted.contours) {
+        for (p, q) in a.points.iter().zip(&b.points) {
+            assert_eq!(p.point.y, q.point.y);
+            assert!((p.point.x + p.point.y * shear - q.point.x).abs() < 1e-5);
+        }
+    }
+
+    // Vertical extent, advance and the extruded depth are unchanged
+    let [min_a, max_a] = upright.bounds().unwrap();
+    let [min_b, max_b] = slanted.bounds().unwrap();
+    assert_eq!((min_a[1], max_a[1]), (min_b[1], max_b[1]));
+    assert!(max_b[0] > max_a[0]);
+    let mesh = glyph
+        .with_subdivisions(20)
+        .skew(-12.0)
+        .to_mesh_3d(0.2)
+        .unwrap();
+    assert!(mesh.vertices.iter().all(|v| v.z.abs() <= 0.1 + 1e-6));
+
+    assert!(matches!(
+        glyph.with_subdivisions(20).skew(90.0).to_outline(),
+        Err(fontmesh::FontMeshError::InvalidParameter(_))
+    ));
+    assert!(glyph
+        .with_subdivisions(20)
+        .skew(f32::NAN)
+        .to_outline()
+        .is_err());
+}
+
+#[test]
+fn test_is_whitespace() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+
+    for c in [' ', '\u{00A0}', '\u{2003}'] {
+        let glyph = Glyph::new(&face, c).unwrap();
+        assert!(glyph.is_whitespace(), "{c:?}");
+        assert!(glyph.advance() > 0.0);
+        assert!(matches!(
+            glyph.outline(),
+            Err(fontmesh::FontMeshError::EmptyOutline)
+        ));
+    }
+    for c in ['A', '.', 'é'] {
+        assert!(!Glyph::new(&face, c).unwrap().is_whitespace(), "{c:?}");
+    }
+}
+
+#[test]
+fn test_min_contour_area_drops_dots() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let glyph = Glyph::new(&face, 'i').unwrap();
+
+    let full = glyph.with_subdivisions(20).to_outline().unwrap();
+    assert_eq!(full.contours.len(), 2);
+    let areas: Vec<f32> = full
+        .contours
+        .iter()
+        .map(|c| c.signed_area().abs())
+        .collect();
+    let threshold = (areas[0] + areas[1]) / 2.0;
+
+    let lod = glyph
+        .with_subdivisions(20)
+        .min_contour_area(threshold)
+        .to_mesh_2d()
+        .unwrap();
+    let plain = glyph.with_subdivisions(20).to_mesh_2d().unwrap();
+    assert!(lod.triangle_count() < plain.triangle_count());
+
+    // 'o' keeps its counter unless small holes may go too
+    let o = Glyph::new(&face, 'o').unwrap();
+    let outline = o.with_subdivisions(20).to_outline().unwrap();
+    let counter = outline
+        .contours
+        .iter()
+        .map(|c| c.signed_area().abs())
+        .fold(f32::MAX, f32::min);
+    let kept = o
+        .with_subdivisions(20)
+        .min_contour_area(counter * 1.01)
+        .to_outline()
+        .unwrap();
+    assert_eq!(kept.contours.len(), 2);
+    let filled = o
+        .with_subdivisions(20)
+        .min_contour_area(counter * 1.01)
+        .drop_small_holes(true)
+        .to_outline()
+        .unwrap();
+    assert_eq!(filled.contours.len(), 1);
+}
+
+#[test]
+fn test_estimate_vertices() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+
+    for c in "AOSg@&é".chars() {
+        let glyph = Glyph::new(&face, c).unwrap();
+        for subdivisions in [1, 8, 20] {
+            let estimate = glyph.estimate_vertices(subdivisions).unwrap();
+            let mesh = glyph.with_subdivisions(subdivisions).to_mesh_2d().unwrap();
+            assert_eq!(estimate, mesh.vertices.len(), "'{}' at {}", c, subdivisions);
+        }
+    }
+    assert!(Glyph::new(&face, ' ')
+        .unwrap()
+        .estimate_vertices(20)
+        .is_err());
+}
+
+#[test]
+fn test_builder_rejects_zero_subdivisions() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let glyph = Glyph::new(&face, 'O').unwrap();
+
+    assert!(matches!(
+        glyph.with_subdivisions(0).to_mesh_2d(),
+        Err(fontmesh::FontMeshError::InvalidQuality(0))
+    ));
+    assert!(glyph.with_subdivisions(u8::MAX).to_mesh_2d().is_ok());
+}
+
+#[test]
+fn test_or_panic_variants() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let glyph = Glyph::new(&face, 'A').unwrap();
+
+    let mesh_2d = glyph.with_subdivisions(20).to_mesh_2d_or_panic();
+    assert_eq!(
+        mesh_2d.vertices,
+        glyph.with_subdivisions(20).to_mesh_2d().unwrap().vertices
+    );
+    let mesh_3d = glyph.with_subdivisions(20).to_mesh_3d_or_panic(0.1);
+    assert!(mesh_3d.is_solid());
+
+    let space = Glyph::new(&face, ' ').unwrap();
+    let panic = std::panic::catch_unwind(|| space.with_subdivisions(20).to_mesh_2d_or_panic());
+    let message = panic.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("failed to mesh glyph"), "{}", message);
+}
+
+#[test]
+fn test_flip_normals() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let mesh = char_to_mesh_3d(&face, 'g', 0.1, 20).unwrap();
+
+    let mut smooth = mesh.clone();
+    fontmesh::compute_smooth_normals(&mut smooth);
+    let mut flipped = mesh.clone();
+    flipped.flip_normals();
+    assert!(flipped
+        .normals
+        .iter()
+        .zip(&mesh.normals)
+        .all(|(f, n)| *f == -*n));
+
+    // Normals recomputed from the reversed winding match the negated ones
+    fontmesh::compute_smooth_normals(&mut flipped);
+    for (f, s) in flipped.normals.iter().zip(&smooth.normals) {
+        assert!((*f + *s).length() < 1e-5, "{:?} vs {:?}", f, s);
+    }
+
+    flipped.flip_normals();
+    assert_eq!(flipped.indices, mesh.indices);
+}
+
+#[test]
+fn test_deep_nesting_registered_sign() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let outline = Glyph::new(&face, '®').unwrap().linearize().unwrap();
+    let nesting = outline.classify_nesting();
+
+    // Ring outside, ring inside, the R, and the R's counter: each one level
+    // deeper than the last and enclosed by the previous one
+    let mut order: Vec<usize> = (0..nesting.len()).collect();
+    order.sort_by_key(|&i| nesting[i].depth);
+    for (level, pair) in order.windows(2).enumerate() {
+        assert_eq!(nesting[pair[0]].depth, level);
+        assert_eq!(nesting[pair[1]].parent, Some(pair[0]));
+    }
+    assert_eq!(nesting[order[0]].parent, None);
+    assert_eq!(nesting[order[3]].depth, 3);
+
+    // The triangulated area is the even-odd area, with both holes left open
+    let mesh = char_to_mesh_2d(&face, '®', 20).unwrap();
+    let mesh_area = mesh.area();
+    let even_odd_area: f32 = outline
+        .contours
+        .iter()
+        .zip(&nesting)
+        .map(|(contour, n)| {
+            let area = contour.signed_area().abs();
+            if n.is_hole() {
+                -area
+            } else {
+                area
+            }
+        })
+        .sum();
+    assert!((mesh_area - even_odd_area).abs() < 1e-3 * even_odd_area);
+    assert!(!mesh.has_filled_holes(&outline));
+}
+
+#[test]
+fn test_chord_mode() {
+    use fontmesh::{linearize_outline_with, LinearizeMode, LinearizeOptions};
+
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let chord = LinearizeOptions {
+        mode: LinearizeMode::Chord,
+        ..LinearizeOptions::new(0)
+    };
+    for c in ['O', 'S', 'g', '@'] {
+        let glyph = Glyph::new(&face, c).unwrap();
+        let raw = glyph.outline().unwrap();
+
+        // On-curve points, plus the midpoints implied between off-curve pairs
+        let mut on_curve = Vec::new();
+        for contour in &raw.contours {
+            let n = contour.points.len();
+            for (i, cp) in contour.points.iter().enumerate() {
+                let next = contour.points[(i + 1) % n];
+                if cp.on_curve {
+                    on_curve.push(cp.point);
+                } else if !next.on_curve {
+                    on_curve.push((cp.point + next.point) * 0.5);
+                }
+            }
+        }
+
+        let linear = linearize_outline_with(raw.clone(), &chord).unwrap();
+        for cp in linear.contours.iter().flat_map(|k| &k.points) {
+            assert!(
+                on_curve.iter().any(|p| p.distance(cp.point) < 1e-6),
+                "'{}': {:?} is not an on-curve point",
+                c,
+                cp.point
+            );
+        }
+
+        let adaptive = glyph.linearize().unwrap();
+        let count =
+            |o: &fontmesh::Outline2D| o.contours.iter().map(|k| k.points.len()).sum::<usize>();
+        assert!(count(&linear) < count(&adaptive));
+
+        // The builder accepts 0 subdivisions in chord mode
+        let mesh = glyph
+            .with_subdivisions(0)
+            .linearize_mode(LinearizeMode::Chord)
+            .to_mesh_2d()
+            .unwrap();
+        assert_eq!(mesh.vertices.len(), count(&linear));
+    }
+}
+
+#[test]
+fn test_tolerance_mode() {
+    use fontmesh::types::Segment;
+    use fontmesh::{linearize_outline_tolerance, FontMeshError, PathBuilder};
+    use glam::Vec2;
+
+    let distance_to_segment = |p: Vec2, a: Vec2, b: Vec2| {
+        let ab = b - a;
+        let t = ((p - a).dot(ab) / ab.length_squared().max(f32::MIN_POSITIVE)).clamp(0.0, 1.0);
+        p.distance(a + ab * t)
+    };
+
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    for c in ['O', 'S', '@'] {
+        let glyph = Glyph::new(&face, c).unwrap();
+        for tolerance in [1e-3, 1e-4] {
+            let linear = linearize_outline_tolerance(glyph.outline().unwrap(), tolerance).unwrap();
+            let edges: Vec<(Vec2, Vec2)> = linear
+                .contours
+                .iter()
+                .flat_map(|k| {
+                    let n = k.points.len();
+                    (0..n).map(move |i| (k.points[i].point, k.points[(i + 1) % n].point))
+                })
+                .collect();
+
+            // Every point of every curve is within the tolerance of the result
+            for segment in glyph.curve_segments().unwrap().into_iter().flatten() {
+                let Segment::Quad(a, b, c) = segment else {
+                    continue;
+                };
+                for i in 0..=16 {
+                    let t = i as f32 / 16.0;
+                    let p = a * (1.0 - t) * (1.0 - t) + b * 2.0 * (1.0 - t) * t + c * t * t;
+                    let gap = edges
+                        .iter()
+                        .map(|&(x, y)| distance_to_segment(p, x, y))
+                        .fold(f32::MAX, f32::min);
+                    assert!(gap <= tolerance * 1.01, "'{}': {} > {}", c, gap, tolerance);
+                }
+            }
+        }
+    }
+
+    // Scaling the shape and the tolerance together keeps the point count
+    let circle = |r: f32| {
+        PathBuilder::new()
+            .move_to(r, 0.0)
+            .quad_to(r, r, 0.0, r)
+            .quad_to(-r, r, -r, 0.0)
+            .quad_to(-r, -r, 0.0, -r)
+            .quad_to(r, -r, r, 0.0)
+            .close()
+            .build()
+    };
+    let points = |r: f32, tolerance: f32| {
+        linearize_outline_tolerance(circle(r), tolerance)
+            .unwrap()
+            .contours[0]
+            .points
+            .len()
+    };
+    assert_eq!(points(0.1, 1e-3), points(1.0, 1e-2));
+    assert!(points(1.0, 1e-3) > points(0.1, 1e-3));
+
+    assert!(matches!(
+        linearize_outline_tolerance(circle(1.0), 0.0),
+        Err(FontMeshError::InvalidParameter(_))
+    ));
+
+    // The builder keeps whichever of subdivisions and tolerance came last
+    let glyph = Glyph::new(&face, 'O').unwrap();
+    let by_tolerance = linearize_outline_tolerance(glyph.outline().unwrap(), 1e-3).unwrap();
+    let tolerance_last = glyph
+        .with_subdivisions(8)
+        .with_tolerance(1e-3)
+        .to_outline()
+        .unwrap();
+    let subdivisions_last = glyph
+        .with_subdivisions(0)
+        .with_tolerance(1e-3)
+        .with_subdivisions(8)
+        .to_outline()
+        .unwrap();
+    let count = |o: &fontmesh::Outline2D| o.contours.iter().map(|k| k.points.len()).sum::<usize>();
+    assert_eq!(count(&tolerance_last), count(&by_tolerance));
+    assert_eq!(
+        count(&subdivisions_last),
+        count(&glyph.linearize_with(8).unwrap())
+    );
+    assert!(glyph
+        .with_subdivisions(20)
+        .with_tolerance(f32::NAN)
+        .to_mesh_2d()
+        .is_err());
+}
+
+#[test]
+fn test_advance_units() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let upm = face.units_per_em() as f32;
+    for c in ['A', 'i', 'W', ' '] {
+        let glyph = Glyph::new(&face, c).unwrap();
+        let id = face.glyph_index(c).unwrap();
+        assert_eq!(Some(glyph.advance_units()), face.glyph_hor_advance(id));
+        assert_eq!(glyph.advance_units() as f32 / upm, glyph.advance());
+    }
+}
+
+#[test]
+fn test_displace_front() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let original = char_to_mesh_3d(&face, 'O', 0.1, 20).unwrap();
+
+    // A constant lift moves the front plane and keeps it flat
+    let mut lifted = original.clone();
+    lifted.displace_front(|_| 0.05);
+    for ((v, o), (n, on)) in lifted
+        .vertices
+        .iter()
+        .zip(&original.vertices)
+        .zip(lifted.normals.iter().zip(&original.normals))
+    {
+        if (o.z - 0.05).abs() < 1e-6 {
+            assert!((v.z - 0.1).abs() < 1e-6);
+        } else {
+            assert_eq!(v, o);
+        }
+        assert!((*n - *on).length() < 1e-5);
+    }
+    assert!(lifted.is_solid());
+
+    // A wave tilts the cap normals but leaves the back alone
+    let mut wavy = original.clone();
+    wavy.displace_front(|p| 0.02 * (p.x * 20.0).sin());
+    assert!(wavy.is_solid());
+    let mut tilted = 0;
+    for ((v, o), n) in wavy
+        .vertices
+        .iter()
+        .zip(&original.vertices)
+        .zip(&wavy.normals)
+    {
+        assert!((n.length() - 1.0).abs() < 1e-4);
+        if o.z < 0.0 {
+            assert_eq!(v, o);
+        } else if n.z > 0.0 && n.z < 0.999 {
+            tilted += 1;
+        }
+    }
+    assert!(tilted > 0);
+}
+
+#[test]
+fn test_filled_area() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+
+    // The even-odd area of the outline: the counter of 'O' is not filled
+    for c in ['O', 'I', 'B', '®'] {
+        let glyph = Glyph::new(&face, c).unwrap();
+        let outline = glyph.linearize_with(20).unwrap();
+        let expected: f32 = outline
+            .contours
+            .iter()
+            .zip(outline.classify_nesting())
+            .map(|(contour, n)| {
+                let area = contour.signed_area().abs();
+                if n.is_hole() {
+                    -area
+                } else {
+                    area
+                }
+            })
+            .sum();
+        let area = glyph.filled_area(20).unwrap();
+        assert!((area - expected).abs() < 1e-3 * expected, "'{}'", c);
+    }
+
+    assert_eq!(
+        Glyph::new(&face, ' ').unwrap().filled_area(20).unwrap(),
+        0.0
+    );
+    assert!(Glyph::new(&face, 'O').unwrap().filled_area(0).is_err());
+}
+
+#[test]
+fn test_thin_strokes_are_filled() {
+    // Hairlines of a script font are a few hundredths of an em wide; none
+    // of them may be merged away by the tessellator
+    let face = Face::parse(CURSIVE_FONT, 0).unwrap();
+    for c in 'a'..='z' {
+        let glyph = Glyph::new(&face, c).unwrap();
+        let outline = glyph.linearize_with(20).unwrap();
+        let expected: f32 = outline
+            .contours
+            .iter()
+            .map(|contour| contour.signed_area())
+            .sum::<f32>()
+            .abs();
+        let area = glyph.filled_area(20).unwrap();
+        assert!(
+            (area - expected).abs() < 1e-3 * expected,
+            "'{}': {} != {}",
+            c,
+            area,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_unindexed_round_trip() {
+    let face = Face::parse(TEST_FONT, 0).unwrap();
+    let mesh = char_to_mesh_3d(&face, 'B', 0.1, 20).unwrap();
+
+    let (vertices, normals) = mesh.to_unindexed();
+    assert_eq!(vertices.len(), mesh.indices.len());
+    for (k, &i) in mesh.indices.iter().enumerate() {
+        assert_eq!(vertices[k], mesh.vertices[i as usize]);
+        assert_eq!(normals[k], mesh.normals[i as usize]);
+    }
+
+    // Welding restores an equivalent mesh with no more vertices than before
+    let welded = fontmesh::Mesh3D::from_unindexed(&vertices, &normals);
+    assert_eq!(welded.triangle_count(), mesh.triangle_count());
+    assert!(welded.vertices.len() <= mesh.vertices.len());
+    assert_eq!(welded.to_unindexed(), (vertices, normals));
+    assert!(welded.is_solid());
+
+    // -0.0 and 0.0 are the same corner
+    use glam::Vec3;
+    let soup = [
+        Vec3::ZERO,
+        Vec3::X,
+        Vec3::Y,
+        Vec3::new(-0.0, 0.0, -0.0),
+        Vec3::Y,
+        Vec3::X,
+    ];
+    let welded = fontmesh::Mesh3D::from_unindexed(&soup, &[Vec3::Z; 6]);
+    assert_eq!(welded.vertices.len(), 3);
+    assert_eq!(welded.indices, [0, 1, 2, 0, 2, 1]);
+}
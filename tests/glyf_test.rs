@@ -0,0 +1,445 @@
+//! Tests for TrueType (`glyf`) outlines built in memory
+
+mod common;
+
+use common::{square_glyph, FontBuilder};
+use fontmesh::glyph::Glyph;
+use fontmesh::{Face, FontMeshError};
+
+#[test]
+fn test_near_closing_point_is_dropped() {
+    // A square whose last point stops 1 unit short of the first
+    let mut square = square_glyph(100);
+    square[0].push((1, -1, true));
+    let data = FontBuilder::new(1000, 2)
+        .map('a', 1)
+        .glyf(&[Vec::new(), square])
+        .build();
+    let face = Face::parse(&data, 0).unwrap();
+    let glyph = Glyph::new(&face, 'a').unwrap();
+
+    // The default half-unit tolerance keeps a whole unit's miss
+    let points = |outline: fontmesh::Outline2D| outline.contours[0].points.len();
+    assert_eq!(points(glyph.with_subdivisions(20).to_outline().unwrap()), 5);
+
+    let closed = glyph
+        .with_subdivisions(20)
+        .closing_tolerance(2.0)
+        .to_outline()
+        .unwrap();
+    assert_eq!(points(closed.clone()), 4);
+    assert_eq!(closed.contours[0].points[3].point.x, 0.1);
+
+    let mesh = glyph
+        .with_subdivisions(20)
+        .closing_tolerance(2.0)
+        .to_mesh_3d(0.1)
+        .unwrap();
+    assert!(mesh.is_solid());
+}
+
+#[test]
+fn test_exact_closing_point_is_dropped() {
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+
+    // DejaVu repeats each contour's first point at its end
+    let outline = Glyph::new(&face, 'I').unwrap().outline().unwrap();
+    let contour = &outline.contours[0];
+    assert_eq!(contour.points.len(), 4);
+    assert_ne!(contour.points[0].point, contour.points[3].point);
+}
+
+#[test]
+fn test_notdef_mesh() {
+    use fontmesh::{notdef_mesh_2d, notdef_mesh_3d};
+
+    // DejaVu draws its own .notdef
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let expected = Glyph::from_id(&face, fontmesh::GlyphId(0))
+        .unwrap()
+        .with_subdivisions(20)
+        .to_mesh_2d()
+        .unwrap();
+    let tofu = notdef_mesh_2d(&face, 20).unwrap();
+    assert_eq!(tofu.vertices, expected.vertices);
+    assert_eq!(tofu.indices, expected.indices);
+
+    // A blank .notdef gets a hollow box
+    let data = FontBuilder::new(1000, 2)
+        .map('a', 1)
+        .glyf(&[Vec::new(), square_glyph(100)])
+        .build();
+    let face = Face::parse(&data, 0).unwrap();
+    let tofu = notdef_mesh_2d(&face, 20).unwrap();
+    assert_eq!(tofu.vertices.len(), 8);
+    assert_eq!(tofu.triangle_count(), 8);
+    let (min, max) = tofu.aabb();
+    assert!((max.y - min.y - 0.7).abs() < 1e-6);
+    assert!(max.x > min.x);
+
+    let solid = notdef_mesh_3d(&face, 0.1, 20).unwrap();
+    assert!(solid.is_solid());
+    assert!(notdef_mesh_2d(&face, 0).is_err());
+    assert!(notdef_mesh_3d(&face, f32::NAN, 20).is_err());
+}
+
+#[test]
+fn test_point_density_across_units_per_em() {
+    // The same 'o', two rings of quadratic curves, rounded to whole units
+    // at each em size
+    let ring = |radius: f32, upm: u16| -> Vec<(i16, i16, bool)> {
+        let s = upm as f32;
+        (0..24)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::PI / 12.0;
+                // Off-curve points sit on the tangent lines' intersection
+                let on = i % 2 == 0;
+                let r = if on {
+                    radius
+                } else {
+                    radius / (std::f32::consts::PI / 12.0).cos()
+                };
+                let (x, y) = (0.4 + r * angle.cos(), 0.4 + r * angle.sin());
+                ((x * s).round() as i16, (y * s).round() as i16, on)
+            })
+            .collect()
+    };
+
+    let counts: Vec<usize> = [256u16, 1000, 2048]
+        .iter()
+        .map(|&upm| {
+            let mut inner = ring(0.25, upm);
+            inner.reverse();
+            let data = FontBuilder::new(upm, 2)
+                .map('o', 1)
+                .glyf(&[Vec::new(), vec![ring(0.35, upm), inner]])
+                .build();
+            let face = Face::parse(&data, 0).unwrap();
+            let outline = Glyph::new(&face, 'o').unwrap().linearize().unwrap();
+            outline.contours.iter().map(|c| c.points.len()).sum()
+        })
+        .collect();
+    // Rounding nudges a few curves' measured turn, but no more than that
+    let (min, max) = (counts.iter().min().unwrap(), counts.iter().max().unwrap());
+    assert!(max - min <= max / 20, "{:?}", counts);
+}
+
+#[test]
+fn test_unsupported_chars() {
+    use fontmesh::unsupported_chars;
+
+    // 'x' is mapped, but to .notdef
+    let data = FontBuilder::new(1000, 2)
+        .map('a', 1)
+        .map('x', 0)
+        .glyf(&[Vec::new(), square_glyph(100)])
+        .build();
+    let face = Face::parse(&data, 0).unwrap();
+
+    assert!(unsupported_chars(&face, "aaa").is_empty());
+    assert_eq!(
+        unsupported_chars(&face, "a x\u{200D}b x"),
+        vec![' ', 'x', 'b']
+    );
+}
+
+#[test]
+fn test_is_meshable() {
+    use fontmesh::{char_to_mesh_2d, is_meshable};
+
+    let square = square_glyph(100);
+    // 'x' maps to .notdef and ' ' to an empty glyph
+    let data = FontBuilder::new(1000, 3)
+        .map('a', 1)
+        .map(' ', 2)
+        .map('x', 0)
+        .glyf(&[square.clone(), square, Vec::new()])
+        .build();
+    let face = Face::parse(&data, 0).unwrap();
+
+    for c in ['a', ' ', 'x', 'b'] {
+        assert_eq!(is_meshable(&face, c), c == 'a', "{:?}", c);
+    }
+    assert!(char_to_mesh_2d(&face, 'a', 4).is_ok());
+    assert!(char_to_mesh_2d(&face, ' ', 4).is_err());
+}
+
+#[test]
+fn test_lod_meshes() {
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let glyph = Glyph::new(&face, 'g').unwrap();
+
+    let levels = [50, 20, 8, 20];
+    let lods = glyph.lod_meshes_3d(&levels, 0.2).unwrap();
+    assert_eq!(lods.len(), levels.len());
+    for (mesh, &level) in lods.iter().zip(&levels) {
+        let single = glyph.with_subdivisions(level).to_mesh_3d(0.2).unwrap();
+        assert_eq!(mesh.vertices, single.vertices);
+        assert_eq!(mesh.indices, single.indices);
+    }
+    assert!(lods[0].triangle_count() > lods[1].triangle_count());
+    assert!(lods[1].triangle_count() > lods[2].triangle_count());
+
+    assert!(glyph.lod_meshes_3d(&[], 0.2).unwrap().is_empty());
+    assert!(matches!(
+        glyph.lod_meshes_3d(&[20, 0], 0.2),
+        Err(FontMeshError::InvalidQuality(0))
+    ));
+}
+
+#[test]
+fn test_zero_glyph_font() {
+    let data = FontBuilder::new(1000, 0).glyf(&[]).build();
+    match fontmesh::parse_font(&data) {
+        Err(FontMeshError::ParseError(message)) => {
+            assert_eq!(message, "font contains no glyphs")
+        }
+        other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+    }
+
+    // Other malformed input keeps ttf-parser's reason
+    match fontmesh::parse_font(&data[..8]) {
+        Err(FontMeshError::ParseError(message)) => {
+            assert!(message.starts_with("Failed to parse font"))
+        }
+        other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_quality() {
+    use fontmesh::Quality;
+
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let glyph = Glyph::new(&face, 'e').unwrap();
+    assert_eq!(
+        glyph.default_subdivisions(),
+        Quality::default().subdivisions()
+    );
+
+    let mut counts = Vec::new();
+    for quality in [Quality::Low, Quality::Normal, Quality::High, Quality::Ultra] {
+        let named = glyph.with_quality(quality).to_mesh_2d().unwrap();
+        let raw = glyph
+            .with_subdivisions(quality.subdivisions())
+            .to_mesh_2d()
+            .unwrap();
+        assert_eq!(named.vertices, raw.vertices);
+        counts.push(named.vertices.len());
+    }
+    assert!(
+        counts[0] < counts[1] && counts[1] < counts[2],
+        "{:?}",
+        counts
+    );
+
+    let chained = glyph
+        .with_subdivisions(1)
+        .with_quality(Quality::High)
+        .to_mesh_2d()
+        .unwrap();
+    assert_eq!(
+        chained.vertices,
+        glyph.with_subdivisions(50).to_mesh_2d().unwrap().vertices
+    );
+}
+
+#[test]
+fn test_mesh_reuse() {
+    use fontmesh::{
+        char_to_mesh_2d, char_to_mesh_3d, glyph_to_mesh_2d_reuse, glyph_to_mesh_3d_reuse, Mesh2D,
+        Mesh3D, Quality,
+    };
+
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let mut mesh_2d = Mesh2D::new();
+    let mut mesh_3d = Mesh3D::new();
+
+    // Largest glyph first, so later ones fit in the buffers it grew
+    glyph_to_mesh_3d_reuse(&face, '@', Quality::High, 0.2, &mut mesh_3d).unwrap();
+    let capacity = mesh_3d.vertices.capacity();
+    let buffer = mesh_3d.vertices.as_ptr();
+
+    for c in "@Hello".chars() {
+        glyph_to_mesh_2d_reuse(&face, c, Quality::Normal, &mut mesh_2d).unwrap();
+        let fresh = char_to_mesh_2d(&face, c, Quality::Normal.subdivisions()).unwrap();
+        assert_eq!(mesh_2d.vertices, fresh.vertices);
+        assert_eq!(mesh_2d.indices, fresh.indices);
+
+        glyph_to_mesh_3d_reuse(&face, c, Quality::High, 0.2, &mut mesh_3d).unwrap();
+        let fresh = char_to_mesh_3d(&face, c, 0.2, Quality::High.subdivisions()).unwrap();
+        assert_eq!(mesh_3d.vertices, fresh.vertices);
+        assert_eq!(mesh_3d.normals, fresh.normals);
+        assert_eq!(mesh_3d.indices, fresh.indices);
+        assert_eq!(mesh_3d.vertices.capacity(), capacity);
+        assert_eq!(mesh_3d.vertices.as_ptr(), buffer);
+    }
+
+    assert!(matches!(
+        glyph_to_mesh_2d_reuse(&face, '\u{E000}', Quality::Low, &mut mesh_2d),
+        Err(FontMeshError::GlyphNotFound(_))
+    ));
+}
+
+#[test]
+fn test_match_x_height_without_x_height() {
+    // No OS/2 table and no 'x' to measure
+    let data = FontBuilder::new(1000, 2)
+        .map('a', 1)
+        .glyf(&[Vec::new(), square_glyph(500)])
+        .build();
+    let face = Face::parse(&data, 0).unwrap();
+    let glyph = Glyph::new(&face, 'a').unwrap();
+    assert_eq!(
+        glyph
+            .with_subdivisions(20)
+            .match_x_height(0.5)
+            .to_mesh_2d()
+            .err(),
+        Some(FontMeshError::MissingMetric("x-height"))
+    );
+}
+
+#[test]
+fn test_to_outline_matches_mesh() {
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let glyph = Glyph::new(&face, '&').unwrap();
+    let builder = || glyph.with_subdivisions(12).skew(12.0).match_x_height(0.5);
+
+    let outline = builder().to_outline().unwrap();
+    let from_outline = outline.triangulate().unwrap();
+    let mesh = builder().to_mesh_2d().unwrap();
+    assert_eq!(from_outline.vertices, mesh.vertices);
+    assert_eq!(from_outline.indices, mesh.indices);
+
+    let extruded = from_outline.extrude(&outline, 0.1).unwrap();
+    assert_eq!(
+        extruded.vertices,
+        builder().to_mesh_3d(0.1).unwrap().vertices
+    );
+}
+
+#[test]
+fn test_builder_uvs() {
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let glyph = Glyph::new(&face, 'R').unwrap();
+
+    let plain = glyph.with_subdivisions(20).to_mesh_3d(0.2).unwrap();
+    assert!(plain.uvs.is_empty());
+
+    let textured = glyph
+        .with_subdivisions(20)
+        .with_uvs(true)
+        .to_mesh_3d(0.2)
+        .unwrap();
+    assert_eq!(textured.vertices, plain.vertices);
+    assert_eq!(textured.uvs.len(), textured.vertices.len());
+
+    // Cap UVs span the bounding box; side UVs run along the contour
+    let cap_vertices = glyph
+        .with_subdivisions(20)
+        .to_mesh_2d()
+        .unwrap()
+        .vertices
+        .len()
+        * 2;
+    let (min, max) = textured.uvs[..cap_vertices]
+        .iter()
+        .fold((glam::Vec2::MAX, glam::Vec2::MIN), |(lo, hi), &uv| {
+            (lo.min(uv), hi.max(uv))
+        });
+    assert!(min.abs_diff_eq(glam::Vec2::ZERO, 1e-5), "{min}");
+    assert!(max.abs_diff_eq(glam::Vec2::ONE, 1e-5), "{max}");
+    assert!(textured.uvs[cap_vertices..].iter().any(|uv| uv.x > 1.0));
+}
+
+#[test]
+fn test_builder_bevel() {
+    use fontmesh::{BevelOptions, BevelStyle};
+
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    let glyph = Glyph::new(&face, 'O').unwrap();
+    let plain = glyph.with_subdivisions(20).to_mesh_3d(0.1).unwrap();
+
+    let bevel = BevelOptions {
+        width: 0.01,
+        segments: 3,
+        style: BevelStyle::Round,
+    };
+    let beveled = glyph
+        .with_subdivisions(20)
+        .with_uvs(true)
+        .with_bevel(bevel)
+        .to_mesh_3d(0.1)
+        .unwrap();
+    assert!(beveled.is_solid());
+    assert!(beveled.uvs.is_empty());
+    assert!(beveled.vertex_count() > plain.vertex_count());
+    let (min, max) = beveled.aabb();
+    let (plain_min, plain_max) = plain.aabb();
+    assert!(min.abs_diff_eq(plain_min, 1e-6) && max.abs_diff_eq(plain_max, 1e-6));
+
+    let too_wide = BevelOptions {
+        width: 0.06,
+        ..bevel
+    };
+    assert!(matches!(
+        glyph
+            .with_subdivisions(20)
+            .with_bevel(too_wide)
+            .to_mesh_3d(0.1),
+        Err(FontMeshError::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn test_bevel_cursive_font() {
+    use fontmesh::{BevelOptions, BevelStyle};
+
+    // Hairline strokes, and an 'R' whose contour crosses itself
+    let face = Face::parse(include_bytes!("../assets/test_font_cursive.ttf"), 0).unwrap();
+    let bevel = BevelOptions {
+        width: 0.0001,
+        segments: 2,
+        style: BevelStyle::Round,
+    };
+    for c in ('!'..='~').filter(|&c| face.glyph_index(c).is_some()) {
+        let mesh = Glyph::new(&face, c)
+            .unwrap()
+            .with_subdivisions(20)
+            .with_bevel(bevel)
+            .to_mesh_3d(0.1)
+            .unwrap_or_else(|e| panic!("'{}': {}", c, e));
+        assert!(mesh.is_solid(), "'{}'", c);
+    }
+}
+
+#[test]
+fn test_depth_relative_to_cap_height() {
+    use fontmesh::{cap_height, ExtrudeDepth};
+
+    let face = Face::parse(include_bytes!("../assets/test_font.ttf"), 0).unwrap();
+    // DejaVu's OS/2 table predates sCapHeight, so the 'H' is measured
+    assert_eq!(face.capital_height(), None);
+    let cap = cap_height(&face).unwrap();
+
+    let mesh = Glyph::new(&face, 'H')
+        .unwrap()
+        .with_subdivisions(20)
+        .to_mesh_3d_with_depth(ExtrudeDepth::RelativeToCapHeight(0.5))
+        .unwrap();
+    let (min, max) = mesh.aabb();
+    assert!((max.z - min.z - 0.5 * cap).abs() < 1e-6);
+    // The 'H' is as tall as the cap height
+    assert!((max.y - cap).abs() < 1e-3);
+    assert_eq!(ExtrudeDepth::from(0.25).resolve(&face), 0.25);
+
+    // Without OS/2 or an 'H', the cap height falls back to 0.7 em
+    let data = FontBuilder::new(1000, 2)
+        .map('a', 1)
+        .glyf(&[Vec::new(), square_glyph(100)])
+        .build();
+    let bare = Face::parse(&data, 0).unwrap();
+    assert_eq!(cap_height(&bare), None);
+    assert_eq!(ExtrudeDepth::RelativeToCapHeight(0.5).resolve(&bare), 0.35);
+}
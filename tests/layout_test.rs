@@ -0,0 +1,421 @@
+//! Tests for the text-level (string) APIs
+
+use fontmesh::layout::{
+    export_text_obj, layout_text, layout_text_colored, layout_text_with, mesh_iter_with,
+    text_mesh_stats, text_width, Align, LineOptions, MeshStats, TextMeshOptions,
+};
+use fontmesh::{atlas_mesh_2d, char_to_mesh_3d, mesh_iter, print_solid, Face, FontMeshError, Rect};
+
+const TEST_FONT: &[u8] = include_bytes!("../assets/test_font.ttf");
+const CURSIVE_FONT: &[u8] = include_bytes!("../assets/test_font_cursive.ttf");
+
+/// Every non-degenerate triangle winds counter-clockwise seen along its normals
+fn assert_wound_by_normals(mesh: &fontmesh::Mesh3D) {
+    for tri in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+        let geometric = (b - a).cross(c - a);
+        if geometric.length_squared() < 1e-14 {
+            continue;
+        }
+        let normal: glam::Vec3 = tri.iter().map(|&i| mesh.normals[i as usize]).sum();
+        assert!(geometric.dot(normal) > 0.0, "{:?}", [a, b, c]);
+    }
+}
+
+#[test]
+fn test_mesh_iter_yields_one_mesh_per_char() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let items: Vec<_> = mesh_iter(&face, "A B", 2.0, 20).collect();
+    assert_eq!(items.len(), 3);
+
+    let (c, mesh) = items[1].as_ref().unwrap();
+    assert_eq!(*c, ' ');
+    assert!(mesh.is_empty(), "whitespace should yield an empty mesh");
+
+    for item in [&items[0], &items[2]] {
+        assert!(!item.as_ref().unwrap().1.is_empty());
+    }
+}
+
+#[test]
+fn test_mesh_iter_reports_missing_glyphs() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let mut iter = mesh_iter(&face, "\u{E000}A", 2.0, 20);
+    assert!(matches!(
+        iter.next(),
+        Some(Err(FontMeshError::GlyphNotFound('\u{E000}')))
+    ));
+    assert!(iter.next().unwrap().is_ok());
+}
+
+#[test]
+fn test_mesh_iter_contour_limit() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let options = TextMeshOptions {
+        skip_if_contours_over: Some(2),
+        ..TextMeshOptions::new(1.0, 20)
+    };
+
+    let items: Vec<_> = mesh_iter_with(&face, "IB", options).collect();
+    assert!(items[0].is_ok());
+    assert!(matches!(
+        items[1],
+        Err(FontMeshError::ComplexityExceeded(3))
+    ));
+}
+
+#[test]
+fn test_export_text_obj() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let mut obj = Vec::new();
+    export_text_obj(&face, "Hi I", &mut obj, 20, 0.1).unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+
+    let objects: Vec<_> = obj.lines().filter(|l| l.starts_with("o ")).collect();
+    assert_eq!(objects, ["o glyph_0_H", "o glyph_1_i", "o glyph_3_I"]);
+
+    let vertices: Vec<Vec<f32>> = obj
+        .lines()
+        .filter_map(|l| l.strip_prefix("v "))
+        .map(|l| l.split(' ').map(|x| x.parse().unwrap()).collect())
+        .collect();
+    let normal_count = obj.lines().filter(|l| l.starts_with("vn ")).count();
+    assert_eq!(vertices.len(), normal_count);
+
+    for face_line in obj.lines().filter_map(|l| l.strip_prefix("f ")) {
+        for corner in face_line.split(' ') {
+            let index: usize = corner.split("//").next().unwrap().parse().unwrap();
+            assert!(index >= 1 && index <= vertices.len());
+        }
+    }
+
+    // The last 'I' sits after the advances of "Hi "
+    let pen: f32 = "Hi "
+        .chars()
+        .map(|c| fontmesh::glyph_advance(&face, c).unwrap())
+        .sum();
+    let last_start = obj.lines().position(|l| l == "o glyph_3_I").unwrap();
+    let last_min_x = obj
+        .lines()
+        .skip(last_start)
+        .filter_map(|l| l.strip_prefix("v "))
+        .map(|l| l.split(' ').next().unwrap().parse::<f32>().unwrap())
+        .fold(f32::MAX, f32::min);
+    assert!(last_min_x > pen);
+
+    let mut out = Vec::new();
+    let err = export_text_obj(&face, "\u{E000}", &mut out, 20, 0.1).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_text_width_and_alignment() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let advance = |c| fontmesh::glyph_advance(&face, c).unwrap();
+
+    assert_eq!(text_width(&face, ""), 0.0);
+    assert_eq!(text_width(&face, "A"), advance('A'));
+    // The test font kerns "AV" by -131 units
+    let expected = advance('A') + advance('V') - 131.0 / 2048.0;
+    assert!((text_width(&face, "AV") - expected).abs() < 1e-6);
+
+    // 'A' and 'V' have (nearly) equal side bearings, so the centered ink is
+    // symmetric about x = 0
+    let centered = layout_text(&face, "AVA", 0.1, 20, Align::Center).unwrap();
+    let (min, max) = centered.aabb();
+    assert!((min.x + max.x).abs() < 1e-3, "{} .. {}", min.x, max.x);
+
+    let left = layout_text(&face, "AVA", 0.1, 20, Align::Left).unwrap();
+    let right = layout_text(&face, "AVA", 0.1, 20, Align::Right).unwrap();
+    let width = text_width(&face, "AVA");
+    assert!((left.aabb().0.x - right.aabb().0.x - width).abs() < 1e-5);
+    assert_eq!(left.triangle_count(), centered.triangle_count());
+
+    assert!(matches!(
+        layout_text(&face, "A\u{E000}", 0.1, 20, Align::Left),
+        Err(FontMeshError::GlyphNotFound('\u{E000}'))
+    ));
+}
+
+#[test]
+fn test_trim_trailing_whitespace() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let options = TextMeshOptions::new(0.1, 20);
+    let line = |trim_trailing_whitespace| LineOptions {
+        align: Align::Center,
+        trim_trailing_whitespace,
+        ..LineOptions::default()
+    };
+
+    let visible = layout_text_with(&face, "hi", &options, &line(false)).unwrap();
+    let trimmed = layout_text_with(&face, "hi   ", &options, &line(true)).unwrap();
+    let untrimmed = layout_text_with(&face, "hi   ", &options, &line(false)).unwrap();
+
+    assert_eq!(trimmed.vertices, visible.vertices);
+    assert!(untrimmed.aabb().0.x < visible.aabb().0.x);
+}
+
+#[test]
+fn test_glyph_z_step() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let options = TextMeshOptions::new(0.1, 20);
+    let flat = layout_text_with(&face, "a b", &options, &LineOptions::default()).unwrap();
+    let line = LineOptions {
+        glyph_z_step: 0.001,
+        ..LineOptions::default()
+    };
+    let stepped = layout_text_with(&face, "a b", &options, &line).unwrap();
+
+    // 'a' stays put and 'b', the next visible glyph, moves one step forward
+    let a_vertices = char_to_mesh_3d(&face, 'a', 0.1, 20).unwrap().vertices.len();
+    assert_eq!(stepped.vertices.len(), flat.vertices.len());
+    for (i, (s, f)) in stepped.vertices.iter().zip(&flat.vertices).enumerate() {
+        let expected = if i < a_vertices { 0.0 } else { 0.001 };
+        assert!((s.z - f.z - expected).abs() < 1e-6);
+        assert_eq!(s.truncate(), f.truncate());
+    }
+}
+
+#[test]
+fn test_layout_text_colored() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let options = TextMeshOptions::new(0.1, 20);
+    let red = [1.0, 0.0, 0.0, 1.0];
+    let blue = [0.0, 0.0, 1.0, 1.0];
+
+    let runs = [("AV ", red), ("A", blue)];
+    let colored = layout_text_colored(&face, &runs, &options, &LineOptions::default()).unwrap();
+    let plain = layout_text_with(&face, "AV A", &options, &LineOptions::default()).unwrap();
+
+    // Same placement as the concatenated text, kerning included
+    assert_eq!(colored.vertices, plain.vertices);
+    assert!(plain.colors.is_empty());
+    assert_eq!(colored.colors.len(), colored.vertices.len());
+
+    let blue_count = fontmesh::char_to_mesh_3d(&face, 'A', 0.1, 20)
+        .unwrap()
+        .vertices
+        .len();
+    let split = colored.colors.len() - blue_count;
+    assert!(colored.colors[..split].iter().all(|&c| c == red));
+    assert!(colored.colors[split..].iter().all(|&c| c == blue));
+}
+
+#[test]
+fn test_zero_width_characters_are_skipped() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    // ZWJ and a variation selector (both in the font as blank glyphs), then
+    // an emoji tag, a supplementary variation selector and a combining mark
+    // that the font doesn't have
+    let text = "A\u{200D}V\u{FE0F}\u{E0067}\u{E0100}\u{1AB0}";
+    for c in ['\u{E0067}', '\u{E0100}', '\u{1AB0}'] {
+        assert!(face.glyph_index(c).is_none());
+    }
+
+    let items: Vec<_> = mesh_iter(&face, text, 0.1, 20).collect();
+    assert_eq!(items.len(), 7);
+    assert!(items.iter().all(|item| item.is_ok()));
+
+    let mesh = layout_text(&face, text, 0.1, 20, Align::Left).unwrap();
+    let plain = layout_text(&face, "AV", 0.1, 20, Align::Left).unwrap();
+    assert_eq!(mesh.triangle_count(), plain.triangle_count());
+
+    let mut obj = Vec::new();
+    export_text_obj(&face, text, &mut obj, 20, 0.1).unwrap();
+}
+
+#[test]
+fn test_text_mesh_stats() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    let mut expected = MeshStats::default();
+    for item in mesh_iter(&face, "Hello, world\u{E000}", 3.0, 10) {
+        let Ok((_, mesh)) = item else { continue };
+        expected.total_vertices += mesh.vertices.len();
+        expected.total_indices += mesh.indices.len();
+        expected.total_triangles += mesh.triangle_count();
+    }
+    let stats = text_mesh_stats(&face, "Hello, world\u{E000}", 10);
+    assert_eq!(stats, expected);
+    assert_eq!(stats.total_indices, stats.total_triangles * 3);
+
+    assert_eq!(text_mesh_stats(&face, "", 10), MeshStats::default());
+    assert_eq!(text_mesh_stats(&face, "   ", 10), MeshStats::default());
+}
+
+#[test]
+fn test_print_solid() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+
+    for text in ["Hi", "AV", "Hello, World!"] {
+        let mesh = print_solid(&face, text, 0.2, 0.05, 10).unwrap();
+        assert_eq!(mesh.solid_defect(), None, "{text}");
+        assert_wound_by_normals(&mesh);
+        let (min, max) = mesh.aabb();
+        assert_eq!(min.z, 0.0);
+        assert!((max.z - 0.25).abs() < 1e-6);
+    }
+
+    // Script letters touch themselves at single points
+    let cursive = Face::parse(CURSIVE_FONT, 0).expect("Failed to load font");
+    assert!(print_solid(&cursive, "label", 0.2, 0.05, 10)
+        .unwrap()
+        .is_solid());
+
+    assert!(matches!(
+        print_solid(&face, "Hi", 0.0, 0.05, 10),
+        Err(FontMeshError::ExtrusionFailed(_))
+    ));
+    assert!(matches!(
+        print_solid(&face, "Hi", 0.2, f32::NAN, 10),
+        Err(FontMeshError::ExtrusionFailed(_))
+    ));
+    assert!(matches!(
+        print_solid(&face, "   ", 0.2, 0.05, 10),
+        Err(FontMeshError::EmptyOutline)
+    ));
+    assert!(matches!(
+        print_solid(&face, "\u{E000}", 0.2, 0.05, 10),
+        Err(FontMeshError::GlyphNotFound('\u{E000}'))
+    ));
+}
+
+#[test]
+fn test_atlas_mesh_2d() {
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let chars: Vec<char> = "Hello, World! gjpqy".chars().collect();
+    let (atlas, regions) = atlas_mesh_2d(&face, &chars, 20).unwrap();
+
+    // Spaces get no region and repeats are packed once
+    let mut visible: Vec<char> = chars.iter().copied().filter(|c| *c != ' ').collect();
+    visible.sort_unstable();
+    visible.dedup();
+    assert_eq!(regions.len(), visible.len());
+
+    let expected: usize = visible
+        .iter()
+        .map(|&c| {
+            fontmesh::char_to_mesh_2d(&face, c, 20)
+                .unwrap()
+                .triangle_count()
+        })
+        .sum();
+    assert_eq!(atlas.triangle_count(), expected);
+
+    let rects: Vec<_> = regions.values().collect();
+    for (i, a) in rects.iter().enumerate() {
+        assert!(a.min.x >= 0.0 && a.min.y >= 0.0 && a.max.x <= 1.0 && a.max.y <= 1.0);
+        for b in &rects[i + 1..] {
+            assert!(!a.overlaps(b));
+        }
+    }
+    // Every vertex lies in some glyph's region
+    for v in &atlas.vertices {
+        assert!(rects.iter().any(|r| {
+            v.x >= r.min.x - 1e-6
+                && v.x <= r.max.x + 1e-6
+                && v.y >= r.min.y - 1e-6
+                && v.y <= r.max.y + 1e-6
+        }));
+    }
+
+    // Proportions are kept
+    let h: Rect = regions[&'H'];
+    let h_mesh = fontmesh::char_to_mesh_2d(&face, 'H', 20).unwrap();
+    let (min, max) = h_mesh.aabb();
+    let aspect = (max.x - min.x) / (max.y - min.y);
+    assert!((h.size().x / h.size().y - aspect).abs() < 1e-4);
+
+    assert!(matches!(
+        atlas_mesh_2d(&face, &['\u{E000}'], 20),
+        Err(FontMeshError::GlyphNotFound('\u{E000}'))
+    ));
+}
+
+#[test]
+fn test_plaque() {
+    use fontmesh::layout::{plaque, Align, PlaqueOptions, PlaqueText};
+    use glam::Vec2;
+
+    let face = Face::parse(TEST_FONT, 0).expect("Failed to load font");
+    let cursive = Face::parse(CURSIVE_FONT, 0).expect("Failed to load font");
+
+    for text in [PlaqueText::Raised(0.1), PlaqueText::Engraved(0.05)] {
+        for align in [Align::Left, Align::Center, Align::Right] {
+            for bevel in [0.0, 0.05] {
+                let options = PlaqueOptions {
+                    text,
+                    align,
+                    bevel,
+                    ..PlaqueOptions::default()
+                };
+                let mesh = plaque(&face, "Hello, World!", &options).unwrap();
+                assert!(mesh.is_solid(), "{:?} {:?} {}", text, align, bevel);
+                assert_wound_by_normals(&mesh);
+
+                // Centered on the origin, back at z = 0
+                let (min, max) = mesh.aabb();
+                assert!((min.x + max.x).abs() < 1e-4 && (min.y + max.y).abs() < 1e-4);
+                assert_eq!(min.z, 0.0);
+                match text {
+                    PlaqueText::Raised(h) => assert!((max.z - 0.2 - h).abs() < 1e-6),
+                    PlaqueText::Engraved(_) => assert!((max.z - 0.2).abs() < 1e-6),
+                }
+            }
+        }
+        assert!(plaque(
+            &cursive,
+            "label",
+            &PlaqueOptions {
+                text,
+                ..PlaqueOptions::default()
+            }
+        )
+        .unwrap()
+        .is_solid());
+    }
+
+    // An explicit size is kept exactly
+    let options = PlaqueOptions {
+        size: Some(Vec2::new(8.0, 1.5)),
+        align: Align::Left,
+        ..PlaqueOptions::default()
+    };
+    let mesh = plaque(&face, "Hi", &options).unwrap();
+    let (min, max) = mesh.aabb();
+    assert!((max.x - min.x - 8.0).abs() < 1e-4 && (max.y - min.y - 1.5).abs() < 1e-4);
+    assert!(mesh.is_solid());
+
+    // Errors
+    let too_small = PlaqueOptions {
+        size: Some(Vec2::new(0.5, 0.5)),
+        ..PlaqueOptions::default()
+    };
+    assert!(matches!(
+        plaque(&face, "Hello", &too_small),
+        Err(FontMeshError::InvalidParameter(_))
+    ));
+    let too_deep = PlaqueOptions {
+        text: PlaqueText::Engraved(0.2),
+        ..PlaqueOptions::default()
+    };
+    assert!(matches!(
+        plaque(&face, "Hi", &too_deep),
+        Err(FontMeshError::InvalidParameter(_))
+    ));
+    let steep = PlaqueOptions {
+        bevel: 0.3,
+        ..PlaqueOptions::default()
+    };
+    assert!(matches!(
+        plaque(&face, "Hi", &steep),
+        Err(FontMeshError::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        plaque(&face, "   ", &PlaqueOptions::default()),
+        Err(FontMeshError::EmptyOutline)
+    ));
+}